@@ -8,6 +8,10 @@ pub trait AddComponent<T> {
     /// This function does not check `entity` is alive. It's possible to add components to removed entities.  
     /// Use [`Entities::add_component`] if you're unsure.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and one of the storages is full.
+    ///
     /// ### Example
     /// ```
     /// use shipyard::{AddComponent, Component, EntitiesViewMut, ViewMut, World};
@@ -95,7 +99,10 @@ macro_rules! add_component {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-add_component![(ViewA, A, 0); (ViewB, B, 1) (ViewC, C, 2) (ViewD, D, 3) (ViewE, E, 4) (ViewF, F, 5) (ViewG, G, 6) (ViewH, H, 7) (ViewI, I, 8) (ViewJ, J, 9)];
+add_component![
+    (ViewA, A, 0); (ViewB, B, 1) (ViewC, C, 2) (ViewD, D, 3) (ViewE, E, 4) (ViewF, F, 5) (ViewG, G, 6) (ViewH, H, 7) (ViewI, I, 8) (ViewJ, J, 9)
+    (ViewK, K, 10) (ViewL, L, 11) (ViewM, M, 12) (ViewN, N, 13) (ViewO, O, 14) (ViewP, P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 add_component![
     (ViewA, A, 0); (ViewB, B, 1) (ViewC, C, 2) (ViewD, D, 3) (ViewE, E, 4) (ViewF, F, 5) (ViewG, G, 6) (ViewH, H, 7) (ViewI, I, 8) (ViewJ, J, 9)
@@ -15,5 +15,6 @@ pub mod system;
 pub mod tracking;
 
 pub use crate::entities::Entities;
-pub use crate::r#mut::Mut;
+pub use crate::get::GetOwned;
+pub use crate::r#mut::{Mut, SilentMut};
 pub use crate::storage::{SBoxBuilder, Storage, StorageId};
@@ -1,7 +1,11 @@
 mod iterator_wrapper;
+mod modified_bitset;
+mod snapshot;
 mod tuple_track;
 
 pub use iterator_wrapper::{Inserted, InsertedOrModified, Modified};
+pub use modified_bitset::ModifiedBitSet;
+pub use snapshot::{StorageTrackingSnapshot, TrackingSnapshot};
 pub use tuple_track::TupleTrack;
 
 use crate::component::Component;
@@ -158,10 +162,10 @@ pub trait RemovalOrDeletionTracking: Tracking {
         sparse_set: &SparseSet<T>,
     ) -> core::iter::Chain<
         core::iter::Map<
-            core::slice::Iter<'_, (EntityId, TrackingTimestamp, T)>,
+            alloc::collections::vec_deque::Iter<'_, (EntityId, TrackingTimestamp, T)>,
             fn(&(EntityId, TrackingTimestamp, T)) -> (EntityId, TrackingTimestamp),
         >,
-        core::iter::Copied<core::slice::Iter<'_, (EntityId, TrackingTimestamp)>>,
+        core::iter::Copied<alloc::collections::vec_deque::Iter<'_, (EntityId, TrackingTimestamp)>>,
     >;
 
     #[doc(hidden)]
@@ -0,0 +1,71 @@
+use super::TrackingTimestamp;
+
+/// Read-only view over the *modification* flags of a storage.
+///
+/// Each bit corresponds to a dense position of the storage, not to an [`EntityId`](crate::entity_id::EntityId).\
+/// The dense position of a component can be found with [`SparseSet::index_of`](crate::sparse_set::SparseSet::index_of).
+#[derive(Clone, Copy)]
+pub struct ModifiedBitSet<'a> {
+    pub(crate) data: &'a [TrackingTimestamp],
+    pub(crate) last: TrackingTimestamp,
+    pub(crate) current: TrackingTimestamp,
+}
+
+impl<'a> ModifiedBitSet<'a> {
+    /// Returns the number of bits in the set, modified or not.\
+    /// This is the number of components in the storage.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the storage has no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if the component at dense position `index` is flagged modified.\
+    /// Returns `false` if `index` is out of bounds.
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        self.data
+            .get(index)
+            .is_some_and(|timestamp| timestamp.is_within(self.last, self.current))
+    }
+
+    /// Returns the number of modified components.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Iterates over the dense positions of all modified components.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = usize> + 'a {
+        let (last, current) = (self.last, self.current);
+
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, timestamp)| {
+                if timestamp.is_within(last, current) {
+                    Some(index)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Packs the flags into 64 bits words.\
+    /// Bit `i % 64` of word `i / 64` is set when the component at dense position `i` is modified.
+    pub fn to_words(&self) -> alloc::vec::Vec<u64> {
+        let mut words = alloc::vec![0; (self.data.len() + 63) / 64];
+
+        for index in self.iter() {
+            words[index / 64] |= 1 << (index % 64);
+        }
+
+        words
+    }
+}
@@ -0,0 +1,40 @@
+use super::TrackingTimestamp;
+use crate::entity_id::EntityId;
+use crate::storage::StorageId;
+use crate::ShipHashMap;
+use alloc::vec::Vec;
+
+/// Copy of the tracking metadata of all storages, components are not part of it.
+///
+/// Created with [`World::tracking_snapshot`] and applied with [`World::restore_tracking_snapshot`].
+///
+/// [`World::tracking_snapshot`]: crate::World::tracking_snapshot
+/// [`World::restore_tracking_snapshot`]: crate::World::restore_tracking_snapshot
+#[derive(Clone, Default)]
+pub struct TrackingSnapshot {
+    pub(crate) storages: ShipHashMap<StorageId, StorageTrackingSnapshot>,
+}
+
+impl TrackingSnapshot {
+    /// Returns the number of storages in this snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storages.len()
+    }
+    /// Returns `true` if this snapshot doesn't contain any storage.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storages.is_empty()
+    }
+}
+
+/// Copy of the tracking metadata of a single storage.
+#[derive(Clone)]
+pub struct StorageTrackingSnapshot {
+    pub(crate) last_insert: TrackingTimestamp,
+    pub(crate) last_modified: TrackingTimestamp,
+    pub(crate) insertion: Vec<(EntityId, TrackingTimestamp)>,
+    pub(crate) modification: Vec<(EntityId, TrackingTimestamp)>,
+    pub(crate) deletion: Vec<(EntityId, TrackingTimestamp)>,
+    pub(crate) removal: Vec<(EntityId, TrackingTimestamp)>,
+}
@@ -1,6 +1,8 @@
 #[cfg(not(feature = "std"))]
 use crate::all_storages::MissingLock;
-use crate::all_storages::{AllStorages, LockPresent, ThreadIdPresent};
+#[cfg(feature = "serde1")]
+use crate::all_storages::SerdeRegistry;
+use crate::all_storages::{create_storage, AllStorages, LockPresent, ThreadIdPresent};
 use crate::atomic_refcell::AtomicRefCell;
 use crate::entities::Entities;
 use crate::public_transport::{RwLock, ShipyardRwLock};
@@ -16,6 +18,7 @@ use core::sync::atomic::AtomicU64;
 pub(crate) struct AllStoragesBuilder<Lock, ThreadId> {
     pub(crate) custom_lock: Option<Box<dyn ShipyardRwLock + Send + Sync>>,
     pub(crate) custom_thread_id: Option<Arc<dyn Fn() -> u64 + Send + Sync>>,
+    pub(crate) fixed_capacity: Option<usize>,
     pub(crate) _phantom: PhantomData<(Lock, ThreadId)>,
 }
 
@@ -25,6 +28,7 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: Some(Arc::new(std_thread_id_generator)),
+            fixed_capacity: None,
             _phantom: PhantomData,
         }
     }
@@ -34,6 +38,7 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: None,
+            fixed_capacity: None,
             _phantom: PhantomData,
         }
     }
@@ -43,6 +48,7 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: None,
+            fixed_capacity: None,
             _phantom: PhantomData,
         }
     }
@@ -53,6 +59,7 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: Some(L::new()),
             custom_thread_id: self.custom_thread_id,
+            fixed_capacity: self.fixed_capacity,
             _phantom: PhantomData,
         }
     }
@@ -65,9 +72,19 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: self.custom_lock,
             custom_thread_id: Some(Arc::new(thread_id)),
+            fixed_capacity: self.fixed_capacity,
             _phantom: PhantomData,
         }
     }
+
+    pub(crate) fn with_fixed_capacity(
+        mut self,
+        capacity: usize,
+    ) -> AllStoragesBuilder<Lock, ThreadId> {
+        self.fixed_capacity = Some(capacity);
+
+        self
+    }
 }
 
 impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
@@ -75,7 +92,10 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
     pub(crate) fn build(self, counter: Arc<AtomicU64>) -> AtomicRefCell<AllStorages> {
         let mut storages = ShipHashMap::new();
 
-        storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
+        storages.insert(
+            StorageId::of::<Entities>(),
+            SBox::new(create_storage(self.fixed_capacity, Entities::new)),
+        );
 
         let storages = if let Some(custom_lock) = self.custom_lock {
             RwLock::new_custom(custom_lock, storages)
@@ -103,13 +123,24 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
                     main_thread_id,
                     thread_id_generator: thread_id_generator.clone(),
                     counter,
+                    fixed_capacity: self.fixed_capacity,
+                    observers: Default::default(),
+                    #[cfg(feature = "serde1")]
+                    serde_registry: SerdeRegistry::default(),
                 },
                 thread_id_generator,
             )
         }
         #[cfg(not(feature = "thread_local"))]
         {
-            AtomicRefCell::new(AllStorages { storages, counter })
+            AtomicRefCell::new(AllStorages {
+                storages,
+                counter,
+                fixed_capacity: self.fixed_capacity,
+                observers: Default::default(),
+                #[cfg(feature = "serde1")]
+                serde_registry: SerdeRegistry::default(),
+            })
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::all_storages::AllStorages;
+use crate::all_storages::{create_storage, AllStorages};
 use crate::atomic_refcell::{ARef, ARefMut};
 use crate::error;
 use crate::storage::{SBox, Storage, StorageId};
@@ -219,9 +219,14 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe { &*storage.0 }.borrow();
             drop(storages);
             match storage {
-                Ok(storage) => Ok(ARef::map(storage, |storage| {
+                Ok(storage) if storage.as_any().is::<S>() => Ok(ARef::map(storage, |storage| {
                     storage.as_any().downcast_ref().unwrap()
                 })),
+                // A lazy unique's slot holds its initializer until the unique is first borrowed
+                Ok(_) => Err(error::GetStorage::MissingStorage {
+                    name: Some(type_name::<S>()),
+                    id: StorageId::of::<S>(),
+                }),
                 Err(err) => Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
@@ -264,9 +269,14 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe { &*storage.0 }.borrow_mut();
             drop(storages);
             match storage {
-                Ok(storage) => Ok(ARefMut::map(storage, |storage| {
+                Ok(storage) if storage.as_any().is::<S>() => Ok(ARefMut::map(storage, |storage| {
                     storage.as_any_mut().downcast_mut().unwrap()
                 })),
+                // A lazy unique's slot holds its initializer until the unique is first borrowed
+                Ok(_) => Err(error::GetStorage::MissingStorage {
+                    name: Some(type_name::<S>()),
+                    id: StorageId::of::<S>(),
+                }),
                 Err(err) => Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
@@ -340,7 +350,7 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
+                    .or_insert_with(|| SBox::new(create_storage(self.fixed_capacity, f)))
                     .0
             }
             .borrow()
@@ -407,7 +417,12 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                    .or_insert_with(|| {
+                        SBox::new_non_send(
+                            create_storage(self.fixed_capacity, f),
+                            self.thread_id_generator.clone(),
+                        )
+                    })
                     .0
             }
             .borrow()
@@ -466,7 +481,7 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
+                    .or_insert_with(|| SBox::new_non_sync(create_storage(self.fixed_capacity, f)))
                     .0
             }
             .borrow()
@@ -534,7 +549,10 @@ impl CustomStorageAccess for AllStorages {
                 &*storages
                     .entry(storage_id)
                     .or_insert_with(|| {
-                        SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                        SBox::new_non_send_sync(
+                            create_storage(self.fixed_capacity, f),
+                            self.thread_id_generator.clone(),
+                        )
                     })
                     .0
             }
@@ -592,7 +610,7 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
+                    .or_insert_with(|| SBox::new(create_storage(self.fixed_capacity, f)))
                     .0
             }
             .borrow_mut()
@@ -659,7 +677,12 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                    .or_insert_with(|| {
+                        SBox::new_non_send(
+                            create_storage(self.fixed_capacity, f),
+                            self.thread_id_generator.clone(),
+                        )
+                    })
                     .0
             }
             .borrow_mut()
@@ -718,7 +741,7 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
+                    .or_insert_with(|| SBox::new_non_sync(create_storage(self.fixed_capacity, f)))
                     .0
             }
             .borrow_mut()
@@ -786,7 +809,10 @@ impl CustomStorageAccess for AllStorages {
                 &*storages
                     .entry(storage_id)
                     .or_insert_with(|| {
-                        SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                        SBox::new_non_send_sync(
+                            create_storage(self.fixed_capacity, f),
+                            self.thread_id_generator.clone(),
+                        )
                     })
                     .0
             }
@@ -0,0 +1,52 @@
+use crate::component::Component;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// Components of a deleted entity, returned by [`AllStorages::delete_entity_collect`].
+///
+/// Only components whose storage registered [`AllStorages::register_take`] are collected.
+///
+/// [`AllStorages::delete_entity_collect`]: crate::all_storages::AllStorages::delete_entity_collect
+/// [`AllStorages::register_take`]: crate::all_storages::AllStorages::register_take
+#[derive(Default)]
+pub struct EntityComponents(pub(crate) ShipHashMap<StorageId, Box<dyn Any>>);
+
+impl EntityComponents {
+    /// Returns a reference to the `T` component, if it was collected.
+    pub fn get<T: Component>(&self) -> Option<&T> {
+        self.0
+            .get(&StorageId::of::<SparseSet<T>>())
+            .and_then(|component| component.downcast_ref())
+    }
+    /// Removes the `T` component from the bag and returns it, if it was collected.
+    pub fn take<T: Component>(&mut self) -> Option<T> {
+        let component = self.0.remove(&StorageId::of::<SparseSet<T>>())?;
+
+        component.downcast().ok().map(|component| *component)
+    }
+    /// Returns `true` if the `T` component was collected.
+    pub fn contains<T: Component>(&self) -> bool {
+        self.0.contains_key(&StorageId::of::<SparseSet<T>>())
+    }
+    /// Returns the number of collected components.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Returns `true` if no component was collected.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Returns the storage ids of the collected components.
+    pub fn storage_ids(&self) -> impl Iterator<Item = StorageId> + '_ {
+        self.0.keys().copied()
+    }
+}
+
+impl core::fmt::Debug for EntityComponents {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
@@ -2,19 +2,29 @@ mod builder;
 mod clone;
 mod custom_storage;
 mod delete_any;
+mod entity_components;
+mod observers;
 mod retain;
+#[cfg(feature = "serde1")]
+mod serde_registry;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
+pub use entity_components::EntityComponents;
 pub use retain::TupleRetainStorage;
 
+use observers::Observers;
+
 pub(crate) use builder::AllStoragesBuilder;
 pub(crate) use clone::TupleClone;
+#[cfg(feature = "serde1")]
+pub(crate) use serde_registry::{RegisteredStorage, RegisteredStoragesVisitor, SerdeRegistry};
 
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::Borrow;
 #[cfg(feature = "thread_local")]
 use crate::borrow::{NonSend, NonSendSync, NonSync};
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
@@ -22,21 +32,27 @@ use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
 use crate::iter::{ShiperatorCaptain, ShiperatorSailor};
 use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
-use crate::memory_usage::AllStoragesMemoryUsage;
+use crate::memory_usage::{AllStoragesMemoryUsage, StorageCompaction};
 use crate::public_transport::RwLock;
 use crate::r#mut::Mut;
 use crate::reserve::BulkEntityIter;
-use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{
+    BulkAddEntity, SparseSet, TightPack, TupleAddComponent, TupleDelete, TuplePack, TupleRemove,
+    TupleTryAddComponent,
+};
 #[cfg(feature = "thread_local")]
 use crate::std_thread_id_generator;
 use crate::storage::{SBox, Storage, StorageId};
 use crate::system::AllSystem;
-use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::unique::UniqueStorage;
+use crate::tracking::{TrackingSnapshot, TrackingTimestamp, TupleTrack};
+use crate::unique::{LazyUnique, UniqueStorage};
 use crate::views::EntitiesViewMut;
 use crate::{error, ShipHashMap};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::type_name;
 use core::sync::atomic::AtomicU64;
 use hashbrown::hash_map::Entry;
@@ -65,6 +81,10 @@ pub struct AllStorages {
     #[cfg(feature = "thread_local")]
     thread_id_generator: Arc<dyn Fn() -> u64 + Send + Sync>,
     counter: Arc<AtomicU64>,
+    pub(crate) fixed_capacity: Option<usize>,
+    observers: Observers,
+    #[cfg(feature = "serde1")]
+    serde_registry: SerdeRegistry,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -86,6 +106,10 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id_generator: Arc::new(std_thread_id_generator),
             counter,
+            fixed_capacity: None,
+            observers: Observers::default(),
+            #[cfg(feature = "serde1")]
+            serde_registry: SerdeRegistry::default(),
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -118,6 +142,139 @@ impl AllStorages {
                 self.get_tracking_timestamp(),
             )));
     }
+    /// Adds a new unique storage, unless one of the same type already exists.\
+    /// Lazy uniques count as existing even if they weren't initialized yet.
+    ///
+    /// ### Errors
+    ///
+    /// - A `T` unique storage already exists, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_distinct_unique(USIZE(0)).unwrap();
+    /// assert!(all_storages.add_distinct_unique(USIZE(1)).is_err());
+    /// ```
+    pub fn add_distinct_unique<T: Send + Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::UniqueAlreadyExists> {
+        let mut storages = self.storages.write();
+
+        if storages.contains_key(&StorageId::of::<UniqueStorage<T>>()) {
+            return Err(error::UniqueAlreadyExists {
+                name: type_name::<T>(),
+            });
+        }
+
+        storages.insert(
+            StorageId::of::<UniqueStorage<T>>(),
+            SBox::new(UniqueStorage::new(component, self.get_tracking_timestamp())),
+        );
+
+        Ok(())
+    }
+    /// Adds a new unique storage whose value is created by `init` the first time it is borrowed.\
+    /// Replaces any previous `T` unique storage or initializer.
+    ///
+    /// Until then the unique storage exists but holds `init`, [`add_unique`](AllStorages::add_unique) and [`remove_unique`](AllStorages::remove_unique) replace or remove it.\
+    /// `init` runs without any lock held, borrowing the unique storage at the same time fails with a borrow error.\
+    /// If `init` panics the unique storage is removed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, UniqueView, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique_lazy(|| USIZE(0));
+    ///
+    /// assert_eq!(all_storages.borrow::<UniqueView<USIZE>>().unwrap().0, 0);
+    /// ```
+    pub fn add_unique_lazy<T: Send + Sync + Unique>(
+        &self,
+        init: impl FnOnce() -> T + Send + Sync + 'static,
+    ) {
+        self.storages.write().insert(
+            StorageId::of::<UniqueStorage<T>>(),
+            SBox::new(LazyUnique::<T>(Some(Box::new(init)))),
+        );
+    }
+    /// Runs the initializer of the `T` unique storage if it has one.\
+    /// Returns `true` if the `T` unique storage was initialized.
+    pub(crate) fn init_lazy_unique<T: Send + Sync + Unique>(
+        &self,
+    ) -> Result<bool, error::GetStorage> {
+        let storage_id = StorageId::of::<UniqueStorage<T>>();
+        let borrow_error = |borrow| error::GetStorage::StorageBorrow {
+            name: Some(type_name::<UniqueStorage<T>>()),
+            id: storage_id,
+            borrow,
+        };
+
+        // The slot stays borrowed while `init` runs, borrowing the unique meanwhile fails
+        let (lazy_ptr, mut slot) = {
+            let storages = self.storages.read();
+            let Some(storage) = storages.get(&storage_id) else {
+                return Ok(false);
+            };
+            let lazy_ptr = storage.0;
+
+            (
+                lazy_ptr,
+                unsafe { &*lazy_ptr }.borrow_mut().map_err(borrow_error)?,
+            )
+        };
+
+        let Some(lazy) = slot.as_any_mut().downcast_mut::<LazyUnique<T>>() else {
+            // Another thread initialized it first
+            return Ok(true);
+        };
+        // The initializer unwound and the slot couldn't be removed
+        let init = lazy
+            .0
+            .take()
+            .ok_or_else(|| borrow_error(error::Borrow::Unique))?;
+
+        let lazy_init = LazyInit {
+            all_storages: self,
+            storage_id,
+            lazy_ptr,
+            slot: Some(slot),
+        };
+        let value = init();
+        lazy_init.finish();
+
+        let mut storages = self.storages.write();
+
+        // The initializer might have been replaced or removed while it was running
+        match storages.get_mut(&storage_id) {
+            Some(storage) if storage.0.cast::<()>() == lazy_ptr.cast::<()>() => {
+                // Same as `remove_unique`, a borrowed storage can't be replaced
+                if let Some(err) = unsafe { &*storage.0 }.borrow_mut().err() {
+                    return Err(borrow_error(err));
+                }
+
+                *storage = SBox::new(UniqueStorage::new(value, self.get_tracking_timestamp()));
+
+                Ok(true)
+            }
+            storage => Ok(storage.is_some()),
+        }
+    }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.
@@ -220,6 +377,27 @@ impl AllStorages {
                 return Err(error::UniqueRemove::MissingUnique(type_name::<T>()));
             };
 
+            // The unique was never borrowed, it still holds its initializer
+            if let Some(lazy) = unsafe { &mut *storage.0 }
+                .get_mut()
+                .as_any_mut()
+                .downcast_mut::<LazyUnique<T>>()
+            {
+                let Some(init) = lazy.0.take() else {
+                    // The initializer unwound and the slot couldn't be removed
+                    storages.insert(storage_id, storage);
+
+                    return Err(error::UniqueRemove::StorageBorrow((
+                        type_name::<T>(),
+                        error::Borrow::Unique,
+                    )));
+                };
+
+                drop(storages);
+
+                return Ok(init());
+            }
+
             let unique: Box<AtomicRefCell<UniqueStorage<T>>> =
                 unsafe { Box::from_raw(storage.0 as *mut AtomicRefCell<UniqueStorage<T>>) };
 
@@ -271,6 +449,165 @@ impl AllStorages {
             false
         }
     }
+    /// Deletes `entity` with all of its components and returns the components registered with [`AllStorages::register_take`].\
+    /// Other components are dropped like with [`AllStorages::delete_entity`].\
+    /// Components whose deletion is tracked are kept by the tracking and not collected.
+    ///
+    /// Returns an empty [`EntityComponents`] if `entity` was not alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Pos(i32, i32);
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.register_take::<Pos>();
+    ///
+    /// let entity = all_storages.add_entity((Pos(1, 2), Health(0)));
+    ///
+    /// let mut components = all_storages.delete_entity_collect(entity);
+    ///
+    /// assert!(!all_storages.is_entity_alive(entity));
+    /// assert_eq!(components.take::<Pos>(), Some(Pos(1, 2)));
+    /// assert!(!components.contains::<Health>());
+    /// ```
+    pub fn delete_entity_collect(&mut self, entity: EntityId) -> EntityComponents {
+        let mut components = EntityComponents::default();
+
+        if !self.entities_mut().unwrap().delete_unchecked(entity) {
+            return components;
+        }
+
+        let current = self.get_current();
+
+        for (&storage_id, storage) in self.storages.get_mut().iter_mut() {
+            if let Some(component) = unsafe { &mut *storage.0 }
+                .get_mut()
+                .delete_and_take(entity, current)
+            {
+                components.0.insert(storage_id, component);
+            }
+        }
+
+        components
+    }
+    /// Swaps all components of `a` and `b`.\
+    /// When only one of them has a component in a storage, the component moves to the other entity.
+    ///
+    /// Swapped components keep their insertion timestamp and are flagged *modified*, moved components are flagged *removed* then *inserted*.\
+    /// Returns `false` and doesn't swap anything if either entity is not alive.
+    ///
+    /// With the `hierarchy` feature, `Parent` and `Children` components are swapped like any other
+    /// but the other entities of the hierarchy still point to the original ids.
+    /// Detach `a` and `b` first to keep the hierarchy consistent.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Name(&'static str);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Player;
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let a = all_storages.add_entity((Name("a"), Player));
+    /// let b = all_storages.add_entity((Name("b"),));
+    ///
+    /// assert!(all_storages.swap_entities(a, b));
+    ///
+    /// assert!(*all_storages.get::<&Name>(a).unwrap() == &Name("b"));
+    /// assert!(*all_storages.get::<&Name>(b).unwrap() == &Name("a"));
+    /// assert!(all_storages.get::<&Player>(a).is_err());
+    /// assert!(all_storages.get::<&Player>(b).is_ok());
+    /// ```
+    pub fn swap_entities(&mut self, a: EntityId, b: EntityId) -> bool {
+        let entities = self.entities().unwrap();
+
+        if !entities.is_alive(a) || !entities.is_alive(b) {
+            return false;
+        }
+
+        drop(entities);
+
+        if a == b {
+            return true;
+        }
+
+        let current = self.get_current();
+
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .swap_entities(a, b, current);
+        }
+
+        true
+    }
+    /// Deletes `entity` and detaches it from its parent.\
+    /// If `cascade` is `true`, all of `entity`'s descendants are deleted too, otherwise its children become roots.
+    ///
+    /// Returns `true` if `entity` was alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{hierarchy::HierarchyViewMut, AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let root = all_storages.add_entity(());
+    /// let child = all_storages.add_entity(());
+    ///
+    /// all_storages
+    ///     .borrow::<HierarchyViewMut>()
+    ///     .unwrap()
+    ///     .attach_child(root, child);
+    ///
+    /// all_storages.delete_in_hierarchy(root, true);
+    ///
+    /// assert!(!all_storages.is_entity_alive(child));
+    /// ```
+    #[cfg(feature = "hierarchy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hierarchy")))]
+    #[track_caller]
+    pub fn delete_in_hierarchy(&mut self, entity: EntityId, cascade: bool) -> bool {
+        let descendants = {
+            let mut hierarchy = self
+                .borrow::<crate::hierarchy::HierarchyViewMut<'_>>()
+                .unwrap();
+
+            let descendants = if cascade {
+                hierarchy.iter_descendants_depth_first(entity).collect()
+            } else {
+                hierarchy.detach_children(entity);
+                Vec::new()
+            };
+
+            hierarchy.detach(entity);
+
+            descendants
+        };
+
+        for descendant in descendants {
+            self.delete_entity(descendant);
+        }
+
+        self.delete_entity(entity)
+    }
     /// Deletes all components from an entity without deleting it.
     ///
     /// ### Example
@@ -469,6 +806,45 @@ impl AllStorages {
             unsafe { &mut *storage.0 }.get_mut().clear(current);
         }
     }
+    /// Deletes all entities and components in the `World` and resets all tracking information.
+    ///
+    /// Unlike [`clear`](AllStorages::clear), deleted components are not added to the deletion tracking data.\
+    /// Storages stay registered and keep their allocated memory, spawning entities after this call will not reallocate until the previous capacity is reached.\
+    /// Entity generations are preserved, ids from before the call are considered dead.\
+    /// Insertion, modification, deletion and removal tracking data is cleared for all storages.\
+    /// Unique storages are left untouched.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    ///
+    /// all_storages.clear_keep_capacity();
+    ///
+    /// assert!(!all_storages.is_entity_alive(entity));
+    /// ```
+    #[track_caller]
+    pub fn clear_keep_capacity(&mut self) {
+        let current = self.get_current();
+        let now = self.get_tracking_timestamp();
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            storage.clear(current);
+            storage.clear_all_removed_and_deleted();
+            storage.clear_all_inserted(now);
+            storage.clear_all_modified(now);
+        }
+    }
     /// Clear all deletion and removal tracking data.
     #[track_caller]
     pub fn clear_all_removed_and_deleted(&mut self) {
@@ -490,6 +866,55 @@ impl AllStorages {
                 .clear_all_removed_and_deleted_older_than_timestamp(timestamp);
         }
     }
+    /// Returns a copy of the tracking metadata of all storages, components are not copied.\
+    /// Insertion, modification, deletion and removal timestamps are part of the snapshot.
+    ///
+    /// Unique storages and custom storages are not part of the snapshot.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity(U32(0));
+    ///
+    /// let snapshot = all_storages.tracking_snapshot();
+    /// all_storages.clear_all_inserted();
+    /// all_storages.restore_tracking_snapshot(&snapshot);
+    /// ```
+    pub fn tracking_snapshot(&mut self) -> TrackingSnapshot {
+        let mut snapshot = TrackingSnapshot::default();
+
+        for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            if let Some(storage_snapshot) = storage.tracking_snapshot() {
+                snapshot.storages.insert(*storage_id, storage_snapshot);
+            }
+        }
+
+        snapshot
+    }
+    /// Restores the tracking metadata of all storages present in `snapshot`.
+    ///
+    /// Components are left untouched, the metadata of components added after the snapshot was taken is not modified.\
+    /// Deleted components can't be restored, only deletions present both in the storage and in `snapshot` are kept.
+    pub fn restore_tracking_snapshot(&mut self, snapshot: &TrackingSnapshot) {
+        for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+            if let Some(storage_snapshot) = snapshot.storages.get(storage_id) {
+                unsafe { &mut *storage.0 }
+                    .get_mut()
+                    .restore_tracking_snapshot(storage_snapshot);
+            }
+        }
+    }
 
     /// Clear all insertion tracking data.
     #[track_caller]
@@ -553,9 +978,117 @@ impl AllStorages {
             .private_retain_mut(current, f);
     }
 
+    /// Deletes all entities for which `keep(id)` returns `false`, and all their components.
+    /// Entities are collected first then deleted, all storages are visited a single time.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let eid0 = all_storages.add_entity(U32(0));
+    /// let eid1 = all_storages.add_entity(U32(1));
+    ///
+    /// all_storages.retain_entities(|eid| eid != eid0);
+    ///
+    /// assert!(!all_storages.is_entity_alive(eid0));
+    /// assert!(all_storages.is_entity_alive(eid1));
+    ///
+    /// let v_u32 = all_storages.borrow::<View<U32>>().unwrap();
+    /// assert_eq!(v_u32.len(), 1);
+    /// ```
+    #[track_caller]
+    pub fn retain_entities(&mut self, keep: impl Fn(EntityId) -> bool) {
+        let current = self.get_current();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let deleted = entities
+            .iter()
+            .filter(|&entity| !keep(entity))
+            .collect::<Vec<_>>();
+
+        if deleted.is_empty() {
+            return;
+        }
+
+        for &entity in &deleted {
+            entities.delete_unchecked(entity);
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            for &entity in &deleted {
+                storage.delete(entity, current);
+            }
+        }
+    }
+
+    /// Applies all operations recorded by [`Commands`](crate::Commands), in the order they were recorded.\
+    /// Operations recorded while applying the commands will be applied at the next call.
+    #[track_caller]
+    pub fn apply_commands(&mut self) {
+        let commands = match self.exclusive_storage_mut::<CommandQueue>() {
+            Ok(queue) => queue.take(),
+            Err(_) => return,
+        };
+
+        for command in commands {
+            command(self);
+        }
+
+        self.dispatch_observers();
+    }
+
+    /// Registers an insertion observer, see [`World::observe_insert`](crate::World::observe_insert).
+    pub(crate) fn observe_insert<T: Component + Send + Sync>(
+        &mut self,
+        f: impl Fn(&mut AllStorages, EntityId) + Send + Sync + 'static,
+    ) {
+        let mut observers = core::mem::take(&mut self.observers);
+        observers.observe_insert::<T>(self, f);
+        self.observers = observers;
+    }
+    /// Returns `true` if insertions are waiting to be dispatched to the observers.
+    #[inline]
+    pub(crate) fn has_pending_observers(&self) -> bool {
+        self.observers.is_pending()
+    }
+    /// Calls the insertion observers for all insertions not dispatched yet.
+    ///
+    /// The observers are taken out of `AllStorages` while they run, insertions they make are dispatched by this same call.
+    pub(crate) fn run_observers(&mut self) -> Result<(), error::Observe> {
+        if !self.observers.is_pending() {
+            return Ok(());
+        }
+
+        let observers = core::mem::take(&mut self.observers);
+        let result = observers.run(self);
+        self.observers = observers;
+
+        result
+    }
+    /// Same as [`run_observers`](AllStorages::run_observers) but panics on error.
+    #[track_caller]
+    pub(crate) fn dispatch_observers(&mut self) {
+        if let Err(err) = self.run_observers() {
+            panic!("{:?}", err);
+        }
+    }
+
     /// Creates a new entity with the components passed as argument and returns its `EntityId`.  
     /// `component` must always be a tuple, even for a single component.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and is full, use [`add_entity_checked`](AllStorages::add_entity_checked) to get an error instead.
+    ///
     /// ### Example
     ///
     /// ```
@@ -574,17 +1107,76 @@ impl AllStorages {
     /// let entity1 = all_storages.add_entity((U32(1), USIZE(11)));
     /// ```
     #[inline]
+    #[track_caller]
     pub fn add_entity<T: TupleAddComponent>(&mut self, component: T) -> EntityId {
+        match self.add_entity_checked(component) {
+            Ok(entity) => entity,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Same as [`add_entity`](AllStorages::add_entity) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - The `World` has a fixed capacity and all entity indices are in use, `component` is dropped.
+    /// - The `World` has a fixed capacity and one of the storages is full, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::with_fixed_capacity(1);
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity_checked((U32(0),)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     all_storages.add_entity_checked((U32(1),)),
+    ///     Err(error::AddEntity::EntitiesFull)
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn add_entity_checked<T: TupleAddComponent>(
+        &mut self,
+        component: T,
+    ) -> Result<EntityId, error::AddEntity> {
+        if self.fixed_capacity.is_some() {
+            if self.exclusive_storage_mut::<Entities>().unwrap().is_full() {
+                return Err(error::AddEntity::EntitiesFull);
+            }
+
+            component
+                .check_capacity(self, None)
+                .map_err(|err| match err {
+                    error::AddComponent::StorageFull(name) => error::AddEntity::StorageFull(name),
+                    _ => unreachable!(),
+                })?;
+        }
+
         let current = self.get_current();
 
         let entity = self.exclusive_storage_mut::<Entities>().unwrap().generate();
         component.add_component(self, entity, current);
 
-        entity
+        self.dispatch_observers();
+
+        Ok(entity)
     }
     /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.  
     /// `source` must always yield a tuple, even for a single component.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and there isn't enough room for all the new entities or their components.
+    ///
     /// ### Example
     ///
     /// ```
@@ -605,13 +1197,55 @@ impl AllStorages {
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
         source.bulk_add_entity(self)
     }
+    /// Creates `n` new entities, the components of each entity are the result of `f` called with its index.\
+    /// `f` must always return a tuple, even for a single component.
+    ///
+    /// The returned `EntityId`s are in the same order as the indices passed to `f`.
+    ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and there isn't enough room for `n` new entities, `f` is not called.
+    /// - The `World` has a fixed capacity and one of the storages is full.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Position(usize);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entities = all_storages.spawn_batch_with(3, |i| (Position(i * 10),));
+    ///
+    /// assert!(*all_storages.get::<&Position>(entities[2]).unwrap() == &Position(20));
+    /// ```
+    #[track_caller]
+    pub fn spawn_batch_with<C, F>(&mut self, n: usize, f: F) -> Vec<EntityId>
+    where
+        F: FnMut(usize) -> C,
+        core::iter::Map<core::ops::Range<usize>, F>: BulkAddEntity,
+    {
+        if n > self.entities().unwrap().remaining_capacity() {
+            panic!("{:?}", error::AddEntity::EntitiesFull);
+        }
+
+        let entities = self.bulk_add_entity((0..n).map(f)).collect();
+
+        self.dispatch_observers();
+
+        entities
+    }
     /// Adds components to an existing entity.  
     /// If the entity already owned a component it will be replaced.  
     /// `component` must always be a tuple, even for a single component.  
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive, use [`add_component_checked`](AllStorages::add_component_checked) to get an error instead.
+    /// - The `World` has a fixed capacity and one of the storages is full.
     ///
     /// ### Example
     ///
@@ -637,6 +1271,47 @@ impl AllStorages {
     #[track_caller]
     #[inline]
     pub fn add_component<T: TupleAddComponent>(&mut self, entity: EntityId, component: T) {
+        if let Err(err) = self.add_component_checked(entity, component) {
+            panic!("{:?}", err);
+        }
+    }
+    /// Adds components to an existing entity.\
+    /// If the entity already owned a component it will be replaced.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Same as [`add_component`](AllStorages::add_component) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive, `component` is dropped.
+    /// - The `World` has a fixed capacity and one of the storages is full, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(());
+    /// all_storages.delete_entity(entity);
+    ///
+    /// assert_eq!(
+    ///     all_storages.add_component_checked(entity, (U32(0),)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn add_component_checked<T: TupleAddComponent>(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Result<(), error::AddComponent> {
         let current = self.get_current();
 
         if self
@@ -644,10 +1319,76 @@ impl AllStorages {
             .unwrap()
             .is_alive(entity)
         {
+            if self.fixed_capacity.is_some() {
+                component.check_capacity(self, Some(entity))?;
+            }
+
             component.add_component(self, entity, current);
+
+            self.dispatch_observers();
+
+            Ok(())
         } else {
-            panic!("{:?}", error::AddComponent::EntityIsNotAlive);
+            Err(error::AddComponent::EntityIsNotAlive)
+        }
+    }
+    /// Adds components to an existing entity without requiring exclusive access to `AllStorages`.\
+    /// If the entity already owned a component it will be replaced.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// All storages are borrowed before any component is added, if this method fails no component is added.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    /// - Component storages (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed.
+    /// - `entity` is not alive, `component` is dropped.
+    /// - One of the component storages borrow failed.
+    /// - The `World` has a fixed capacity and one of the storages is full, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.add_entity(());
+    ///
+    /// let all_storages = world.all_storages().unwrap();
+    /// all_storages.try_add_component(entity, (U32(0),)).unwrap();
+    ///
+    /// let u32s = all_storages.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// assert!(matches!(
+    ///     all_storages.try_add_component(entity, (U32(1),)),
+    ///     Err(error::TryAddComponent::StorageBorrow(_))
+    /// ));
+    /// ```
+    #[track_caller]
+    pub fn try_add_component<T: TupleTryAddComponent>(
+        &self,
+        entity: EntityId,
+        component: T,
+    ) -> Result<(), error::TryAddComponent> {
+        let entities = self.entities()?;
+
+        if !entities.is_alive(entity) {
+            return Err(error::AddComponent::EntityIsNotAlive.into());
         }
+
+        let mut storages = component.borrow_storages(self)?;
+
+        component.check_storages_capacity(&storages, entity)?;
+        component.add_to_storages(&mut storages, entity, self.get_current());
+
+        Ok(())
     }
     /// Deletes components from an entity. As opposed to `remove`, `delete` doesn't return anything.  
     /// `C` must always be a tuple, even for a single component.
@@ -674,8 +1415,9 @@ impl AllStorages {
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
         C::delete(self, entity);
     }
-    /// Removes components from an entity.  
-    /// `C` must always be a tuple, even for a single component.
+    /// Removes components from an entity.\
+    /// `C` can be a single component or a tuple of components.\
+    /// Components the entity doesn't have are returned as `None`.
     ///
     /// ### Example
     ///
@@ -700,6 +1442,57 @@ impl AllStorages {
     pub fn remove<C: TupleRemove>(&mut self, entity: EntityId) -> C::Out {
         C::remove(self, entity)
     }
+    /// Reorders the storages in `C` so components of entities present in all of them are at the start, in the same order.\
+    /// `C` must be a tuple of components.
+    ///
+    /// The first [`packed_len`] components of each storage can then be zipped directly.\
+    /// Iterating these storages together skips entity lookups as long as one of them only holds packed components.
+    ///
+    /// Adding components keeps the pack, removing, deleting or sorting packed components unpacks all storages of the pack.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity(U32(0));
+    /// all_storages.add_entity((U32(1), USIZE(1)));
+    ///
+    /// all_storages.tight_pack::<(U32, USIZE)>();
+    /// assert!(all_storages.is_packed::<(U32, USIZE)>());
+    ///
+    /// let (u32s, usizes) = all_storages.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    /// let len = u32s.packed_len().unwrap();
+    ///
+    /// for (i, j) in u32s.as_slice()[..len].iter().zip(&usizes.as_slice()[..len]) {
+    ///     assert_eq!(i.0 as usize, j.0);
+    /// }
+    /// ```
+    ///
+    /// [`packed_len`]: crate::sparse_set::SparseSet::packed_len
+    pub fn tight_pack<C: TuplePack>(&mut self) {
+        C::tight_pack(self);
+    }
+    /// Returns `true` if the storages in `C` are still packed together.\
+    /// `C` must be a tuple of components.
+    ///
+    /// Returns `false` if one of the storages doesn't exist or is mutably borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - `C` storages (shared)
+    pub fn is_packed<C: TuplePack>(&self) -> bool {
+        C::is_packed(self)
+    }
     #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
 You can use a tuple to get multiple storages at once.
 
@@ -1097,6 +1890,19 @@ let i = all_storages.run(sys1);
             Err(err) => Err(error::GetStorage::Entities(err)),
         }
     }
+    /// Returns the pack of `T`'s storage without creating it.\
+    /// Returns `None` if the storage doesn't exist or is mutably borrowed.
+    pub(crate) fn sparse_set_pack<T: Component + Send + Sync>(&self) -> Option<TightPack> {
+        let storages = self.storages.read();
+        let storage = storages.get(&StorageId::of::<SparseSet<T>>())?;
+        let storage = unsafe { &*storage.0 }.borrow().ok()?;
+        drop(storages);
+
+        storage
+            .as_any()
+            .downcast_ref::<SparseSet<T>>()
+            .and_then(|sparse_set| sparse_set.pack.clone())
+    }
     pub(crate) fn exclusive_storage_mut<T: 'static>(
         &mut self,
     ) -> Result<&mut T, error::GetStorage> {
@@ -1135,7 +1941,7 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new(f()))
+                .or_insert_with(|| SBox::new(create_storage(self.fixed_capacity, f)))
                 .0
         }
         .get_mut()
@@ -1159,7 +1965,12 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                .or_insert_with(|| {
+                    SBox::new_non_send(
+                        create_storage(self.fixed_capacity, f),
+                        self.thread_id_generator.clone(),
+                    )
+                })
                 .0
         }
         .get_mut()
@@ -1182,7 +1993,7 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_sync(f()))
+                .or_insert_with(|| SBox::new_non_sync(create_storage(self.fixed_capacity, f)))
                 .0
         }
         .get_mut()
@@ -1206,7 +2017,12 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send_sync(f(), self.thread_id_generator.clone()))
+                .or_insert_with(|| {
+                    SBox::new_non_send_sync(
+                        create_storage(self.fixed_capacity, f),
+                        self.thread_id_generator.clone(),
+                    )
+                })
                 .0
         }
         .get_mut()
@@ -1214,18 +2030,250 @@ let i = all_storages.run(sys1);
         .downcast_mut()
         .unwrap()
     }
-    /// Make the given entity alive.  
-    /// Does nothing if an entity with a greater generation is already at this index.  
-    /// Returns `true` if the entity is successfully spawned.
-    #[inline]
-    pub fn spawn(&mut self, entity: EntityId) -> bool {
-        self.exclusive_storage_mut::<Entities>()
-            .unwrap()
-            .spawn(entity)
+    /// Make the given entity alive.  
+    /// Does nothing if an entity with a greater generation is already at this index.  
+    /// Returns `true` if the entity is successfully spawned.
+    ///
+    /// When the `World` has a fixed capacity, `entity`'s index has to be lower than the capacity.
+    #[inline]
+    pub fn spawn(&mut self, entity: EntityId) -> bool {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .spawn(entity)
+    }
+    /// Displays storages memory information.
+    pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
+        AllStoragesMemoryUsage(self)
+    }
+    /// Shrinks all storages using less than `threshold` of their allocated memory.\
+    /// `threshold` is a ratio of used over allocated bytes, `0.5` shrinks storages using less than half their memory.
+    ///
+    /// Returns the memory reclaimed by each shrunk storage.\
+    /// Worlds created with a fixed capacity keep the memory they reserved upfront and are never compacted.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entities = all_storages
+    ///     .bulk_add_entity((0..1000).map(|i| (Health(i),)))
+    ///     .collect::<Vec<_>>();
+    /// for entity in &entities[1..] {
+    ///     all_storages.delete_entity(*entity);
+    /// }
+    ///
+    /// let report = all_storages.compact(0.5);
+    ///
+    /// assert!(report
+    ///     .iter()
+    ///     .any(|compaction| compaction.storage_name.contains("Health")
+    ///         && compaction.reclaimed_memory_bytes > 0));
+    /// ```
+    pub fn compact(&mut self, threshold: f32) -> Vec<StorageCompaction> {
+        let mut report = Vec::new();
+
+        if self.fixed_capacity.is_some() {
+            return report;
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            let before = match storage.memory_usage() {
+                Some(before) => before,
+                None => continue,
+            };
+
+            if before.allocated_memory_bytes == 0
+                || before.used_memory_bytes as f32 / before.allocated_memory_bytes as f32
+                    >= threshold
+            {
+                continue;
+            }
+
+            storage.shrink_to_fit();
+
+            let after = storage
+                .memory_usage()
+                .map_or(0, |usage| usage.allocated_memory_bytes);
+
+            report.push(StorageCompaction {
+                storage_name: before.storage_name,
+                reclaimed_memory_bytes: before.allocated_memory_bytes.saturating_sub(after),
+            });
+        }
+
+        report
+    }
+    /// Returns a human-readable description of all storages, sorted by name.\
+    /// Each storage shows its number of components and, if registered with [`AllStorages::register_debug`],
+    /// up to `max_per_storage` components.
+    ///
+    /// Storages already borrowed are listed without their content.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Pos(i32, i32);
+    ///
+    /// #[derive(Component)]
+    /// struct Vel(i32, i32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.register_debug::<Pos>();
+    ///
+    /// all_storages.add_entity((Pos(0, 0), Vel(1, 1)));
+    /// all_storages.add_entity((Pos(1, 2),));
+    ///
+    /// let dump = all_storages.debug_dump(1);
+    ///
+    /// assert!(dump.contains("Pos(0, 0)"));
+    /// assert!(!dump.contains("Pos(1, 2)"));
+    /// assert!(dump.contains("... 1 more"));
+    /// ```
+    pub fn debug_dump(&self, max_per_storage: usize) -> String {
+        use core::fmt::Write;
+
+        let storages = self.storages.read();
+        let mut lines = storages
+            .iter()
+            .map(|(storage_id, storage)| {
+                let mut line = String::new();
+
+                match unsafe { &*(storage.0) }.borrow() {
+                    Ok(storage) => {
+                        let _ = write!(line, "{}", storage.name());
+
+                        if let Some(len) = storage.len() {
+                            let _ = write!(line, ": {} components", len);
+
+                            if let Some(components) = storage.debug_components(max_per_storage) {
+                                for (entity, component) in &components {
+                                    let _ = write!(line, "\n  {:?}: {}", entity, component);
+                                }
+
+                                if len > components.len() {
+                                    let _ = write!(line, "\n  ... {} more", len - components.len());
+                                }
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        let _ = write!(line, "{:?}: borrowed", storage_id);
+                    }
+                }
+
+                line
+            })
+            .collect::<Vec<_>>();
+
+        lines.sort_unstable();
+
+        lines.join("\n")
+    }
+    /// Returns the number of alive entities.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let eid = all_storages.add_entity(());
+    /// all_storages.add_entity(());
+    /// all_storages.delete_entity(eid);
+    ///
+    /// assert_eq!(all_storages.entity_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn entity_count(&self) -> usize {
+        self.entities().unwrap().alive_count()
+    }
+    /// Returns the number of component storages.\
+    /// `Entities` and unique storages are not counted, borrowed storages are.
+    pub fn storage_count(&self) -> usize {
+        self.storages
+            .read()
+            .values()
+            .filter(|storage| storage.is_component())
+            .count()
     }
-    /// Displays storages memory information.
-    pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
-        AllStoragesMemoryUsage(self)
+    /// Returns the [`StorageId`] of all component storages, sorted.\
+    /// `Entities` and unique storages are not included, borrowed storages are.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::advanced::StorageId;
+    /// use shipyard::sparse_set::SparseSet;
+    /// use shipyard::{AllStoragesViewMut, Component, Unique, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Unique)]
+    /// struct Time(f32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity(Health(100));
+    /// all_storages.add_unique(Time(0.0));
+    ///
+    /// let storage_ids = all_storages.component_storage_ids();
+    ///
+    /// assert_eq!(storage_ids, [StorageId::of::<SparseSet<Health>>()]);
+    /// assert!(all_storages
+    ///     .storage_name(storage_ids[0])
+    ///     .unwrap()
+    ///     .contains("Health"));
+    /// ```
+    pub fn component_storage_ids(&self) -> Vec<StorageId> {
+        let storages = self.storages.read();
+        let mut storage_ids = storages
+            .iter()
+            .filter(|(_, storage)| storage.is_component())
+            .map(|(storage_id, _)| *storage_id)
+            .collect::<Vec<_>>();
+
+        storage_ids.sort_unstable();
+
+        storage_ids
+    }
+    /// Returns the name of the storage identified by `storage_id`.\
+    /// Returns `None` if there is no such storage or if it can't be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - `storage_id`'s storage (shared)
+    pub fn storage_name(&self, storage_id: StorageId) -> Option<Cow<'static, str>> {
+        let storages = self.storages.read();
+        let storage = storages.get(&storage_id)?;
+        let storage = unsafe { &*(storage.0) }.borrow().ok()?;
+
+        Some(storage.name())
     }
 
     #[inline]
@@ -1559,6 +2607,8 @@ for (i, j) in &mut iter {
                 other_current,
             );
         }
+
+        other.dispatch_observers();
     }
 
     /// Moves all components from an entity to another in another `World`.
@@ -1624,6 +2674,181 @@ for (i, j) in &mut iter {
                 other_current,
             );
         }
+
+        other.dispatch_observers();
+    }
+
+    /// Moves an entity and the components of `storages` to another `World`.\
+    /// The entity gets a new id in `other`, it is returned.\
+    /// Components in other storages are deleted alongside the entity.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `other` has a fixed capacity and all entity indices are in use.
+    /// - `other` has a fixed capacity and the storage of one of the moved components is full.
+    ///
+    /// Nothing is moved or deleted when an error is returned.
+    ///
+    /// ```
+    /// use shipyard::{advanced::StorageId, AllStoragesViewMut, Component, sparse_set::SparseSet, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages1.add_entity((U32(0), USIZE(1)));
+    ///
+    /// let new_entity = all_storages1.transfer_entity(
+    ///     &mut all_storages2,
+    ///     entity,
+    ///     &[StorageId::of::<SparseSet<USIZE>>()],
+    /// ).unwrap();
+    ///
+    /// assert!(!all_storages1.is_entity_alive(entity));
+    /// assert!(all_storages2.get::<&U32>(new_entity).is_err());
+    /// assert_eq!(all_storages2.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    pub fn transfer_entity(
+        &mut self,
+        other: &mut AllStorages,
+        entity: EntityId,
+        storages: &[StorageId],
+    ) -> Result<EntityId, error::TransferEntity> {
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return Err(error::TransferEntity::EntityIsNotAlive);
+        }
+
+        if other.fixed_capacity.is_some() {
+            if other.exclusive_storage_mut::<Entities>().unwrap().is_full() {
+                return Err(error::TransferEntity::EntitiesFull);
+            }
+
+            for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+                if storages.contains(storage_id) {
+                    unsafe { &mut *storage.0 }
+                        .get_mut()
+                        .check_move_capacity(other, entity)?;
+                }
+            }
+        }
+
+        let current = self.get_current();
+        let other_current = other.get_current();
+
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .delete_unchecked(entity);
+
+        let new_entity = other
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .generate();
+
+        for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            if storages.contains(storage_id) {
+                storage.move_component_from(other, entity, new_entity, current, other_current);
+            } else {
+                storage.delete(entity, current);
+            }
+        }
+
+        other.dispatch_observers();
+
+        Ok(new_entity)
+    }
+
+    /// Moves entities and the components of `storages` to another `World`.\
+    /// Entities get a new id in `other`, each `(old_id, new_id)` pair is added to `remap`.\
+    /// Components in other storages are deleted alongside the entities.
+    ///
+    /// Components storing `EntityId`s can then be fixed up using `remap`.
+    ///
+    /// ### Errors
+    ///
+    /// - One of the entities can't be transferred, see [`transfer_entity`](AllStorages::transfer_entity).\
+    ///   The entities before it are transferred and added to `remap`, the ones after it are left untouched.
+    ///
+    /// ```
+    /// use shipyard::{advanced::StorageId, AllStoragesViewMut, Component, EntityId, sparse_set::SparseSet, World};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Target(EntityId);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity1 = all_storages1.add_entity(());
+    /// let entity2 = all_storages1.add_entity(Target(entity1));
+    ///
+    /// let mut remap = HashMap::new();
+    /// all_storages1.transfer_entities(
+    ///     &mut all_storages2,
+    ///     [entity1, entity2],
+    ///     &[StorageId::of::<SparseSet<Target>>()],
+    ///     &mut remap,
+    /// ).unwrap();
+    ///
+    /// let new_entity2 = remap[&entity2];
+    /// let mut target = all_storages2.get::<&mut Target>(new_entity2).unwrap();
+    /// target.0 = remap[&target.0];
+    ///
+    /// assert_eq!(**target, Target(remap[&entity1]));
+    /// ```
+    pub fn transfer_entities(
+        &mut self,
+        other: &mut AllStorages,
+        entities: impl IntoIterator<Item = EntityId>,
+        storages: &[StorageId],
+        remap: &mut impl Extend<(EntityId, EntityId)>,
+    ) -> Result<(), error::TransferEntity> {
+        for entity in entities {
+            let new_entity = self.transfer_entity(other, entity, storages)?;
+            remap.extend(core::iter::once((entity, new_entity)));
+        }
+
+        Ok(())
+    }
+
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for `capacity` components.\
+    /// Adding components will not reallocate until `capacity` is reached.\
+    /// A `capacity` of 0 only creates the storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.register_with_capacity::<U32>(1000);
+    /// ```
+    #[track_caller]
+    pub fn register_with_capacity<T: Component + Send + Sync>(&mut self, capacity: usize) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .reserve_total(capacity);
     }
 
     /// Registers the function to clone these components.
@@ -1632,6 +2857,57 @@ for (i, j) in &mut iter {
         T::register_clone(self);
     }
 
+    /// Shows `T` components in [`AllStorages::debug_dump`].
+    #[inline]
+    pub fn register_debug<T: Component + Send + Sync + core::fmt::Debug>(&mut self) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .register_debug();
+    }
+
+    /// Collects `T` components in the [`EntityComponents`] returned by [`AllStorages::delete_entity_collect`].
+    #[inline]
+    pub fn register_take<T: Component + Send + Sync>(&mut self) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .register_take();
+    }
+
+    /// Registers `f` to upgrade `Old`, a previous serialization format of `New`, when deserializing.
+    ///
+    /// See [`World::register_component_migrate`](crate::World::register_component_migrate).
+    #[cfg(feature = "serde1")]
+    #[track_caller]
+    pub fn register_component_migrate<Old, New>(
+        &mut self,
+        f: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        Old: serde::de::DeserializeOwned,
+        New: Component + Send + Sync,
+    {
+        self.exclusive_storage_or_insert_mut(
+            StorageId::of::<SparseSet<New>>(),
+            SparseSet::<New>::new,
+        )
+        .register_migration(f);
+    }
+
+    /// Includes `T` components in [`AllStorages::serialize_registered`] and [`AllStorages::deserialize_registered`] under `name`.\
+    /// Registering the same component multiple times with the same name has no effect.
+    ///
+    /// See [`World::register_serde`](crate::World::register_serde).
+    ///
+    /// ### Panics
+    ///
+    /// - `T` is already registered under a different name.
+    /// - `name` is already used by another component.
+    #[cfg(feature = "serde1")]
+    #[track_caller]
+    pub fn register_serde<T>(&mut self, name: &'static str)
+    where
+        T: Component + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.serde_registry.register::<T>(name);
+    }
+
     /// Clones all storages with a registered clone function from this `AllStorages` to `other`.
     ///
     /// Tracking is not cloned. Components will count as inserted in `other`.
@@ -1771,6 +3047,59 @@ for (i, j) in &mut iter {
     }
 }
 
+/// Keeps a lazy unique's slot borrowed while its initializer runs.\
+/// If the initializer unwinds the slot is removed, the unique is then missing instead of never getting a value.
+struct LazyInit<'a> {
+    all_storages: &'a AllStorages,
+    storage_id: StorageId,
+    lazy_ptr: *mut AtomicRefCell<dyn Storage>,
+    slot: Option<ARefMut<'a, &'a mut (dyn Storage + 'static)>>,
+}
+
+impl LazyInit<'_> {
+    /// Releases the slot once the initializer returned.
+    fn finish(mut self) {
+        self.slot = None;
+    }
+}
+
+impl Drop for LazyInit<'_> {
+    fn drop(&mut self) {
+        // `finish` wasn't called, the initializer unwound
+        let Some(slot) = self.slot.take() else {
+            return;
+        };
+
+        drop(slot);
+
+        let mut storages = self.all_storages.storages.write();
+
+        if let Entry::Occupied(entry) = storages.entry(self.storage_id) {
+            let storage = entry.get().0;
+
+            if storage.cast::<()>() == self.lazy_ptr.cast::<()>()
+                && unsafe { &*storage }.borrow_mut().is_ok()
+            {
+                entry.remove();
+            }
+        }
+    }
+}
+
+/// Creates a storage with `f`, limited to `fixed_capacity` when the `World` has one.
+pub(crate) fn create_storage<S: Storage>(
+    fixed_capacity: Option<usize>,
+    f: impl FnOnce() -> S,
+) -> S {
+    let mut storage = f();
+
+    if let Some(capacity) = fixed_capacity {
+        storage.set_fixed_capacity(capacity);
+    }
+
+    storage
+}
+
 impl core::fmt::Debug for AllStorages {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut debug_struct = f.debug_struct("AllStorages");
@@ -1904,4 +3233,125 @@ impl AllStorages {
             Err(err) => Err(error::Deserialize::Borrow(err)),
         }
     }
+
+    /// Serializes all storages registered with [`AllStorages::register_serde`], other storages are skipped.
+    ///
+    /// Storages are serialized as a map from their registered name to their ids and components, in registration order.\
+    /// Each storage is borrowed while it is written to `serializer`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Registered storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - Serialization error.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, serde::Serialize, serde::Deserialize)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Component)]
+    /// struct Cache(Vec<u8>);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.register_serde::<Name>("name");
+    /// all_storages.add_entity((Name("Alice".to_string()), Cache(Vec::new())));
+    ///
+    /// let mut serialized = Vec::new();
+    /// all_storages
+    ///     .serialize_registered(&mut serde_json::ser::Serializer::new(&mut serialized))
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// let serialized_str = String::from_utf8(serialized).unwrap();
+    /// assert_eq!(serialized_str, r#"{"name":[[{"index":0,"gen":0},"Alice"]]}"#);
+    /// ```
+    pub fn serialize_registered<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, error::Serialize<S>> {
+        use serde::ser::SerializeMap;
+
+        let borrow_error = core::cell::Cell::new(None);
+
+        let mut map = serializer
+            .serialize_map(Some(self.serde_registry.iter().len()))
+            .map_err(error::Serialize::Serialization)?;
+
+        for entry in self.serde_registry.iter() {
+            let storage = RegisteredStorage {
+                all_storages: self,
+                serialize: entry.serialize,
+                borrow_error: &borrow_error,
+            };
+
+            if let Err(err) = map.serialize_entry(entry.name, &storage) {
+                return Err(match borrow_error.take() {
+                    Some(err) => error::Serialize::Borrow(err),
+                    None => error::Serialize::Serialization(err),
+                });
+            }
+        }
+
+        map.end().map_err(error::Serialize::Serialization)
+    }
+
+    /// Deserializes storages serialized with [`AllStorages::serialize_registered`].
+    ///
+    /// Only storages registered with [`AllStorages::register_serde`] are deserialized, others are skipped.\
+    /// Components are added the same way as [`AllStorages::deserialize`], entities are not made alive.
+    ///
+    /// ### Borrows
+    ///
+    /// - Registered storages present in `deserializer` (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - Deserialization error.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, EntityId, World};
+    ///
+    /// #[derive(Component, serde::Serialize, serde::Deserialize)]
+    /// struct Name(String);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.register_serde::<Name>("name");
+    ///
+    /// let serialized = r#"{"name":[[{"index":0,"gen":0},"Alice"]],"unknown":[]}"#;
+    /// all_storages
+    ///     .deserialize_registered(&mut serde_json::de::Deserializer::from_str(serialized))
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// let alice_eid = EntityId::new_from_index_and_gen(0, 0);
+    /// assert_eq!(all_storages.get::<&Name>(alice_eid).unwrap().0, "Alice");
+    /// ```
+    pub fn deserialize_registered<'de, D: serde::Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<(), error::Deserialize<'de, D>> {
+        let borrow_error = core::cell::Cell::new(None);
+
+        deserializer
+            .deserialize_map(RegisteredStoragesVisitor {
+                all_storages: self,
+                borrow_error: &borrow_error,
+            })
+            .map_err(|err| match borrow_error.take() {
+                Some(err) => error::Deserialize::Borrow(err),
+                None => error::Deserialize::Deserialization(err),
+            })
+    }
 }
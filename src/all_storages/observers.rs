@@ -0,0 +1,118 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::AtomicRefCell;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of dispatch rounds after which observers are considered to be inserting components endlessly.
+const OBSERVER_DEPTH_LIMIT: usize = 64;
+
+type InsertObserver = Box<dyn Fn(&mut AllStorages, EntityId) + Send + Sync>;
+
+/// Observers registered with [`World::observe_insert`](crate::World::observe_insert), in registration order.\
+/// Held by `AllStorages` so insertions made through it can be dispatched before returning.
+#[derive(Default)]
+pub(crate) struct Observers {
+    observed: Vec<ObservedStorage>,
+    /// Set by the storages' insertion callbacks, cleared when all insertions are dispatched
+    pending: Arc<AtomicBool>,
+}
+
+struct ObservedStorage {
+    storage_id: StorageId,
+    insertions: Arc<InsertionQueue>,
+    on_insert: Vec<InsertObserver>,
+}
+
+/// Entities inserted in an observed storage, filled by the storage's insertion callback.
+///
+/// Insertions require a borrow of `AllStorages` and dispatching an exclusive one so the queue is never borrowed twice.
+struct InsertionQueue(AtomicRefCell<Vec<EntityId>>);
+
+// With thread_local `AtomicRefCell` is only `Send` when it can't hold `!Send` values,
+// this one holds `EntityId`s and never checks the thread it's borrowed from.
+#[cfg(feature = "thread_local")]
+unsafe impl Send for InsertionQueue {}
+
+impl Observers {
+    /// Registers `f` and, the first time `T` is observed, hooks `T`'s insertion observer.
+    pub(crate) fn observe_insert<T: Component + Send + Sync>(
+        &mut self,
+        all_storages: &mut AllStorages,
+        f: impl Fn(&mut AllStorages, EntityId) + Send + Sync + 'static,
+    ) {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        if let Some(observed) = self
+            .observed
+            .iter_mut()
+            .find(|observed| observed.storage_id == storage_id)
+        {
+            observed.on_insert.push(Box::new(f));
+
+            return;
+        }
+
+        let insertions = Arc::new(InsertionQueue(AtomicRefCell::new(Vec::new())));
+        let queue = insertions.clone();
+        let pending = self.pending.clone();
+
+        let sparse_set =
+            all_storages.exclusive_storage_or_insert_mut(storage_id, SparseSet::<T>::new);
+        sparse_set.set_insertion_observer(move |entity| {
+            queue.0.borrow_mut().unwrap().push(entity);
+            pending.store(true, Ordering::Release);
+        });
+
+        self.observed.push(ObservedStorage {
+            storage_id,
+            insertions,
+            on_insert: vec![Box::new(f)],
+        });
+    }
+    /// Returns `true` if insertions are waiting to be dispatched.
+    #[inline]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::Acquire)
+    }
+    /// Calls the observers of all insertions recorded since the last run.
+    ///
+    /// Storages are visited in registration order and their insertions in the order they happened.\
+    /// Insertions made by observers are dispatched in the following rounds, until none is left.
+    ///
+    /// After [`OBSERVER_DEPTH_LIMIT`] rounds the remaining insertions are dropped and an error is returned.
+    pub(crate) fn run(&self, all_storages: &mut AllStorages) -> Result<(), error::Observe> {
+        for _ in 0..OBSERVER_DEPTH_LIMIT {
+            if !self.pending.swap(false, Ordering::Acquire) {
+                return Ok(());
+            }
+
+            for observed in &self.observed {
+                let insertions = core::mem::take(&mut *observed.insertions.0.borrow_mut().unwrap());
+
+                for entity in insertions {
+                    for on_insert in &observed.on_insert {
+                        on_insert(all_storages, entity);
+                    }
+                }
+            }
+        }
+
+        if self.pending.swap(false, Ordering::Acquire) {
+            for observed in &self.observed {
+                observed.insertions.0.borrow_mut().unwrap().clear();
+            }
+
+            return Err(error::Observe::DepthLimit);
+        }
+
+        Ok(())
+    }
+}
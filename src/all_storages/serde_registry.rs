@@ -0,0 +1,184 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::views::serde::de::view_mut::ViewMutDeserializer;
+use crate::views::{View, ViewMut};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt;
+use serde::de::{DeserializeOwned, DeserializeSeed, Error as _, IgnoredAny, MapAccess, Visitor};
+use serde::ser::Error as _;
+use serde::Serialize;
+
+/// Borrows the storage and passes it to the callback.
+type SerializeFn =
+    fn(&AllStorages, &mut dyn FnMut(&dyn erased_serde::Serialize)) -> Result<(), error::GetStorage>;
+/// Borrows the storage and deserializes its components from the deserializer.
+type DeserializeFn = for<'de> fn(
+    &AllStorages,
+    &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<Result<(), erased_serde::Error>, error::GetStorage>;
+
+/// Storages registered with [`AllStorages::register_serde`], in registration order.
+#[derive(Default)]
+pub(crate) struct SerdeRegistry(Vec<SerdeEntry>);
+
+pub(crate) struct SerdeEntry {
+    pub(crate) name: &'static str,
+    storage_id: StorageId,
+    pub(crate) serialize: SerializeFn,
+    pub(crate) deserialize: DeserializeFn,
+}
+
+impl SerdeRegistry {
+    /// Registers `T`'s storage under `name`, does nothing if it's already registered under the same name.
+    #[track_caller]
+    pub(crate) fn register<T>(&mut self, name: &'static str)
+    where
+        T: Component + Send + Sync + Serialize + DeserializeOwned,
+    {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        for entry in &self.0 {
+            if entry.storage_id == storage_id {
+                assert!(
+                    entry.name == name,
+                    "{} is already registered as \"{}\".",
+                    core::any::type_name::<T>(),
+                    entry.name
+                );
+
+                return;
+            }
+
+            assert!(
+                entry.name != name,
+                "\"{}\" is already used by another storage.",
+                name
+            );
+        }
+
+        self.0.push(SerdeEntry {
+            name,
+            storage_id,
+            serialize: serialize::<T>,
+            deserialize: deserialize::<T>,
+        });
+    }
+    pub(crate) fn iter(&self) -> core::slice::Iter<'_, SerdeEntry> {
+        self.0.iter()
+    }
+    pub(crate) fn get(&self, name: &str) -> Option<&SerdeEntry> {
+        self.0.iter().find(|entry| entry.name == name)
+    }
+}
+
+fn serialize<T: Component + Send + Sync + Serialize>(
+    all_storages: &AllStorages,
+    f: &mut dyn FnMut(&dyn erased_serde::Serialize),
+) -> Result<(), error::GetStorage> {
+    let view = all_storages.borrow::<View<T>>()?;
+
+    f(&view);
+
+    Ok(())
+}
+
+fn deserialize<'de, T: Component + Send + Sync + DeserializeOwned>(
+    all_storages: &AllStorages,
+    deserializer: &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<Result<(), erased_serde::Error>, error::GetStorage> {
+    let mut view = all_storages.borrow::<ViewMut<T>>()?;
+
+    Ok(ViewMutDeserializer::new(&mut view).deserialize(deserializer))
+}
+
+/// Serializes a registered storage, borrowing it only while it's written.
+///
+/// Borrow errors are stored in `borrow_error` to be reported as [`error::Serialize::Borrow`].
+pub(crate) struct RegisteredStorage<'a> {
+    pub(crate) all_storages: &'a AllStorages,
+    pub(crate) serialize: SerializeFn,
+    pub(crate) borrow_error: &'a Cell<Option<error::GetStorage>>,
+}
+
+impl Serialize for RegisteredStorage<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializer = Some(serializer);
+        let mut result = None;
+
+        (self.serialize)(self.all_storages, &mut |storage| {
+            result = serializer
+                .take()
+                .map(|serializer| erased_serde::serialize(storage, serializer));
+        })
+        .map_err(|err| {
+            let error = S::Error::custom(&err);
+            self.borrow_error.set(Some(err));
+            error
+        })?;
+
+        result.unwrap()
+    }
+}
+
+/// Deserializes a map of registered storages, skipping the ones that aren't registered.
+///
+/// Borrow errors are stored in `borrow_error` to be reported as [`error::Deserialize::Borrow`].
+pub(crate) struct RegisteredStoragesVisitor<'a> {
+    pub(crate) all_storages: &'a AllStorages,
+    pub(crate) borrow_error: &'a Cell<Option<error::GetStorage>>,
+}
+
+impl<'de> Visitor<'de> for RegisteredStoragesVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map of storages")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        // serde doesn't like to deserialize field names using &str
+        // when there are escape characters
+        while let Some(name) = map.next_key::<String>()? {
+            match self.all_storages.serde_registry.get(&name) {
+                Some(entry) => map.next_value_seed(RegisteredStorageSeed {
+                    all_storages: self.all_storages,
+                    deserialize: entry.deserialize,
+                    borrow_error: self.borrow_error,
+                })?,
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct RegisteredStorageSeed<'a> {
+    all_storages: &'a AllStorages,
+    deserialize: DeserializeFn,
+    borrow_error: &'a Cell<Option<error::GetStorage>>,
+}
+
+impl<'de> DeserializeSeed<'de> for RegisteredStorageSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+
+        match (self.deserialize)(self.all_storages, &mut deserializer) {
+            Ok(result) => result.map_err(D::Error::custom),
+            Err(err) => {
+                let error = D::Error::custom(&err);
+                self.borrow_error.set(Some(err));
+                Err(error)
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::alloc::System;
+
+/// Number of profiled runs in progress, allocations are only counted while it isn't `0`.
+static PROFILED_RUNS: AtomicUsize = AtomicUsize::new(0);
+/// Bytes allocated by all threads while a run was profiled.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator counting the bytes allocated while a workload is profiled.
+///
+/// Has to be registered with `#[global_allocator]` for [`World::run_workload_alloc_profile`] to report anything.\
+/// Allocations made by any thread during a profiled run are counted, including rayon's worker threads.\
+/// Reallocations count their new size.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::alloc_profile::CountingAllocator;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator<System> = CountingAllocator(System);
+/// ```
+///
+/// [`World::run_workload_alloc_profile`]: crate::World::run_workload_alloc_profile()
+pub struct CountingAllocator<A = System>(pub A);
+
+impl<A> CountingAllocator<A> {
+    #[inline]
+    fn count(size: usize) {
+        if PROFILED_RUNS.load(Ordering::Relaxed) != 0 {
+            ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+
+        self.0.alloc(layout)
+    }
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+
+        self.0.alloc_zeroed(layout)
+    }
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout)
+    }
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count(new_size);
+
+        self.0.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Counts allocations from all threads until dropped.
+pub(crate) struct ProfiledRun(());
+
+impl ProfiledRun {
+    pub(crate) fn start() -> ProfiledRun {
+        PROFILED_RUNS.fetch_add(1, Ordering::Relaxed);
+
+        ProfiledRun(())
+    }
+    /// Returns the number of bytes allocated by all threads while a run was profiled.
+    ///
+    /// The count wraps on overflow, compare two values with `wrapping_sub`.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        ALLOCATED.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for ProfiledRun {
+    fn drop(&mut self) {
+        PROFILED_RUNS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::storage::Storage;
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use alloc::vec::Vec;
+use core::ptr::NonNull;
+
+/// Size of the first chunk allocated by a [`ScratchArena`].
+const MIN_CHUNK_SIZE: usize = 1024;
+
+/// Bump allocator backing the [`Arena`](crate::Arena) view.
+///
+/// Memory is handed out from the last chunk, a new chunk at least twice as big is allocated when it's full.\
+/// Resetting keeps the memory, merging all chunks into a single one the first time multiple chunks were used.
+#[derive(Default)]
+pub(crate) struct ScratchArena {
+    chunks: Vec<(NonNull<u8>, usize)>,
+    used: usize,
+}
+
+// SAFE the arena owns its chunks like a `Vec<Box<[u8]>>` would, they are only modified through `&mut self`
+unsafe impl Send for ScratchArena {}
+unsafe impl Sync for ScratchArena {}
+
+impl ScratchArena {
+    /// Returns a pointer to uninitialized memory fitting `layout`.
+    ///
+    /// The memory stays valid until the arena is reset or dropped.
+    pub(crate) fn alloc_layout(&mut self, layout: Layout) -> NonNull<u8> {
+        if let Some(&(ptr, size)) = self.chunks.last() {
+            if let Some(offset) = Self::fit(ptr, size, self.used, layout) {
+                self.used = offset + layout.size();
+
+                // SAFE offset is in bounds
+                return unsafe { NonNull::new_unchecked(ptr.as_ptr().add(offset)) };
+            }
+        }
+
+        let size = self
+            .chunks
+            .last()
+            .map_or(MIN_CHUNK_SIZE, |&(_, size)| size * 2)
+            .max(layout.size() + layout.align());
+        let ptr = Self::alloc_chunk(size);
+        self.chunks.push((ptr, size));
+
+        // a new chunk is always big enough
+        let offset = Self::fit(ptr, size, 0, layout).unwrap();
+        self.used = offset + layout.size();
+
+        // SAFE offset is in bounds
+        unsafe { NonNull::new_unchecked(ptr.as_ptr().add(offset)) }
+    }
+    /// Makes all the memory available again, without freeing it.
+    pub(crate) fn reset(&mut self) {
+        let chunks = &mut self.chunks;
+
+        if chunks.len() > 1 {
+            let size = chunks.iter().map(|&(_, size)| size).sum();
+
+            for (ptr, size) in chunks.drain(..) {
+                Self::dealloc_chunk(ptr, size);
+            }
+
+            chunks.push((Self::alloc_chunk(size), size));
+        }
+
+        self.used = 0;
+    }
+    /// Returns the offset of the first address after `used` aligned for `layout`, if it fits in the chunk.
+    fn fit(ptr: NonNull<u8>, size: usize, used: usize, layout: Layout) -> Option<usize> {
+        let address = ptr.as_ptr() as usize + used;
+        let offset = used + (address.wrapping_neg() & (layout.align() - 1));
+
+        if offset.checked_add(layout.size())? <= size {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+    fn alloc_chunk(size: usize) -> NonNull<u8> {
+        let layout = Layout::from_size_align(size, 1).unwrap();
+
+        // SAFE size is never zero
+        NonNull::new(unsafe { alloc(layout) }).unwrap_or_else(|| handle_alloc_error(layout))
+    }
+    fn dealloc_chunk(ptr: NonNull<u8>, size: usize) {
+        // SAFE the chunk was allocated with this layout
+        unsafe { dealloc(ptr.as_ptr(), Layout::from_size_align_unchecked(size, 1)) }
+    }
+}
+
+impl Drop for ScratchArena {
+    fn drop(&mut self) {
+        for (ptr, size) in self.chunks.drain(..) {
+            Self::dealloc_chunk(ptr, size);
+        }
+    }
+}
+
+impl Storage for ScratchArena {}
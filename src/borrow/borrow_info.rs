@@ -6,6 +6,8 @@ use super::non_send_sync::NonSendSync;
 use super::non_sync::NonSync;
 use super::Mutability;
 use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::arena::ScratchArena;
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::error;
@@ -16,9 +18,10 @@ use crate::system::Nothing;
 use crate::tracking::Tracking;
 use crate::unique::UniqueStorage;
 use crate::views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, Arena, Commands, EntitiesView, EntitiesViewMut,
+    UniqueView, UniqueViewMut, View, ViewMut, WorldView,
 };
+use crate::world::World;
 use alloc::vec::Vec;
 use core::any::type_name;
 
@@ -110,6 +113,22 @@ unsafe impl<'a> BorrowInfo for AllStoragesViewMut<'a> {
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
 }
 
+// The whole World can be reached so the system is scheduled as if it borrowed AllStorages exclusively
+unsafe impl<'a> BorrowInfo for WorldView<'a> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<World>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<AllStorages>(),
+            #[cfg(not(feature = "thread_local"))]
+            thread_safe: true,
+            #[cfg(feature = "thread_local")]
+            thread_safe: false,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
 unsafe impl<'a> BorrowInfo for EntitiesView<'a> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
         info.push(TypeInfo {
@@ -134,6 +153,34 @@ unsafe impl<'a> BorrowInfo for EntitiesViewMut<'a> {
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
 }
 
+unsafe impl<'a> BorrowInfo for Arena<'a> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<ScratchArena>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<ScratchArena>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a> BorrowInfo for Commands<'a> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<CommandQueue>().into(),
+            // With std the queue is behind a lock, each `Commands` only appends its own buffer
+            #[cfg(feature = "std")]
+            mutability: Mutability::Shared,
+            #[cfg(not(feature = "std"))]
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<CommandQueue>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
 unsafe impl<'a, T: Send + Sync + Component, Track> BorrowInfo for View<'a, T, Track>
 where
     Track: Tracking,
@@ -487,7 +534,10 @@ macro_rules! borrow_info {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-borrow_info![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+borrow_info![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 borrow_info![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
@@ -17,7 +17,9 @@ pub use non_sync::NonSync;
 pub use world_borrow::WorldBorrow;
 
 use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::arena::ScratchArena;
 use crate::atomic_refcell::{ARef, ARefMut, SharedBorrow};
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::error;
 use crate::sparse_set::SparseSet;
@@ -26,7 +28,11 @@ use crate::storage::StorageId;
 use crate::system::Nothing;
 use crate::tracking::{Tracking, TrackingTimestamp};
 use crate::unique::UniqueStorage;
-use crate::views::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
+use crate::views::{
+    Arena, Commands, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut,
+};
+use alloc::vec::Vec;
+use core::cell::RefCell;
 use core::marker::PhantomData;
 
 /// Describes if a storage is borrowed exclusively or not.  
@@ -76,6 +82,42 @@ pub enum Mutability {
 /// }
 /// ```
 ///
+/// ### Example of derive:
+///
+/// With the `proc` feature, `Borrow` and [`BorrowInfo`] can be derived for structs whose fields all implement them.\
+/// This is useful to group views and keep systems' signature short.\
+/// The derived `borrow_info` lists the borrows of all fields.
+/// ```rust
+/// use shipyard::{Borrow, BorrowInfo, Unique, UniqueView, UniqueViewMut, Workload, World};
+///
+/// #[derive(Unique)]
+/// struct Time(f32);
+///
+/// #[derive(Unique)]
+/// struct Score(u32);
+///
+/// #[derive(Borrow, BorrowInfo)]
+/// struct Resources<'v> {
+///     time: UniqueView<'v, Time>,
+///     score: UniqueViewMut<'v, Score>,
+/// }
+///
+/// fn update(mut resources: Resources) {
+///     if resources.time.0 > 1.0 {
+///         resources.score.0 += 1;
+///     }
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(Time(2.0));
+/// world.add_unique(Score(0));
+///
+/// Workload::new("").with_system(update).add_to_world(&world).unwrap();
+/// world.run_default_workload().unwrap();
+///
+/// assert_eq!(world.borrow::<UniqueView<Score>>().unwrap().0, 1);
+/// ```
+///
 /// [`World::borrow`]: crate::World::borrow
 /// [`World::run`]: crate::World::run
 pub trait Borrow {
@@ -166,6 +208,57 @@ impl Borrow for EntitiesViewMut<'_> {
     }
 }
 
+impl Borrow for Commands<'_> {
+    type View<'a> = Commands<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        #[cfg(feature = "std")]
+        let queue = all_storages.custom_storage_or_insert(CommandQueue::default)?;
+        #[cfg(not(feature = "std"))]
+        let queue = all_storages.custom_storage_or_insert_mut(CommandQueue::default)?;
+
+        #[cfg(feature = "std")]
+        let (queue, borrow) = unsafe { ARef::destructure(queue) };
+        #[cfg(not(feature = "std"))]
+        let (queue, borrow) = unsafe { ARefMut::destructure(queue) };
+
+        Ok(Commands {
+            queue,
+            buffer: Vec::new(),
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl Borrow for Arena<'_> {
+    type View<'a> = Arena<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let arena = all_storages.custom_storage_or_insert_mut(ScratchArena::default)?;
+
+        let (arena, borrow) = unsafe { ARefMut::destructure(arena) };
+
+        Ok(Arena {
+            arena: RefCell::new(arena),
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
 impl<T: Send + Sync + Component, Track> Borrow for View<'_, T, Track>
 where
     Track: Tracking,
@@ -412,7 +505,17 @@ impl<T: Send + Sync + Unique> Borrow for UniqueView<'_, T> {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::View<'a>, error::GetStorage> {
-        let view = all_storages.custom_storage()?;
+        let view = all_storages
+            .custom_storage::<UniqueStorage<T>>()
+            .or_else(|err| {
+                if matches!(err, error::GetStorage::MissingStorage { .. })
+                    && all_storages.init_lazy_unique::<T>()?
+                {
+                    all_storages.custom_storage()
+                } else {
+                    Err(err)
+                }
+            })?;
 
         let (unique, borrow) = unsafe { ARef::destructure(view) };
 
@@ -533,7 +636,17 @@ impl<T: Send + Sync + Unique> Borrow for UniqueViewMut<'_, T> {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::View<'a>, error::GetStorage> {
-        let view = all_storages.custom_storage_mut::<UniqueStorage<T>>()?;
+        let view = all_storages
+            .custom_storage_mut::<UniqueStorage<T>>()
+            .or_else(|err| {
+                if matches!(err, error::GetStorage::MissingStorage { .. })
+                    && all_storages.init_lazy_unique::<T>()?
+                {
+                    all_storages.custom_storage_mut()
+                } else {
+                    Err(err)
+                }
+            })?;
 
         let (unique, borrow) = unsafe { ARefMut::destructure(view) };
 
@@ -687,7 +800,10 @@ macro_rules! borrow {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-borrow![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+borrow![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 borrow![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
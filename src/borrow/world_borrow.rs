@@ -2,7 +2,7 @@ use crate::atomic_refcell::ARef;
 use crate::borrow::Borrow;
 use crate::error;
 use crate::tracking::TrackingTimestamp;
-use crate::views::{AllStoragesView, AllStoragesViewMut};
+use crate::views::{AllStoragesView, AllStoragesViewMut, WorldView};
 use crate::world::World;
 
 /// Allows a type to be borrowed by [`World::borrow`] and [`World::run`].
@@ -71,3 +71,16 @@ impl WorldBorrow for AllStoragesViewMut<'_> {
             .map_err(error::GetStorage::AllStoragesBorrow)
     }
 }
+
+impl WorldBorrow for WorldView<'_> {
+    type WorldView<'a> = WorldView<'a>;
+
+    #[inline]
+    fn world_borrow(
+        world: &World,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::WorldView<'_>, error::GetStorage> {
+        Ok(WorldView(world))
+    }
+}
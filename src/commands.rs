@@ -0,0 +1,65 @@
+use crate::all_storages::AllStorages;
+use crate::storage::Storage;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+
+/// A structural change recorded by [`Commands`](crate::Commands).
+pub(crate) type Command = Box<dyn FnOnce(&mut AllStorages) + Send + Sync>;
+
+/// Structural changes recorded by [`Commands`](crate::Commands), waiting to be applied.
+///
+/// Each [`Commands`](crate::Commands) records in its own buffer and appends it to the queue when dropped.\
+/// With the `std` feature the queue is behind a lock, systems using `Commands` only borrow it shared and can run in parallel.
+#[derive(Default)]
+pub(crate) struct CommandQueue {
+    #[cfg(feature = "std")]
+    commands: Mutex<Vec<Command>>,
+    #[cfg(not(feature = "std"))]
+    commands: Vec<Command>,
+}
+
+impl CommandQueue {
+    /// Moves `commands` at the end of the queue.
+    #[cfg(feature = "std")]
+    pub(crate) fn append(&self, commands: &mut Vec<Command>) {
+        self.commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .append(commands);
+    }
+    /// Moves `commands` at the end of the queue.
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn append(&mut self, commands: &mut Vec<Command>) {
+        self.commands.append(commands);
+    }
+    /// Removes all commands from the queue, in the order they were appended.
+    pub(crate) fn take(&mut self) -> Vec<Command> {
+        #[cfg(feature = "std")]
+        let commands = self
+            .commands
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        let commands = &mut self.commands;
+
+        core::mem::take(commands)
+    }
+}
+
+impl Storage for CommandQueue {
+    fn is_empty(&self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            self.commands
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_empty()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.commands.is_empty()
+        }
+    }
+}
@@ -38,7 +38,10 @@ use core::mem::size_of;
 pub struct Entities {
     pub(crate) data: Vec<EntityId>,
     list: Option<(usize, usize)>,
+    /// Number of alive entities
+    alive: usize,
     on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
+    fixed_capacity: Option<usize>,
 }
 
 impl Entities {
@@ -47,7 +50,9 @@ impl Entities {
         Entities {
             data: Vec::new(),
             list: None,
+            alive: 0,
             on_deletion: None,
+            fixed_capacity: None,
         }
     }
     /// Returns `true` if `entity` matches a living entity.
@@ -64,7 +69,7 @@ impl Entities {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive, use [`add_component_checked`](Entities::add_component_checked) to get an error instead.
     ///
     /// ### Example
     /// ```
@@ -95,6 +100,49 @@ impl Entities {
             panic!("{:?}", error::AddComponent::EntityIsNotAlive);
         }
     }
+    /// Adds `component` to `entity`, multiple components can be added at the same time using a tuple.\
+    /// `Entities` is only borrowed immutably.
+    ///
+    /// Same as [`add_component`](Entities::add_component) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive, `component` is dropped.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, EntitiesViewMut, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+    ///
+    /// let entity = entities.add_entity((), ());
+    /// entities.delete_unchecked(entity);
+    ///
+    /// assert_eq!(
+    ///     entities.add_component_checked(entity, &mut u32s, U32(0)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    pub fn add_component_checked<C, S: AddComponent<C>>(
+        &self,
+        entity: EntityId,
+        mut storages: S,
+        component: C,
+    ) -> Result<(), error::AddComponent> {
+        if self.is_alive(entity) {
+            storages.add_component_unchecked(entity, component);
+
+            Ok(())
+        } else {
+            Err(error::AddComponent::EntityIsNotAlive)
+        }
+    }
     /// Adds `component` to `entity`, multiple components can be added at the same time using a tuple.  
     /// If the entity already has this component, it won't be replaced. Very useful if you want accurate modification tracking.  
     /// `Entities` is only borrowed immutably.  
@@ -103,7 +151,7 @@ impl Entities {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive, use [`add_distinct_component_checked`](Entities::add_distinct_component_checked) to get an error instead.
     ///
     /// ### Example
     /// ```
@@ -135,7 +183,37 @@ impl Entities {
             panic!("{:?}", error::AddComponent::EntityIsNotAlive);
         }
     }
+    /// Adds `component` to `entity`, multiple components can be added at the same time using a tuple.\
+    /// If the entity already has this component, it won't be replaced.\
+    /// `Entities` is only borrowed immutably.
+    ///
+    /// Returns `Ok(true)` if the component was added.\
+    /// Same as [`add_distinct_component`](Entities::add_distinct_component) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive, `component` is dropped.
+    #[inline]
+    pub fn add_distinct_component_checked<S: AddDistinctComponent>(
+        &self,
+        entity: EntityId,
+        mut storages: S,
+        component: S::Component,
+    ) -> Result<bool, error::AddComponent> {
+        if self.is_alive(entity) {
+            Ok(storages.add_distinct_component_unchecked(entity, component))
+        } else {
+            Err(error::AddComponent::EntityIsNotAlive)
+        }
+    }
+    #[track_caller]
     pub(crate) fn generate(&mut self) -> EntityId {
+        if self.is_full() {
+            panic!("{:?}", error::AddEntity::EntitiesFull);
+        }
+
+        self.alive += 1;
+
         if let Some((new, ref mut old)) = self.list {
             let old_index = *old;
 
@@ -158,7 +236,25 @@ impl Entities {
             entity_id
         }
     }
+    /// Returns `true` if the `World` has a fixed capacity and [`generate`](Entities::generate) would need an index past it.
+    pub(crate) fn is_full(&self) -> bool {
+        self.list.is_none() && self.remaining_capacity() == 0
+    }
+    /// Returns how many new indices can be used before reaching the fixed capacity of the `World`.\
+    /// Returns `usize::MAX` if the `World` doesn't have a fixed capacity.
+    #[inline]
+    pub(crate) fn remaining_capacity(&self) -> usize {
+        self.fixed_capacity.map_or(usize::MAX, |capacity| {
+            capacity.saturating_sub(self.data.len())
+        })
+    }
+    #[track_caller]
     pub(crate) fn bulk_generate(&mut self, count: usize) -> &[EntityId] {
+        if count > self.remaining_capacity() {
+            panic!("{:?}", error::AddEntity::EntitiesFull);
+        }
+
+        self.alive += count;
         self.data
             .extend((self.data.len() as u64..(self.data.len() + count) as u64).map(EntityId::new));
 
@@ -168,6 +264,8 @@ impl Entities {
     /// If the entity has components, they will not be deleted and still be accessible using this id.
     pub fn delete_unchecked(&mut self, entity_id: EntityId) -> bool {
         if self.is_alive(entity_id) {
+            self.alive -= 1;
+
             // SAFE we checked for OOB
             if unsafe {
                 self.data
@@ -210,6 +308,10 @@ impl Entities {
     /// Stores `component` in a new entity and returns its [`EntityId`].  
     /// Multiple components can be added at the same time using a tuple.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and is full.
+    ///
     /// ### Example:
     /// ```
     /// use shipyard::{Component, EntitiesViewMut, ViewMut, World};
@@ -233,6 +335,7 @@ impl Entities {
     ///
     /// [`EntityId`]: crate::entity_id::EntityId
     #[inline]
+    #[track_caller]
     pub fn add_entity<T: AddEntity>(
         &mut self,
         mut storages: T,
@@ -245,6 +348,10 @@ impl Entities {
     /// Creates multiple new entities and returns an iterator yielding the new [`EntityId`]s.  
     /// Multiple components can be added at the same time using a tuple.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and there isn't enough room for all the new entities or their components.
+    ///
     /// ### Example
     ///
     /// ```
@@ -293,15 +400,164 @@ impl Entities {
             slice: &self.data[entities_len..],
         }
     }
+    /// Creates `n` entities occupying contiguous indices and returns an iterator yielding their ids.
+    ///
+    /// Dead slots waiting to be reused are skipped, all entities use fresh slots.\
+    /// Components can then be added to these entities in index order.
+    ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and there isn't enough room for `n` new entities.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{EntitiesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut entities = world.borrow::<EntitiesViewMut>().unwrap();
+    ///
+    /// let eid = entities.add_entity((), ());
+    /// entities.delete_unchecked(eid);
+    ///
+    /// let new_entities = entities.allocate_range(3).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     new_entities.iter().map(|eid| eid.index()).collect::<Vec<_>>(),
+    ///     [1, 2, 3]
+    /// );
+    /// assert!(new_entities.iter().all(|&eid| entities.is_alive(eid)));
+    /// assert_eq!(entities.recycled_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn allocate_range(&mut self, n: usize) -> BulkEntityIter<'_> {
+        let new_entities = self.bulk_generate(n);
+
+        BulkEntityIter {
+            iter: new_entities.iter().copied(),
+            slice: new_entities,
+        }
+    }
     /// Creates an iterator over all entities.
     #[inline]
     pub fn iter(&self) -> EntitiesIter<'_> {
         self.into_iter()
     }
+    /// Returns the number of slots ever allocated, alive or dead.\
+    /// This is the number of different indices handed out by this storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{EntitiesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut entities = world.borrow::<EntitiesViewMut>().unwrap();
+    ///
+    /// let eid = entities.add_entity((), ());
+    /// entities.add_entity((), ());
+    /// entities.delete_unchecked(eid);
+    ///
+    /// assert_eq!(entities.slot_count(), 2);
+    /// assert_eq!(entities.recycled_count(), 1);
+    ///
+    /// entities.add_entity((), ());
+    ///
+    /// assert_eq!(entities.slot_count(), 2);
+    /// assert_eq!(entities.recycled_count(), 0);
+    /// ```
+    #[inline]
+    pub fn slot_count(&self) -> usize {
+        self.data.len()
+    }
+    /// Returns the number of alive entities.
+    #[inline]
+    pub(crate) fn alive_count(&self) -> usize {
+        self.alive
+    }
+    /// Returns the number of dead slots waiting to be reused by new entities.\
+    /// Slots whose generation reached its maximum are never reused and not counted.
+    pub fn recycled_count(&self) -> usize {
+        if let Some((new, old)) = self.list {
+            let mut count = 1;
+            let mut index = old;
+
+            while index != new {
+                // SAFE indices in the list are always in bound
+                index = unsafe { self.data.get_unchecked(index).uindex() };
+                count += 1;
+            }
+
+            count
+        } else {
+            0
+        }
+    }
+    /// Reorders the dead slots waiting to be reused so new entities take the lowest indices first.
+    ///
+    /// Slots are normally reused in the order their entity was deleted.\
+    /// After this call they're reused by increasing index, keeping living entities packed at the start of the id range.\
+    /// Slots freed afterwards are reused once all the current ones are taken.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut entities = world.borrow::<EntitiesViewMut>().unwrap();
+    ///
+    /// let ids = (0..4).map(|_| entities.add_entity((), ())).collect::<Vec<_>>();
+    ///
+    /// entities.delete_unchecked(ids[3]);
+    /// entities.delete_unchecked(ids[1]);
+    /// entities.delete_unchecked(ids[2]);
+    ///
+    /// entities.recycle_all_dead();
+    ///
+    /// assert_eq!(entities.add_entity((), ()).index(), 1);
+    /// assert_eq!(entities.add_entity((), ()).index(), 2);
+    /// assert_eq!(entities.add_entity((), ()).index(), 3);
+    /// ```
+    pub fn recycle_all_dead(&mut self) {
+        let (new, old) = match self.list {
+            Some(list) => list,
+            None => return,
+        };
+
+        let mut indices = Vec::with_capacity(self.recycled_count());
+        let mut index = old;
+        indices.push(index);
+
+        while index != new {
+            index = self.data[index].uindex();
+            indices.push(index);
+        }
+
+        indices.sort_unstable();
+
+        for pair in indices.windows(2) {
+            self.data[pair[0]].set_index(pair[1] as u64);
+        }
+
+        let last = indices[indices.len() - 1];
+        self.data[last].set_index(EntityId::max_index());
+
+        self.list = Some((last, indices[0]));
+    }
     /// Make the given entity alive.  
     /// Does nothing if an entity with a greater generation is already at this index.  
     /// Returns `true` if the entity is successfully spawned.
+    ///
+    /// When the `World` has a fixed capacity, `entity`'s index has to be lower than the capacity.
     pub fn spawn(&mut self, entity: EntityId) -> bool {
+        if self
+            .fixed_capacity
+            .is_some_and(|capacity| entity.uindex() >= capacity)
+        {
+            return false;
+        }
+
         if let Some(&old_entity) = self.data.get(entity.index() as usize) {
             if self.is_alive(old_entity) {
                 if old_entity.gen() <= entity.gen() {
@@ -339,6 +595,7 @@ impl Entities {
                     }
 
                     self.data[entity.uindex()] = entity;
+                    self.alive += 1;
 
                     true
                 } else {
@@ -372,6 +629,7 @@ impl Entities {
             }
 
             self.data[entity.uindex()] = entity;
+            self.alive += 1;
 
             true
         }
@@ -428,6 +686,13 @@ impl Storage for Entities {
             .position(|id| id.gen() < EntityId::max_gen())
             .unwrap();
         self.list = Some((self.data.len() - end - 1, begin));
+        self.alive = 0;
+    }
+
+    fn set_fixed_capacity(&mut self, capacity: usize) {
+        self.data
+            .reserve_exact(capacity.saturating_sub(self.data.len()));
+        self.fixed_capacity = Some(capacity);
     }
 
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
@@ -445,6 +710,11 @@ impl Storage for Entities {
         self.data.is_empty()
     }
 
+    #[inline]
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        Some(self.is_alive(entity))
+    }
+
     #[inline]
     fn move_component_from(
         &mut self,
@@ -462,7 +732,9 @@ impl Storage for Entities {
         Some(SBoxBuilder::new(Entities {
             data: self.data.clone(),
             list: self.list,
+            alive: self.alive,
             on_deletion: None,
+            fixed_capacity: None,
         }))
     }
 
@@ -556,3 +828,32 @@ fn iterator() {
 
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn recycle_all_dead() {
+    let mut entities = Entities::new();
+
+    let ids = (0..5).map(|_| entities.generate()).collect::<Vec<_>>();
+
+    entities.recycle_all_dead();
+    assert_eq!(entities.list, None);
+
+    for &i in &[4, 0, 3, 1] {
+        entities.delete_unchecked(ids[i]);
+    }
+
+    entities.recycle_all_dead();
+    assert_eq!(entities.recycled_count(), 4);
+
+    let recycled = entities.generate();
+    assert_eq!(recycled.index(), 0);
+    assert_eq!(recycled.gen(), 1);
+
+    entities.delete_unchecked(ids[2]);
+
+    let order = (0..4)
+        .map(|_| entities.generate().index())
+        .collect::<Vec<_>>();
+    assert_eq!(order, [1, 3, 4, 2]);
+    assert_eq!(entities.list, None);
+}
@@ -4,13 +4,15 @@ mod serde;
 use core::num::NonZeroU64;
 
 /// Entity handle.
+///
+/// `EntityId`s are ordered by index then by generation, this order is consistent with [`Eq`].
 //
 // the id is 64 bits long
 // <- 46 index -> <- 16 gen -> <- 2 meta ->
 // a generation of !0 is used as a dead entity
 //
 // inserted and modified component are flagged using metadata
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct EntityId(pub(super) NonZeroU64);
 
@@ -156,6 +158,23 @@ impl EntityId {
     }
 }
 
+/// Orders by index first and generation second.
+impl Ord for EntityId {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.index()
+            .cmp(&other.index())
+            .then_with(|| self.gen().cmp(&other.gen()))
+    }
+}
+
+impl PartialOrd for EntityId {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl core::fmt::Debug for EntityId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if *self == EntityId::dead() {
@@ -166,6 +185,18 @@ impl core::fmt::Debug for EntityId {
     }
 }
 
+/// Prints the index and generation of the `EntityId`, like `EntityId(index=3, gen=1)`.\
+/// [`Debug`](core::fmt::Debug) uses a shorter format, `EId(3.1)`.
+impl core::fmt::Display for EntityId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if *self == EntityId::dead() {
+            f.write_str("EntityId(dead)")
+        } else {
+            write!(f, "EntityId(index={}, gen={})", self.index(), self.gen())
+        }
+    }
+}
+
 #[test]
 fn entity_id() {
     let mut entity_id = EntityId::new(0);
@@ -183,3 +214,37 @@ fn entity_id() {
     assert_eq!(entity_id.index(), 554);
     assert_eq!(entity_id.gen(), 3);
 }
+
+#[test]
+fn fmt() {
+    let mut entity_id = EntityId::new(701);
+    entity_id.bump_gen().unwrap();
+
+    assert_eq!(alloc::format!("{:?}", entity_id), "EId(701.1)");
+    assert_eq!(
+        alloc::format!("{}", entity_id),
+        "EntityId(index=701, gen=1)"
+    );
+    assert_eq!(alloc::format!("{}", EntityId::dead()), "EntityId(dead)");
+}
+
+#[test]
+fn ord() {
+    use core::cmp::Ordering;
+
+    let index0_gen1 = EntityId::new_from_index_and_gen(0, 1);
+    let index1_gen0 = EntityId::new_from_index_and_gen(1, 0);
+    let index1_gen1 = EntityId::new_from_index_and_gen(1, 1);
+
+    assert!(index0_gen1 < index1_gen0);
+    assert!(index1_gen0 < index1_gen1);
+    assert!(index1_gen1 < EntityId::dead());
+    assert_eq!(index1_gen0.cmp(&EntityId::new(1)), Ordering::Equal);
+
+    let mut ids = alloc::vec![index1_gen1, EntityId::dead(), index1_gen0, index0_gen1];
+    ids.sort();
+    assert_eq!(
+        ids,
+        [index0_gen1, index1_gen0, index1_gen1, EntityId::dead()]
+    );
+}
@@ -1,11 +1,13 @@
 //! All error types.
 
+use crate::borrow::Mutability;
 use crate::entity_id::EntityId;
 use crate::scheduler::info::TypeInfo;
 use crate::scheduler::Label;
 use crate::storage::StorageId;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
 #[cfg(feature = "std")]
@@ -92,6 +94,35 @@ impl GetStorage {
     pub fn from_custom<E: core::any::Any + Send>(error: E) -> GetStorage {
         GetStorage::Custom(Box::new(error))
     }
+    /// Copies the error, custom errors are replaced by their message.
+    pub(crate) fn duplicate(&self) -> GetStorage {
+        match self {
+            GetStorage::AllStoragesBorrow(borrow) => GetStorage::AllStoragesBorrow(*borrow),
+            GetStorage::StorageBorrow { name, id, borrow } => GetStorage::StorageBorrow {
+                name: *name,
+                id: *id,
+                borrow: *borrow,
+            },
+            GetStorage::Entities(borrow) => GetStorage::Entities(*borrow),
+            GetStorage::MissingStorage { name, id } => GetStorage::MissingStorage {
+                name: *name,
+                id: *id,
+            },
+            GetStorage::TrackingNotEnabled { name, id, tracking } => {
+                GetStorage::TrackingNotEnabled {
+                    name: *name,
+                    id: *id,
+                    tracking: *tracking,
+                }
+            }
+            #[cfg(feature = "std")]
+            GetStorage::Custom(err) => {
+                GetStorage::from_custom(alloc::string::ToString::to_string(err))
+            }
+            #[cfg(not(feature = "std"))]
+            GetStorage::Custom(err) => GetStorage::from_custom(alloc::format!("{:?}", err)),
+        }
+    }
 }
 
 impl PartialEq for GetStorage {
@@ -238,14 +269,36 @@ impl Display for NewEntity {
     }
 }
 
-/// Returned by [`AllStorages::add_component`] and [`World::add_component`] when trying to add components to an entity that is not alive.
+/// Returned when trying to add components to an entity that is not alive.
+///
+/// Adding components to a dead entity:
+/// - panics with [`World::add_component`], [`AllStorages::add_component`], [`Entities::add_component`]
+///   and [`Entities::add_distinct_component`]
+/// - returns this error with [`World::add_component_checked`], [`AllStorages::add_component_checked`],
+///   [`Entities::add_component_checked`], [`Entities::add_distinct_component_checked`],
+///   [`ViewMut::replace`] and [`Entry`]
+/// - is not checked by [`AddComponent::add_component_unchecked`], the component is not added
+///   if a more recent entity with the same index has one
 ///
-/// [`AllStorages::add_component`]: crate::all_storages::AllStorages::add_component()
 /// [`World::add_component`]: crate::world::World::add_component()
+/// [`AllStorages::add_component`]: crate::all_storages::AllStorages::add_component()
+/// [`Entities::add_component`]: crate::entities::Entities::add_component()
+/// [`Entities::add_distinct_component`]: crate::entities::Entities::add_distinct_component()
+/// [`World::add_component_checked`]: crate::world::World::add_component_checked()
+/// [`AllStorages::add_component_checked`]: crate::all_storages::AllStorages::add_component_checked()
+/// [`Entities::add_component_checked`]: crate::entities::Entities::add_component_checked()
+/// [`Entities::add_distinct_component_checked`]: crate::entities::Entities::add_distinct_component_checked()
+/// [`ViewMut::replace`]: crate::ViewMut::replace()
+/// [`Entry`]: crate::views::Entry
+/// [`AddComponent::add_component_unchecked`]: crate::AddComponent::add_component_unchecked()
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AddComponent {
     #[allow(missing_docs)]
     EntityIsNotAlive,
+    /// The storage of this component reached the capacity of a [`World::with_fixed_capacity`], no component was added.
+    ///
+    /// [`World::with_fixed_capacity`]: crate::World::with_fixed_capacity()
+    StorageFull(&'static str),
 }
 
 #[cfg(feature = "std")]
@@ -257,6 +310,10 @@ impl Debug for AddComponent {
             AddComponent::EntityIsNotAlive => {
                 f.write_str("Entity has to be alive to add component to it.")
             }
+            AddComponent::StorageFull(name) => f.write_fmt(format_args!(
+                "{} storage is full, the World has a fixed capacity.",
+                name
+            )),
         }
     }
 }
@@ -267,6 +324,127 @@ impl Display for AddComponent {
     }
 }
 
+/// Returned by [`AllStorages::try_add_component`].
+///
+/// No component is added when this error is returned.
+///
+/// [`AllStorages::try_add_component`]: crate::all_storages::AllStorages::try_add_component()
+#[derive(PartialEq)]
+pub enum TryAddComponent {
+    /// Entities or one of the component storages couldn't be borrowed.
+    StorageBorrow(GetStorage),
+    #[allow(missing_docs)]
+    AddComponent(AddComponent),
+}
+
+impl From<GetStorage> for TryAddComponent {
+    fn from(get_storage: GetStorage) -> TryAddComponent {
+        TryAddComponent::StorageBorrow(get_storage)
+    }
+}
+
+impl From<AddComponent> for TryAddComponent {
+    fn from(add_component: AddComponent) -> TryAddComponent {
+        TryAddComponent::AddComponent(add_component)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryAddComponent {}
+
+impl Debug for TryAddComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            TryAddComponent::StorageBorrow(err) => f.write_fmt(format_args!("{:?}", err)),
+            TryAddComponent::AddComponent(err) => f.write_fmt(format_args!("{:?}", err)),
+        }
+    }
+}
+
+impl Display for TryAddComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Returned by [`World::add_entity_checked`] and [`AllStorages::add_entity_checked`] when the [`World`] has a fixed capacity.
+///
+/// [`World::add_entity_checked`]: crate::World::add_entity_checked()
+/// [`AllStorages::add_entity_checked`]: crate::all_storages::AllStorages::add_entity_checked()
+/// [`World`]: crate::World
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AddEntity {
+    /// All entity indices are in use.
+    EntitiesFull,
+    /// The storage of this component is full.
+    StorageFull(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl Error for AddEntity {}
+
+impl Debug for AddEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AddEntity::EntitiesFull => {
+                f.write_str("Cannot add an entity, the World has a fixed capacity and is full.")
+            }
+            AddEntity::StorageFull(name) => f.write_fmt(format_args!(
+                "{} storage is full, the World has a fixed capacity.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for AddEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Returned by [`World::transfer_entity`] and [`AllStorages::transfer_entity`].
+///
+/// Neither `World` is modified when this error is returned.
+///
+/// [`World::transfer_entity`]: crate::World::transfer_entity()
+/// [`AllStorages::transfer_entity`]: crate::all_storages::AllStorages::transfer_entity()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TransferEntity {
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+    /// The target `World` has a fixed capacity and all its entity indices are in use.
+    EntitiesFull,
+    /// The storage of this component in the target `World` is full.
+    StorageFull(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl Error for TransferEntity {}
+
+impl Debug for TransferEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            TransferEntity::EntityIsNotAlive => {
+                f.write_str("Entity has to be alive to transfer it to another World.")
+            }
+            TransferEntity::EntitiesFull => f.write_str(
+                "Cannot transfer the entity, the other World has a fixed capacity and is full.",
+            ),
+            TransferEntity::StorageFull(name) => f.write_fmt(format_args!(
+                "{} storage is full, the other World has a fixed capacity.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for TransferEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error type returned by [`Workload::add_to_world`].
 ///
 /// [`Workload::add_to_world`]: crate::Workload::add_to_world()
@@ -426,9 +604,18 @@ pub enum RunWorkload {
     /// The `Scheduler` is exclusively borrowed.
     Scheduler,
     /// Error while running a system.
-    Run((Box<dyn Label>, Run)),
+    Run(RunSystem),
     /// Workload is not present in the world.
     MissingWorkload,
+    /// Workload was run from a thread already running it, most likely from one of its own systems.
+    Reentrant(Box<dyn Label>),
+    /// The cancel flag was set, the workload stopped before running its next batch.
+    Cancelled,
+    /// Insertion observers failed while dispatching the insertions made by the workload.
+    Observe(Observe),
+    /// `AllStorages` was borrowed when the workload tried to apply the commands recorded by its systems.\
+    /// The commands stay queued until the next flush or [`World::apply_commands`](crate::World::apply_commands).
+    ApplyCommands,
 }
 
 impl RunWorkload {
@@ -436,7 +623,10 @@ impl RunWorkload {
     #[cfg(feature = "std")]
     pub fn custom_error(self) -> Option<Box<dyn Error + Send + Sync>> {
         match self {
-            RunWorkload::Run((_, Run::Custom(error))) => Some(error),
+            RunWorkload::Run(RunSystem {
+                error: Run::Custom(error),
+                ..
+            }) => Some(error),
             _ => None,
         }
     }
@@ -444,14 +634,24 @@ impl RunWorkload {
     #[cfg(not(feature = "std"))]
     pub fn custom_error(self) -> Option<Box<dyn core::any::Any + Send>> {
         match self {
-            RunWorkload::Run((_, Run::Custom(error))) => Some(error),
+            RunWorkload::Run(RunSystem {
+                error: Run::Custom(error),
+                ..
+            }) => Some(error),
             _ => None,
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl Error for RunWorkload {}
+impl Error for RunWorkload {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunWorkload::Run(run_system) => Some(&run_system.error),
+            _ => None,
+        }
+    }
+}
 
 impl Debug for RunWorkload {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
@@ -460,9 +660,14 @@ impl Debug for RunWorkload {
                 f.write_str("Cannot borrow the scheduler while it's already mutably borrowed.")
             }
             RunWorkload::MissingWorkload => f.write_str("No workload with this name exists. You first need to add the workload using `World::add_workload`."),
-            RunWorkload::Run((system_name, run)) => {
-                f.write_fmt(format_args!("{:?} failed: {:?}", system_name, run))
-            }
+            RunWorkload::Run(run_system) => Debug::fmt(run_system, f),
+            RunWorkload::Reentrant(workload_name) => f.write_fmt(format_args!(
+                "Workload {:?} is already running. A workload cannot run itself.",
+                workload_name
+            )),
+            RunWorkload::Cancelled => f.write_str("Workload was cancelled."),
+            RunWorkload::Observe(observe) => Debug::fmt(observe, f),
+            RunWorkload::ApplyCommands => f.write_str("Cannot apply the commands recorded by the workload while AllStorages is borrowed. The commands stay queued."),
         }
     }
 }
@@ -473,6 +678,50 @@ impl Display for RunWorkload {
     }
 }
 
+/// Error returned by a system while running a workload, part of [`RunWorkload`].
+///
+/// When a workload's run if fails, `system` is the workload's name.
+pub struct RunSystem {
+    /// Workload the system was running in.
+    pub workload: Box<dyn Label>,
+    /// System that returned the error.
+    pub system: Box<dyn Label>,
+    /// Error returned by the system.
+    pub error: Run,
+}
+
+impl RunSystem {
+    pub(crate) fn new(workload: &dyn Label, system: Box<dyn Label>, error: Run) -> RunSystem {
+        RunSystem {
+            workload: workload.dyn_clone(),
+            system,
+            error,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RunSystem {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Debug for RunSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!(
+            "{:?} failed: {:?} In workload {:?}.",
+            self.system, self.error, self.workload
+        ))
+    }
+}
+
+impl Display for RunSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`World::run`] and [`AllStorages::run`].
 /// Can refer to an invalid storage borrow or a custom error.
 ///
@@ -487,6 +736,8 @@ pub enum Run {
     /// Error returned by the system.
     #[cfg(not(feature = "std"))]
     Custom(Box<dyn core::any::Any + Send>),
+    /// The system ran but dispatching the observers of its insertions failed.
+    Observe(Observe),
 }
 
 impl From<GetStorage> for Run {
@@ -495,6 +746,12 @@ impl From<GetStorage> for Run {
     }
 }
 
+impl From<Observe> for Run {
+    fn from(observe: Observe) -> Run {
+        Run::Observe(observe)
+    }
+}
+
 impl Run {
     #[cfg(feature = "std")]
     #[allow(missing_docs)]
@@ -506,6 +763,17 @@ impl Run {
     pub fn from_custom<E: core::any::Any + Send>(error: E) -> Run {
         Run::Custom(Box::new(error))
     }
+    /// Copies the error, custom errors are replaced by their message.
+    pub(crate) fn duplicate(&self) -> Run {
+        match self {
+            Run::GetStorage(get_storage) => Run::GetStorage(get_storage.duplicate()),
+            #[cfg(feature = "std")]
+            Run::Custom(err) => Run::from_custom(alloc::string::ToString::to_string(err)),
+            #[cfg(not(feature = "std"))]
+            Run::Custom(err) => Run::from_custom(alloc::format!("{:?}", err)),
+            Run::Observe(observe) => Run::Observe(*observe),
+        }
+    }
 }
 
 impl PartialEq for Run {
@@ -514,6 +782,7 @@ impl PartialEq for Run {
             (Run::GetStorage(l_get_storage), Run::GetStorage(r_get_storage)) => {
                 l_get_storage == r_get_storage
             }
+            (Run::Observe(l_observe), Run::Observe(r_observe)) => l_observe == r_observe,
             _ => false,
         }
     }
@@ -529,6 +798,7 @@ impl Debug for Run {
             Run::Custom(err) => {
                 f.write_fmt(format_args!("run failed with a custom error, {:?}.", err))
             }
+            Run::Observe(observe) => Debug::fmt(observe, f),
         }
     }
 }
@@ -571,12 +841,15 @@ impl Display for MissingComponent {
 /// Returned when trying to add an invalid system to a workload.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InvalidSystem {
-    /// `AllStorages` borrowed alongside another storage.
+    /// `AllStorages` borrowed exclusively alongside another storage.\
+    /// A shared `AllStorages` borrow can be combined with other views.
     AllStorages,
-    /// Multiple views of the same storage including an exclusive one.
-    MultipleViews,
-    /// Multiple exclusive views for the same storage.
-    MultipleViewsMut,
+    /// Multiple views of the same storage including an exclusive one.\
+    /// Contains the two conflicting borrows.
+    MultipleViews(ConflictingBorrow, ConflictingBorrow),
+    /// Multiple exclusive views for the same storage.\
+    /// Contains the two conflicting borrows.
+    MultipleViewsMut(ConflictingBorrow, ConflictingBorrow),
     /// System returning `Workload`
     WorkloadUsedAsSystem(&'static str),
 }
@@ -587,9 +860,9 @@ impl Error for InvalidSystem {}
 impl Debug for InvalidSystem {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
-            InvalidSystem::AllStorages => f.write_str("A system borrowing both AllStorages and a storage can't run. You can borrow the storage inside the system with AllStorages::borrow or AllStorages::run instead."),
-            InvalidSystem::MultipleViews => f.write_str("Multiple views of the same storage including an exclusive borrow, consider removing the shared borrow."),
-            InvalidSystem::MultipleViewsMut => f.write_str("Multiple exclusive views of the same storage, consider removing one."),
+            InvalidSystem::AllStorages => f.write_str("A system borrowing both AllStorages exclusively and a storage can't run. You can borrow the storage inside the system with AllStorages::borrow or AllStorages::run instead."),
+            InvalidSystem::MultipleViews(borrow, _) => f.write_fmt(format_args!("System borrows `{}` both mutably and immutably, consider removing the shared borrow.", borrow)),
+            InvalidSystem::MultipleViewsMut(borrow, _) => f.write_fmt(format_args!("System borrows `{}` mutably twice, consider removing one borrow.", borrow)),
             InvalidSystem::WorkloadUsedAsSystem(system_name) => f.write_fmt(format_args!("Workload used as a system, you should call it `{}()`.", system_name)),
         }
     }
@@ -601,6 +874,126 @@ impl Display for InvalidSystem {
     }
 }
 
+/// One of the borrows reported by [`InvalidSystem::MultipleViews`] and [`InvalidSystem::MultipleViewsMut`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConflictingBorrow {
+    /// Name of the storage, `None` when the borrow's [`TypeInfo`] name was built at runtime.
+    pub name: Option<&'static str>,
+    #[allow(missing_docs)]
+    pub storage_id: StorageId,
+    #[allow(missing_docs)]
+    pub mutability: Mutability,
+}
+
+impl ConflictingBorrow {
+    pub(crate) fn new(type_info: &TypeInfo) -> ConflictingBorrow {
+        ConflictingBorrow {
+            name: match type_info.name {
+                Cow::Borrowed(name) => Some(name),
+                Cow::Owned(_) => None,
+            },
+            storage_id: type_info.storage_id,
+            mutability: type_info.mutability,
+        }
+    }
+}
+
+impl Display for ConflictingBorrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        if let Some(name) = self.name {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("{:?}", self.storage_id))
+        }
+    }
+}
+
+/// Error returned by [`Workload::from_manifest`].
+///
+/// [`Workload::from_manifest`]: crate::scheduler::Workload::from_manifest()
+#[derive(Clone, PartialEq, Eq)]
+pub enum FromManifest {
+    /// No system is registered with this name.
+    UnknownSystem(String),
+    /// No run if condition is registered with this name.
+    UnknownRunIf(String),
+    /// A barrier or flush is placed after the last system.
+    OutOfBounds(usize),
+}
+
+#[cfg(feature = "std")]
+impl Error for FromManifest {}
+
+impl Debug for FromManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            FromManifest::UnknownSystem(name) => {
+                f.write_fmt(format_args!("No system named `{}` in the registry.", name))
+            }
+            FromManifest::UnknownRunIf(name) => f.write_fmt(format_args!(
+                "No run if condition named `{}` in the registry.",
+                name
+            )),
+            FromManifest::OutOfBounds(index) => f.write_fmt(format_args!(
+                "Barrier or flush at index {} is after the last system.",
+                index
+            )),
+        }
+    }
+}
+
+impl Display for FromManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`Workload::to_manifest`].
+///
+/// [`Workload::to_manifest`]: crate::scheduler::Workload::to_manifest()
+#[derive(Clone, PartialEq, Eq)]
+pub enum ToManifest {
+    /// This workload has a run if condition or this system has one without a name, it can't be named.
+    RunIf(String),
+    /// This system's label would not match the same systems once turned into a string.
+    Label {
+        #[allow(missing_docs)]
+        system: String,
+        #[allow(missing_docs)]
+        label: String,
+    },
+    /// This workload is ordered relative to other workloads or this system is ordered by id.
+    Ordering(String),
+}
+
+#[cfg(feature = "std")]
+impl Error for ToManifest {}
+
+impl Debug for ToManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ToManifest::RunIf(name) => f.write_fmt(format_args!(
+                "`{}` has an unnamed run if condition, it can't be part of a manifest. Use `run_if_as` to name a system's condition.",
+                name
+            )),
+            ToManifest::Label { system, label } => f.write_fmt(format_args!(
+                "`{}`'s label `{}` would not match the same systems in a manifest.",
+                system, label
+            )),
+            ToManifest::Ordering(name) => f.write_fmt(format_args!(
+                "`{}` is ordered relative to other workloads or by system id, it can't be part of a manifest.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for ToManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`World::remove_unique`] and [`AllStorages::remove_unique`].
 ///
 /// [`World::remove_unique`]: crate::world::World::remove_unique()
@@ -638,6 +1031,34 @@ impl Display for UniqueRemove {
     }
 }
 
+/// Error returned by [`World::add_distinct_unique`] and [`AllStorages::add_distinct_unique`].
+///
+/// [`World::add_distinct_unique`]: crate::world::World::add_distinct_unique()
+/// [`AllStorages::add_distinct_unique`]: crate::all_storages::AllStorages::add_distinct_unique()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct UniqueAlreadyExists {
+    /// Name of the unique.
+    pub name: &'static str,
+}
+
+#[cfg(feature = "std")]
+impl Error for UniqueAlreadyExists {}
+
+impl Debug for UniqueAlreadyExists {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!(
+            "A unique storage already exists for {}.",
+            self.name
+        ))
+    }
+}
+
+impl Display for UniqueAlreadyExists {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`apply`] and [`apply_mut`].
 ///
 /// [`apply`]: crate::ViewMut::apply()
@@ -671,6 +1092,34 @@ impl Display for Apply {
     }
 }
 
+/// Error returned when dispatching the observers registered with [`World::observe_insert`].
+///
+/// [`World::observe_insert`]: crate::World::observe_insert()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Observe {
+    /// Observers kept inserting observed components for 64 rounds, the remaining insertions were dropped.
+    DepthLimit,
+}
+
+#[cfg(feature = "std")]
+impl Error for Observe {}
+
+impl Debug for Observe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Observe::DepthLimit => f.write_str(
+                "Observers kept inserting observed components for 64 rounds, they are most likely triggering each other endlessly.",
+            ),
+        }
+    }
+}
+
+impl Display for Observe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`are_all_uniques_present_in_world`].
 ///
 /// [`are_all_uniques_present_in_world`]: crate::Workload::are_all_uniques_present_in_world()
@@ -867,7 +1316,7 @@ impl Display for GetComponent {
     }
 }
 
-/// Returned by [`World::serialize`] or [`AllStorages::serialize`].
+/// Returned by [`World::serialize`], [`AllStorages::serialize`] and their `serialize_registered` counterparts.
 ///
 /// [`World::serialize`]: crate::world::World::serialize
 /// [`AllStorages::serialize`]: crate::all_storages::AllStorages::serialize
@@ -900,7 +1349,7 @@ impl<S: serde::Serializer> Display for Serialize<S> {
     }
 }
 
-/// Returned by Des`World::deserialize`] or [`AllStorages::deserialize`].
+/// Returned by Des`World::deserialize`], [`AllStorages::deserialize`] and their `deserialize_registered` counterparts.
 ///
 /// [`World::deserialize`]: crate::world::World::deserialize
 /// [`AllStorages::deserialize`]: crate::all_storages::AllStorages::deserialize
@@ -1,6 +1,7 @@
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::optional::Optional;
 use crate::r#mut::Mut;
 use crate::sparse_set::SparseSet;
 use crate::tracking::Tracking;
@@ -13,7 +14,9 @@ pub trait Get {
     type Out;
     /// Retrieve components of `entity`.
     ///
-    /// Multiple components can be queried at the same time using a tuple.
+    /// Multiple components can be queried at the same time using a tuple.\
+    /// When components are missing, the error names the first missing one in tuple order.\
+    /// Views wrapped in [`Optional`] return `None` instead of an error when their component is missing.
     ///
     /// ### Example:
     /// ```
@@ -28,11 +31,133 @@ pub trait Get {
     /// let mut world = World::new();
     ///
     /// let entity = world.add_entity((USIZE(0), U32(1)));
+    /// let partial = world.add_entity((USIZE(2),));
     ///
     /// let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
     /// assert_eq!((&usizes, &u32s).get(entity), Ok((&USIZE(0), &U32(1))));
+    ///
+    /// assert_eq!(
+    ///     (&usizes, u32s.as_optional()).get(partial),
+    ///     Ok((&USIZE(2), None))
+    /// );
+    /// assert!((&usizes, &u32s).get(partial).is_err());
     /// ```
     fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent>;
+    /// Retrieve components of `entity` alongside its id.
+    ///
+    /// Multiple components can be queried at the same time using a tuple.
+    ///
+    /// ### Example:
+    /// ```
+    /// use shipyard::{Component, Get, View, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity1 = world.add_entity((USIZE(0), U32(1)));
+    /// let entity2 = world.add_entity((USIZE(2), U32(3)));
+    ///
+    /// let (usizes, mut u32s) = world.borrow::<(View<USIZE>, ViewMut<U32>)>().unwrap();
+    ///
+    /// for entity in [entity1, entity2] {
+    ///     let (id, (usize, mut u32)) = (&usizes, &mut u32s).get_with_id(entity).unwrap();
+    ///
+    ///     assert_eq!(id, entity);
+    ///     u32.0 += usize.0 as u32;
+    /// }
+    /// ```
+    #[inline]
+    fn get_with_id(self, entity: EntityId) -> Result<(EntityId, Self::Out), error::MissingComponent>
+    where
+        Self: Sized,
+    {
+        self.get(entity).map(|out| (entity, out))
+    }
+    /// Retrieve a clone of the components of `entity`.\
+    /// Returns `None` if `entity` is missing a component.
+    ///
+    /// Multiple components can be queried at the same time using a tuple.\
+    /// The components are not flagged *modified*, even when retrieved from a `&mut ViewMut`.
+    ///
+    /// ### Example:
+    /// ```
+    /// use shipyard::{Component, Get, View, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+    /// struct Level(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((Name("Ferris".to_string()), Level(3)));
+    ///
+    /// let (names, levels) = world.borrow::<(View<Name>, View<Level>)>().unwrap();
+    /// assert_eq!(
+    ///     (&names, &levels).get_cloned(entity),
+    ///     Some((Name("Ferris".to_string()), Level(3)))
+    /// );
+    /// ```
+    #[inline]
+    fn get_cloned(self, entity: EntityId) -> Option<<Self::Out as GetOwned>::Owned>
+    where
+        Self: Sized,
+        Self::Out: GetOwned,
+    {
+        self.get(entity).ok().map(GetOwned::get_owned)
+    }
+}
+
+/// Output of [`Get`] that can be cloned into owned components, used by [`Get::get_cloned`].
+pub trait GetOwned {
+    #[allow(missing_docs)]
+    type Owned;
+
+    /// Clones the components.
+    fn get_owned(self) -> Self::Owned;
+}
+
+impl<T: Clone> GetOwned for &T {
+    type Owned = T;
+
+    #[inline]
+    fn get_owned(self) -> T {
+        self.clone()
+    }
+}
+
+impl<T: Clone> GetOwned for Mut<'_, T> {
+    type Owned = T;
+
+    #[inline]
+    fn get_owned(self) -> T {
+        // Deref doesn't flag the component
+        (*self).clone()
+    }
+}
+
+impl<T: GetOwned> GetOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    #[inline]
+    fn get_owned(self) -> Option<T::Owned> {
+        self.map(GetOwned::get_owned)
+    }
+}
+
+impl<G: Get> Get for Optional<G> {
+    type Out = Option<G::Out>;
+
+    #[inline]
+    fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
+        Ok(self.0.get(entity).ok())
+    }
 }
 
 impl<'a, T: Component> Get for &'a SparseSet<T> {
@@ -112,6 +237,15 @@ macro_rules! impl_get_component {
                 Ok(($(self.$index.get(entity)?,)+))
             }
         }
+
+        impl<$($type: GetOwned),+> GetOwned for ($($type,)+) {
+            type Owned = ($($type::Owned,)+);
+
+            #[inline]
+            fn get_owned(self) -> Self::Owned {
+                ($(self.$index.get_owned(),)+)
+            }
+        }
     }
 }
 
@@ -126,7 +260,10 @@ macro_rules! get_component {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-get_component![(ViewA, 0); (ViewB, 1) (ViewC, 2) (ViewD, 3) (ViewE, 4) (ViewF, 5) (ViewG, 6) (ViewH, 7) (ViewI, 8) (ViewJ, 9)];
+get_component![
+    (ViewA, 0); (ViewB, 1) (ViewC, 2) (ViewD, 3) (ViewE, 4) (ViewF, 5) (ViewG, 6) (ViewH, 7) (ViewI, 8) (ViewJ, 9)
+    (ViewK, 10) (ViewL, 11) (ViewM, 12) (ViewN, 13) (ViewO, 14) (ViewP, 15)
+];
 #[cfg(feature = "extended_tuple")]
 get_component![
     (ViewA, 0); (ViewB, 1) (ViewC, 2) (ViewD, 3) (ViewE, 4) (ViewF, 5) (ViewG, 6) (ViewH, 7) (ViewI, 8) (ViewJ, 9)
@@ -0,0 +1,584 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::remove::Remove;
+use crate::scheduler::info::TypeInfo;
+use crate::sparse_set::SparseSet;
+use crate::track;
+use crate::tracking::TrackingTimestamp;
+use crate::views::{EntitiesView, View, ViewMut};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Component present on entities attached to a parent.
+///
+/// All children of an entity form a circular doubly-linked list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parent {
+    parent: EntityId,
+    prev: EntityId,
+    next: EntityId,
+}
+
+impl Parent {
+    /// Returns the parent entity.
+    #[inline]
+    pub fn get(&self) -> EntityId {
+        self.parent
+    }
+    /// Returns the previous sibling.\
+    /// The first child's previous sibling is the last child.
+    #[inline]
+    pub fn prev_sibling(&self) -> EntityId {
+        self.prev
+    }
+    /// Returns the next sibling.\
+    /// The last child's next sibling is the first child.
+    #[inline]
+    pub fn next_sibling(&self) -> EntityId {
+        self.next
+    }
+}
+
+impl Component for Parent {
+    type Tracking = track::Untracked;
+}
+
+/// Component present on entities with at least one child.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Children {
+    first: EntityId,
+    len: usize,
+}
+
+impl Children {
+    /// Returns the first child.
+    #[inline]
+    pub fn first(&self) -> EntityId {
+        self.first
+    }
+    /// Returns the number of children.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if there is no children.\
+    /// Entities lose their [`Children`] component when their last child is detached, this will always return `false`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Component for Children {
+    type Tracking = track::Untracked;
+}
+
+/// Shared view over the hierarchy.
+pub struct HierarchyView<'v> {
+    parents: View<'v, Parent>,
+    children: View<'v, Children>,
+}
+
+impl HierarchyView<'_> {
+    /// Returns the parent of `entity`.
+    #[inline]
+    pub fn parent(&self, entity: EntityId) -> Option<EntityId> {
+        self.parents.private_get(entity).map(Parent::get)
+    }
+    /// Iterates `parent`'s children in the order they were attached.
+    #[inline]
+    pub fn iter_children(&self, parent: EntityId) -> ChildrenIter<'_> {
+        ChildrenIter::new(&self.parents, &self.children, parent)
+    }
+    /// Iterates `entity`'s parent, grand parent,... up to the root.
+    #[inline]
+    pub fn iter_ancestors(&self, entity: EntityId) -> AncestorsIter<'_> {
+        AncestorsIter {
+            parents: &self.parents,
+            cursor: entity,
+        }
+    }
+    /// Iterates `entity`'s descendants, depth first.
+    #[inline]
+    pub fn iter_descendants_depth_first(&self, entity: EntityId) -> DepthFirstIter<'_> {
+        DepthFirstIter::new(&self.parents, &self.children, entity)
+    }
+    /// Iterates `entity`'s descendants, breadth first.
+    #[inline]
+    pub fn iter_descendants_breadth_first(&self, entity: EntityId) -> BreadthFirstIter<'_> {
+        BreadthFirstIter::new(&self.parents, &self.children, entity)
+    }
+}
+
+impl Borrow for HierarchyView<'_> {
+    type View<'a> = HierarchyView<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(HierarchyView {
+            parents: View::<Parent>::borrow(all_storages, all_borrow.clone(), last_run, current)?,
+            children: View::<Children>::borrow(all_storages, all_borrow, last_run, current)?,
+        })
+    }
+}
+
+unsafe impl BorrowInfo for HierarchyView<'_> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        View::<Parent>::borrow_info(info);
+        View::<Children>::borrow_info(info);
+    }
+
+    fn enable_tracking(
+        _enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+    }
+}
+
+/// Exclusive view over the hierarchy.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{hierarchy::HierarchyViewMut, World};
+///
+/// let mut world = World::new();
+///
+/// let root = world.add_entity(());
+/// let child = world.add_entity(());
+/// let grand_child = world.add_entity(());
+///
+/// let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+///
+/// hierarchy.attach_child(root, child);
+/// hierarchy.attach_child(child, grand_child);
+///
+/// assert_eq!(hierarchy.iter_children(root).collect::<Vec<_>>(), vec![child]);
+/// assert_eq!(
+///     hierarchy.iter_descendants_depth_first(root).collect::<Vec<_>>(),
+///     vec![child, grand_child]
+/// );
+/// ```
+pub struct HierarchyViewMut<'v> {
+    entities: EntitiesView<'v>,
+    parents: ViewMut<'v, Parent>,
+    children: ViewMut<'v, Children>,
+}
+
+impl HierarchyViewMut<'_> {
+    /// Attaches `child` to `parent`, as its last child.\
+    /// If `child` already had a parent, it is detached first.
+    ///
+    /// ### Panics
+    ///
+    /// - `parent` or `child` is not alive.
+    /// - `child` is `parent` or one of its ancestors.
+    #[track_caller]
+    pub fn attach_child(&mut self, parent: EntityId, child: EntityId) {
+        if !self.entities.is_alive(parent) || !self.entities.is_alive(child) {
+            panic!("{:?}", error::AddComponent::EntityIsNotAlive);
+        }
+
+        assert!(
+            parent != child && !self.iter_ancestors(parent).any(|id| id == child),
+            "Cannot attach an entity to itself or to one of its descendants."
+        );
+
+        self.detach(child);
+
+        if let Some(children) = self.children.private_get(parent) {
+            let first = children.first;
+
+            if !self.is_linked(parent, first) || !self.is_linked(parent, self.parents[first].prev) {
+                self.relink_children(parent, first);
+            }
+        }
+
+        if self.children.contains(parent) {
+            let children = &mut self.children[parent];
+            children.len += 1;
+
+            let next = children.first;
+            let prev = self.parents[next].prev;
+
+            self.parents[prev].next = child;
+            self.parents[next].prev = child;
+
+            self.entities
+                .add_component(child, &mut self.parents, Parent { parent, prev, next });
+        } else {
+            self.entities.add_component(
+                child,
+                &mut self.parents,
+                Parent {
+                    parent,
+                    prev: child,
+                    next: child,
+                },
+            );
+            self.entities.add_component(
+                parent,
+                &mut self.children,
+                Children {
+                    first: child,
+                    len: 1,
+                },
+            );
+        }
+    }
+    /// Detaches `child` from its parent, its own children stay attached to it.\
+    /// Returns `false` if `child` didn't have a parent.
+    pub fn detach(&mut self, child: EntityId) -> bool {
+        let link = match self.parents.remove(child) {
+            Some(link) => link,
+            None => return false,
+        };
+
+        // The parent was deleted without detaching its children
+        if !self.children.contains(link.parent) {
+            return true;
+        }
+
+        if link.next == child {
+            self.children.remove(link.parent);
+        } else if !self.is_linked(link.parent, link.prev) || !self.is_linked(link.parent, link.next)
+        {
+            self.relink_children(link.parent, link.next);
+        } else {
+            let children = &mut self.children[link.parent];
+            children.len -= 1;
+
+            if children.first == child {
+                children.first = link.next;
+            }
+
+            self.parents[link.prev].next = link.next;
+            self.parents[link.next].prev = link.prev;
+        }
+
+        true
+    }
+    /// Detaches all children of `parent`, they become roots.
+    pub fn detach_children(&mut self, parent: EntityId) {
+        if let Some(children) = self.children.remove(parent) {
+            let mut cursor = children.first;
+
+            for _ in 0..children.len {
+                match self.parents.remove(cursor) {
+                    Some(link) => cursor = link.next,
+                    // A child was deleted without being detached, the remaining children are found by their link
+                    None => {
+                        let remaining = self.children_of(parent);
+
+                        for child in remaining {
+                            self.parents.remove(child);
+                        }
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    /// Returns `true` if `entity` is still in `parent`'s list of children.
+    fn is_linked(&self, parent: EntityId, entity: EntityId) -> bool {
+        self.parents
+            .private_get(entity)
+            .is_some_and(|link| link.parent == parent)
+    }
+    /// Returns all entities whose [`Parent`] is `parent`, in storage order.
+    fn children_of(&self, parent: EntityId) -> Vec<EntityId> {
+        self.parents
+            .dense
+            .iter()
+            .zip(&self.parents.data)
+            .filter(|(_, link)| link.parent == parent)
+            .map(|(&entity, _)| entity)
+            .collect()
+    }
+    /// Rebuilds `parent`'s list of children when one of them was deleted without being detached.\
+    /// Children keep their order between the missing links, starting with the ones reachable from `start`.
+    fn relink_children(&mut self, parent: EntityId, start: EntityId) {
+        let mut ordered = Vec::new();
+        let walk = |hierarchy: &Self, ordered: &mut Vec<EntityId>, mut cursor: EntityId| {
+            while hierarchy.is_linked(parent, cursor) && !ordered.contains(&cursor) {
+                ordered.push(cursor);
+                cursor = hierarchy.parents[cursor].next;
+            }
+        };
+
+        walk(self, &mut ordered, start);
+
+        let remaining = self.children_of(parent);
+
+        // Each missing link starts a new run of children
+        for &child in &remaining {
+            if !self.is_linked(parent, self.parents[child].prev) {
+                walk(self, &mut ordered, child);
+            }
+        }
+
+        for child in remaining {
+            if !ordered.contains(&child) {
+                ordered.push(child);
+            }
+        }
+
+        if ordered.is_empty() {
+            self.children.remove(parent);
+
+            return;
+        }
+
+        for (i, &child) in ordered.iter().enumerate() {
+            let link = &mut self.parents[child];
+            link.prev = ordered[(i + ordered.len() - 1) % ordered.len()];
+            link.next = ordered[(i + 1) % ordered.len()];
+        }
+
+        self.children[parent] = Children {
+            first: ordered[0],
+            len: ordered.len(),
+        };
+    }
+    /// Returns the parent of `entity`.
+    #[inline]
+    pub fn parent(&self, entity: EntityId) -> Option<EntityId> {
+        self.parents.private_get(entity).map(Parent::get)
+    }
+    /// Iterates `parent`'s children in the order they were attached.
+    #[inline]
+    pub fn iter_children(&self, parent: EntityId) -> ChildrenIter<'_> {
+        ChildrenIter::new(&self.parents, &self.children, parent)
+    }
+    /// Iterates `entity`'s parent, grand parent,... up to the root.
+    #[inline]
+    pub fn iter_ancestors(&self, entity: EntityId) -> AncestorsIter<'_> {
+        AncestorsIter {
+            parents: &self.parents,
+            cursor: entity,
+        }
+    }
+    /// Iterates `entity`'s descendants, depth first.
+    #[inline]
+    pub fn iter_descendants_depth_first(&self, entity: EntityId) -> DepthFirstIter<'_> {
+        DepthFirstIter::new(&self.parents, &self.children, entity)
+    }
+    /// Iterates `entity`'s descendants, breadth first.
+    #[inline]
+    pub fn iter_descendants_breadth_first(&self, entity: EntityId) -> BreadthFirstIter<'_> {
+        BreadthFirstIter::new(&self.parents, &self.children, entity)
+    }
+}
+
+impl Borrow for HierarchyViewMut<'_> {
+    type View<'a> = HierarchyViewMut<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(HierarchyViewMut {
+            entities: EntitiesView::borrow(all_storages, all_borrow.clone(), last_run, current)?,
+            parents: ViewMut::<Parent>::borrow(
+                all_storages,
+                all_borrow.clone(),
+                last_run,
+                current,
+            )?,
+            children: ViewMut::<Children>::borrow(all_storages, all_borrow, last_run, current)?,
+        })
+    }
+}
+
+unsafe impl BorrowInfo for HierarchyViewMut<'_> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        EntitiesView::borrow_info(info);
+        ViewMut::<Parent>::borrow_info(info);
+        ViewMut::<Children>::borrow_info(info);
+    }
+
+    fn enable_tracking(
+        _enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+    }
+}
+
+/// Iterator over an entity's children.
+///
+/// Stops early when it reaches a child whose [`Parent`] component was deleted without detaching it.
+pub struct ChildrenIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    cursor: EntityId,
+    remaining: usize,
+}
+
+impl<'a> ChildrenIter<'a> {
+    fn new(
+        parents: &'a SparseSet<Parent>,
+        children: &'a SparseSet<Children>,
+        parent: EntityId,
+    ) -> ChildrenIter<'a> {
+        let (cursor, remaining) = children
+            .private_get(parent)
+            .map_or((parent, 0), |children| (children.first, children.len));
+
+        ChildrenIter {
+            parents,
+            cursor,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for ChildrenIter<'_> {
+    type Item = EntityId;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let child = self.cursor;
+
+        // The link is missing if the child's `Parent` component was deleted without detaching it first,
+        // the rest of the siblings can't be reached.
+        let Some(link) = self.parents.private_get(child) else {
+            self.remaining = 0;
+
+            return None;
+        };
+
+        self.remaining -= 1;
+        self.cursor = link.next;
+
+        Some(child)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for ChildrenIter<'_> {}
+
+/// Iterator over an entity's ancestors, from its parent up to the root.
+pub struct AncestorsIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    cursor: EntityId,
+}
+
+impl Iterator for AncestorsIter<'_> {
+    type Item = EntityId;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = self.parents.private_get(self.cursor)?.parent;
+        self.cursor = parent;
+
+        Some(parent)
+    }
+}
+
+/// Iterator over an entity's descendants, depth first.
+pub struct DepthFirstIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    children: &'a SparseSet<Children>,
+    stack: Vec<ChildrenIter<'a>>,
+}
+
+impl<'a> DepthFirstIter<'a> {
+    fn new(
+        parents: &'a SparseSet<Parent>,
+        children: &'a SparseSet<Children>,
+        entity: EntityId,
+    ) -> DepthFirstIter<'a> {
+        let mut stack = Vec::new();
+        if children.contains(entity) {
+            stack.push(ChildrenIter::new(parents, children, entity));
+        }
+
+        DepthFirstIter {
+            parents,
+            children,
+            stack,
+        }
+    }
+}
+
+impl Iterator for DepthFirstIter<'_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some(descendant) => {
+                    if self.children.contains(descendant) {
+                        self.stack
+                            .push(ChildrenIter::new(self.parents, self.children, descendant));
+                    }
+
+                    return Some(descendant);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over an entity's descendants, breadth first.
+pub struct BreadthFirstIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    children: &'a SparseSet<Children>,
+    current: ChildrenIter<'a>,
+    queue: VecDeque<EntityId>,
+}
+
+impl<'a> BreadthFirstIter<'a> {
+    fn new(
+        parents: &'a SparseSet<Parent>,
+        children: &'a SparseSet<Children>,
+        entity: EntityId,
+    ) -> BreadthFirstIter<'a> {
+        BreadthFirstIter {
+            parents,
+            children,
+            current: ChildrenIter::new(parents, children, entity),
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+impl Iterator for BreadthFirstIter<'_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(descendant) = self.current.next() {
+                if self.children.contains(descendant) {
+                    self.queue.push_back(descendant);
+                }
+
+                return Some(descendant);
+            }
+
+            let parent = self.queue.pop_front()?;
+            self.current = ChildrenIter::new(self.parents, self.children, parent);
+        }
+    }
+}
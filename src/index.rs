@@ -0,0 +1,110 @@
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::iter::IntoIter;
+use crate::tracking::{DeletionTracking, InsertionTracking, ModificationTracking, RemovalTracking};
+use crate::views::View;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// Secondary index listing the entities whose `T` component maps to a given key.
+///
+/// The index is kept up to date with [`Index::update`], using `T`'s tracking information.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::index::Index;
+/// use shipyard::{track, Component, EntityId, View, World};
+///
+/// struct Target(EntityId);
+/// impl Component for Target {
+///     type Tracking = track::All;
+/// }
+///
+/// let mut world = World::new();
+///
+/// let player = world.add_entity(());
+/// let enemy1 = world.add_entity(Target(player));
+/// let enemy2 = world.add_entity(Target(player));
+///
+/// let mut index = Index::new(|target: &Target| target.0);
+///
+/// index.update(&world.borrow::<View<Target>>().unwrap());
+/// assert_eq!(index.lookup(&player), &[enemy1, enemy2]);
+///
+/// world.delete_entity(enemy1);
+///
+/// index.update(&world.borrow::<View<Target>>().unwrap());
+/// assert_eq!(index.lookup(&player), &[enemy2]);
+/// ```
+pub struct Index<T, K> {
+    #[allow(clippy::type_complexity)]
+    key: Box<dyn Fn(&T) -> K + Send + Sync>,
+    keys: ShipHashMap<EntityId, K>,
+    entities: ShipHashMap<K, Vec<EntityId>>,
+}
+
+impl<T: Component, K: Clone + Eq + Hash> Index<T, K> {
+    /// Creates an empty index, `key` extracts the key of each component.
+    pub fn new(key: impl Fn(&T) -> K + Send + Sync + 'static) -> Index<T, K> {
+        Index {
+            key: Box::new(key),
+            keys: ShipHashMap::new(),
+            entities: ShipHashMap::new(),
+        }
+    }
+
+    /// Applies all insertions, modifications, removals and deletions visible in `view`.
+    ///
+    /// Inside a workload this is what happened since the last run of the system.\
+    /// Outside workloads this is what happened since the last clear of the tracking information.\
+    /// Applying the same changes multiple times has no effect.
+    pub fn update<Track>(&mut self, view: &View<'_, T, Track>)
+    where
+        Track: InsertionTracking + ModificationTracking + RemovalTracking + DeletionTracking,
+    {
+        for entity in view.removed_or_deleted() {
+            self.remove(entity);
+        }
+
+        for (entity, component) in view.inserted_or_modified().iter().with_id() {
+            let key = (self.key)(component);
+
+            if self.keys.get(&entity) == Some(&key) {
+                continue;
+            }
+
+            self.remove(entity);
+            self.entities.entry(key.clone()).or_default().push(entity);
+            self.keys.insert(entity, key);
+        }
+    }
+
+    /// Returns the entities whose component maps to `key`.
+    pub fn lookup(&self, key: &K) -> &[EntityId] {
+        match self.entities.get(key) {
+            Some(entities) => entities,
+            None => &[],
+        }
+    }
+
+    /// Returns the key of `entity`'s component.
+    pub fn key_of(&self, entity: EntityId) -> Option<&K> {
+        self.keys.get(&entity)
+    }
+
+    /// Removes `entity` from the index.
+    fn remove(&mut self, entity: EntityId) {
+        if let Some(key) = self.keys.remove(&entity) {
+            if let Some(entities) = self.entities.get_mut(&key) {
+                entities.retain(|&id| id != entity);
+
+                if entities.is_empty() {
+                    self.entities.remove(&key);
+                }
+            }
+        }
+    }
+}
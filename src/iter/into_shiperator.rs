@@ -8,7 +8,7 @@ use crate::entity_id::EntityId;
 use crate::iter::ParShiperator;
 use crate::iter::{captain::ShiperatorCaptain, mixed::Mixed, Shiperator};
 use crate::optional::Optional;
-use crate::sparse_set::{FullRawWindow, FullRawWindowMut, RawEntityIdAccess};
+use crate::sparse_set::{FullRawWindow, FullRawWindowMut, RawEntityIdAccess, TightPack};
 use crate::storage::StorageId;
 use crate::tracking::Tracking;
 use crate::views::{View, ViewMut};
@@ -110,6 +110,12 @@ pub trait IntoShiperator {
     fn can_captain() -> bool;
     /// Returns `true` if the Shiperator can be a sailor.
     fn can_sailor() -> bool;
+    /// Returns the pack of the storage when the Shiperator iterates all its components.
+    #[doc(hidden)]
+    #[inline]
+    fn tight_pack(&self) -> Option<TightPack> {
+        None
+    }
 }
 
 impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator for &'tmp View<'v, T, Track> {
@@ -136,6 +142,11 @@ impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator for &'tmp Vie
     fn can_sailor() -> bool {
         true
     }
+
+    #[inline]
+    fn tight_pack(&self) -> Option<TightPack> {
+        self.pack.clone()
+    }
 }
 
 impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator for &'tmp ViewMut<'v, T, Track> {
@@ -162,6 +173,11 @@ impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator for &'tmp Vie
     fn can_sailor() -> bool {
         true
     }
+
+    #[inline]
+    fn tight_pack(&self) -> Option<TightPack> {
+        self.pack.clone()
+    }
 }
 
 impl<'tmp, 'v: 'tmp, T: Component, Track> IntoShiperator for &'tmp mut ViewMut<'v, T, Track> {
@@ -188,6 +204,11 @@ impl<'tmp, 'v: 'tmp, T: Component, Track> IntoShiperator for &'tmp mut ViewMut<'
     fn can_sailor() -> bool {
         true
     }
+
+    #[inline]
+    fn tight_pack(&self) -> Option<TightPack> {
+        self.pack.clone()
+    }
 }
 
 impl<'tmp> IntoShiperator for &'tmp [EntityId] {
@@ -324,8 +345,27 @@ macro_rules! impl_into_shiperator_tuple {
                 self,
                 storage_ids: &mut ShipHashSet<StorageId>,
             ) -> (Self::Shiperator, usize, RawEntityIdAccess) {
+                let packed_len = TightPack::common_len(&[$(self.$index.tight_pack(),)+]);
                 let mut shiperators = ($(self.$index.into_shiperator(storage_ids),)+);
 
+                // All storages are packed together and one of them only holds packed components,
+                // the packed components are exactly the ones to iterate and can be zipped
+                if let Some(len) = packed_len.filter(|&len| [$(shiperators.$index.1),+].contains(&len)) {
+                    let mut mask = 0;
+                    $(
+                        mask |= 1 << $index;
+                    )+
+
+                    return (
+                        Mixed {
+                            shiperator: ($(shiperators.$index.0,)+),
+                            mask,
+                        },
+                        len,
+                        shiperators.0.2,
+                    );
+                }
+
                 let can_captains = ($(
                     $type::can_captain(),
                 )+);
@@ -463,7 +503,10 @@ macro_rules! into_shiperator_tuple {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_shiperator_tuple![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_shiperator_tuple![
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_shiperator_tuple![
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
@@ -20,7 +20,7 @@ pub use mixed::Mixed;
 pub use output::ShiperatorOutput;
 #[cfg(feature = "parallel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
-pub use parallel::ParShiperator;
+pub use parallel::{ParCollectIntoVec, ParShiperator};
 pub use sailor::ShiperatorSailor;
 pub use with_id::WithId;
 
@@ -1,8 +1,18 @@
-use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor};
+use crate::entity_id::EntityId;
+use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor, WithId};
+use alloc::vec::Vec;
+use rayon::iter::ParallelExtend;
 
 #[allow(missing_docs)]
 pub struct ParShiperator<S>(pub(crate) Shiperator<S>);
 
+impl<S> ParShiperator<S> {
+    /// Returns the [`EntityId`] alongside the component(s).
+    pub fn with_id(self) -> WithId<ParShiperator<S>> {
+        WithId(self)
+    }
+}
+
 impl<S: ShiperatorCaptain + ShiperatorSailor + Send + Clone>
     rayon::iter::plumbing::UnindexedProducer for Shiperator<S>
 {
@@ -71,3 +81,67 @@ where
         }
     }
 }
+
+impl<S: ShiperatorCaptain + ShiperatorSailor + Send + Clone> rayon::iter::ParallelIterator
+    for WithId<ParShiperator<S>>
+where
+    S::Out: Send,
+{
+    type Item = (EntityId, S::Out);
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge_unindexed(WithId(self.0 .0), consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        rayon::iter::ParallelIterator::opt_len(&self.0)
+    }
+}
+
+/// Collects a parallel iterator into an existing `Vec`, like rayon's `IndexedParallelIterator::collect_into_vec`
+/// but for all parallel iterators, including shipyard's.\
+/// The method has a different name so it doesn't conflict with rayon's when both traits are in scope.
+pub trait ParCollectIntoVec: rayon::iter::ParallelIterator {
+    /// Clears `vec` and fills it with the iterator's items, keeping `vec`'s capacity.\
+    /// Items are in the same order as the sequential iterator.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use shipyard::iter::ParCollectIntoVec;
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Health(1));
+    /// let entity1 = world.add_entity(Health(2));
+    ///
+    /// let mut buffer = Vec::with_capacity(16);
+    ///
+    /// world.run(|healths: View<Health>| {
+    ///     healths
+    ///         .par_iter()
+    ///         .with_id()
+    ///         .map(|(id, health)| (id, health.0 * 2))
+    ///         .collect_into_buffer(&mut buffer);
+    /// });
+    ///
+    /// assert_eq!(buffer, [(entity0, 2), (entity1, 4)]);
+    /// assert!(buffer.capacity() >= 16);
+    /// ```
+    fn collect_into_buffer(self, vec: &mut Vec<Self::Item>) {
+        vec.clear();
+        vec.par_extend(self);
+    }
+}
+
+impl<I: rayon::iter::ParallelIterator> ParCollectIntoVec for I {}
@@ -18,6 +18,43 @@ impl<S: ShiperatorCaptain + ShiperatorSailor> Shiperator<S> {
     pub fn ids(self) -> core::iter::Map<WithId<Shiperator<S>>, fn((EntityId, S::Out)) -> EntityId> {
         WithId(self).map(|(eid, _)| eid)
     }
+    /// Returns a running index and the [`EntityId`] alongside the component(s).
+    ///
+    /// When iterating a single storage, the index is the component's position in the storage.\
+    /// Mutable views yield mutable components.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Node(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Node(0));
+    /// let entity1 = world.add_entity(Node(1));
+    ///
+    /// let nodes = world.borrow::<View<Node>>().unwrap();
+    ///
+    /// let mut iter = nodes.iter().enumerate_entities();
+    /// let (index, entity, node) = iter.next().unwrap();
+    /// assert_eq!((index, entity, node.0), (0, entity0, 0));
+    /// let (index, entity, node) = iter.next().unwrap();
+    /// assert_eq!((index, entity, node.0), (1, entity1, 1));
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn enumerate_entities(
+        self,
+    ) -> core::iter::Map<
+        core::iter::Enumerate<WithId<Shiperator<S>>>,
+        fn((usize, (EntityId, S::Out))) -> (usize, EntityId, S::Out),
+    > {
+        WithId(self)
+            .enumerate()
+            .map(|(index, (eid, component))| (index, eid, component))
+    }
 }
 
 impl<S: ShiperatorCaptain + ShiperatorSailor> Iterator for WithId<Shiperator<S>> {
@@ -92,7 +129,8 @@ where
 impl<S: ShiperatorCaptain + ShiperatorSailor> DoubleEndedIterator for WithId<Shiperator<S>> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if let Some(item) = self.0.next_back() {
-            let entity_id = unsafe { self.0.entities.get(self.0.end + 1) };
+            // `next_back` leaves `end` on the index of the item it returned
+            let entity_id = unsafe { self.0.entities.get(self.0.end) };
 
             Some((entity_id, item))
         } else {
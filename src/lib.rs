@@ -5,10 +5,13 @@
 //! ## Features
 //!
 //! - **parallel** *(default)* &mdash; enables workload threading and add parallel iterators
-//! - **extended_tuple** &mdash; extends implementations from the default 10 to 32 tuple size at the cost of 4X build time
+//! - **alloc_profile** &mdash; adds a counting global allocator to profile workload allocations
+//! - **extended_tuple** &mdash; extends implementations from the default 16 to 32 tuple size at the cost of 4X build time
+//! - **hierarchy** &mdash; adds parent/child relationships between entities
 //! - **proc** *(default)* &mdash; re-exports macros from `shipyard_proc`, mainly to derive `Component`
 //! - **serde1** &mdash; adds (de)serialization support with [serde](https://github.com/serde-rs/serde)
 //! - **std** *(default)* &mdash; lets Shipyard use the standard library
+//! - **test_utils** &mdash; adds `assert_valid_system!` to check systems in tests
 //! - **thread_local** &mdash; adds methods and types required to work with `!Send` and `!Sync` components
 //! - **tracing** &mdash; reports workload and system execution
 
@@ -49,8 +52,14 @@ mod add_entity;
 pub mod advanced;
 /// Contains all storages present in the [`World`].
 pub mod all_storages;
+/// Counts allocations to profile workloads.
+#[cfg(feature = "alloc_profile")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc_profile")))]
+pub mod alloc_profile;
+mod arena;
 /// Allows access to helper types needed to implement [`Borrow`](borrow::Borrow).
 pub mod borrow;
+mod commands;
 mod component;
 mod contains;
 mod delete;
@@ -58,6 +67,12 @@ mod entities;
 mod entity_id;
 pub mod error;
 mod get;
+/// Parent/child relationships between entities.
+#[cfg(feature = "hierarchy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hierarchy")))]
+pub mod hierarchy;
+/// Secondary indexes kept up to date with tracking.
+pub mod index;
 /// Contains all items related to storage iteration.
 pub mod iter;
 /// Trait used as bound for [`World::iter`](crate::world::World::iter) and [`AllStorages::iter`](crate::all_storages::AllStorages::iter).
@@ -103,8 +118,8 @@ pub use shipyard_proc::{Borrow, BorrowInfo, Component, IntoIter, Label, Unique,
 pub use unique::UniqueStorage;
 #[doc(inline)]
 pub use views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, Arena, Commands, EntitiesView, EntitiesViewMut,
+    UniqueView, UniqueViewMut, View, ViewMut, WorldView,
 };
 #[doc(inline)]
 pub use world::World;
@@ -34,6 +34,15 @@ impl core::fmt::Debug for StorageMemoryUsage {
     }
 }
 
+/// Memory reclaimed from a storage by [`AllStorages::compact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageCompaction {
+    #[allow(missing_docs)]
+    pub storage_name: Cow<'static, str>,
+    /// Amount of allocated memory freed by the storage in bytes.
+    pub reclaimed_memory_bytes: usize,
+}
+
 /// A trait to query the detailed memory usage of a storage
 pub trait MemoryUsageDetail {
     /// The output type of the detailed memory usage.
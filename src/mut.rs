@@ -63,3 +63,45 @@ impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for Mut<'_, T> {
         self.data.fmt(f)
     }
 }
+
+/// Mutable access to a component that is only flagged *modified* by [`SilentMut::mark`].
+///
+/// Unlike [`Mut`], mutably dereferencing a [`SilentMut`] doesn't flag the component.\
+/// Created with [`ViewMut::iter_silent`](crate::ViewMut::iter_silent).
+pub struct SilentMut<'a, T: ?Sized> {
+    pub(crate) flag: &'a mut TrackingTimestamp,
+    pub(crate) current: TrackingTimestamp,
+    pub(crate) data: &'a mut T,
+}
+
+impl<T: ?Sized> SilentMut<'_, T> {
+    /// Flags the component as *modified*.
+    ///
+    /// This is an associated function that needs to be used as `SilentMut::mark(...)`. A method would interfere with methods of the same name used through Deref.
+    #[inline]
+    pub fn mark(this: &mut Self) {
+        *this.flag = this.current;
+    }
+}
+
+impl<T: ?Sized> core::ops::Deref for SilentMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.data
+    }
+}
+
+impl<T: ?Sized> core::ops::DerefMut for SilentMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.data
+    }
+}
+
+impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for SilentMut<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.data.fmt(f)
+    }
+}
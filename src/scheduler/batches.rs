@@ -1,8 +1,14 @@
 use crate::error;
+use crate::scheduler::info::WorkloadStats;
 use crate::scheduler::system::WorkloadRunIfFn;
+use crate::scheduler::Label;
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 /// List of indexes into both systems and system_names
 #[derive(Default)]
@@ -16,8 +22,247 @@ pub(crate) struct Batches {
     pub(crate) sequential: Vec<usize>,
     /// Index into `systems_run_if`
     pub(crate) sequential_run_if: Vec<usize>,
+    /// Index into `parallel` of the batches followed by a flush
+    pub(crate) parallel_flushes: Vec<usize>,
+    /// Index into `sequential` of the systems followed by a flush
+    pub(crate) sequential_flushes: Vec<usize>,
     pub(crate) workload_run_if: Option<Box<dyn WorkloadRunIfFn>>,
     pub(crate) systems_run_if: Vec<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    /// Set while the workload is running, used to detect re-entrant runs
+    #[cfg(not(feature = "std"))]
+    pub(crate) running: AtomicBool,
+    /// Error that stopped the last run
+    #[cfg(feature = "std")]
+    pub(crate) last_error: LastError,
+    /// Number of systems in the longest chain of systems that have to run one after the other
+    pub(crate) critical_path: usize,
+    /// Number of systems that ran during the last run
+    pub(crate) systems_run: AtomicUsize,
+    /// Maximum number of systems running at the same time in a batch
+    pub(crate) max_threads: Option<usize>,
+    /// Group of systems to run next, set with [`Workload::staggered`](crate::Workload::staggered)
+    pub(crate) staggered: Option<Staggered>,
+}
+
+impl Batches {
+    /// Flags the workload as running on this thread until the returned guard is dropped.\
+    /// Returns `None` if the workload is already running on this thread.
+    ///
+    /// Other threads can run the workload at the same time.
+    #[cfg(feature = "std")]
+    pub(crate) fn start_running(&self) -> Option<RunningGuard<'_>> {
+        let workload: *const Batches = self;
+        let workload = workload as usize;
+
+        RUNNING.with(|running| {
+            let mut running = running.borrow_mut();
+
+            if running.contains(&workload) {
+                None
+            } else {
+                running.push(workload);
+
+                Some(RunningGuard {
+                    workload,
+                    _batches: core::marker::PhantomData,
+                })
+            }
+        })
+    }
+    /// Flags the workload as running until the returned guard is dropped.\
+    /// Returns `None` if the workload is already running.
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn start_running(&self) -> Option<RunningGuard<'_>> {
+        if self.running.swap(true, Ordering::Acquire) {
+            None
+        } else {
+            Some(RunningGuard {
+                running: &self.running,
+            })
+        }
+    }
+    /// Returns the parallelism of this workload and how many systems ran during the last run.
+    pub(crate) fn stats(&self) -> WorkloadStats {
+        let widths = self
+            .parallel
+            .iter()
+            .map(|(single, systems)| usize::from(single.is_some()) + systems.len());
+
+        WorkloadStats {
+            batches: self.parallel.len(),
+            max_batch_width: widths.clone().max().unwrap_or(0),
+            systems: widths.sum(),
+            critical_path: self.critical_path,
+            systems_run: self.systems_run.load(Ordering::Relaxed),
+        }
+    }
+    /// Returns a guard advancing the `World`'s tick and moving a staggered workload to its next group when dropped.\
+    /// Every run path holds one from the start of the run, both change whether the run finished or stopped early.
+    pub(crate) fn advance_on_exit(&self, tick: Arc<AtomicU64>) -> AdvanceOnExit {
+        AdvanceOnExit {
+            staggered: self.staggered.clone(),
+            tick,
+        }
+    }
+    /// Replaces the error that stopped the last run.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub(crate) fn set_last_error(&self, last_error: Option<(Box<dyn Label>, error::Run)>) {
+        #[cfg(feature = "std")]
+        {
+            *self
+                .last_error
+                .0
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = last_error;
+        }
+    }
+    /// Returns a copy of the system that stopped the last run and its error.
+    #[cfg(feature = "std")]
+    pub(crate) fn last_error(&self) -> Option<(Box<dyn Label>, error::Run)> {
+        self.last_error
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .as_ref()
+            .map(|(system, err)| (system.dyn_clone(), err.duplicate()))
+    }
+}
+
+/// Cursor shared between a staggered workload and the `run_if` of its systems.
+#[derive(Clone)]
+pub(crate) struct Staggered {
+    groups: usize,
+    cursor: Arc<AtomicUsize>,
+}
+
+impl Staggered {
+    pub(crate) fn new(groups: usize) -> Staggered {
+        Staggered {
+            groups,
+            cursor: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+    /// Returns a `run_if` only allowing the system to run when `group` is the current group.\
+    /// `run_if` is still checked for this group.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn run_if(
+        &self,
+        group: usize,
+        run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>>,
+    ) -> Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static> {
+        let cursor = self.cursor.clone();
+
+        match run_if {
+            Some(run_if) => {
+                Box::new(move |world| Ok(cursor.load(Ordering::Relaxed) == group && run_if(world)?))
+            }
+            None => Box::new(move |_| Ok(cursor.load(Ordering::Relaxed) == group)),
+        }
+    }
+    /// Moves to the next group, going back to the first one after the last.
+    pub(crate) fn advance(&self) {
+        let next = (self.cursor.load(Ordering::Relaxed) + 1) % self.groups;
+
+        self.cursor.store(next, Ordering::Relaxed);
+    }
+}
+
+/// Advances the `World`'s tick and moves a staggered workload to its next group when dropped,
+/// created with [`Batches::advance_on_exit`].
+pub(crate) struct AdvanceOnExit {
+    staggered: Option<Staggered>,
+    tick: Arc<AtomicU64>,
+}
+
+impl Drop for AdvanceOnExit {
+    fn drop(&mut self) {
+        if let Some(staggered) = &self.staggered {
+            staggered.advance();
+        }
+
+        self.tick.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Error that stopped the last run of a workload.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(crate) struct LastError(std::sync::Mutex<Option<(Box<dyn Label>, error::Run)>>);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Workloads running on this thread, identified by the address of their batches.\
+    /// Nested runs are pushed on top of the workload running them.
+    static RUNNING: core::cell::RefCell<Vec<usize>> = const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Returns the workloads running on this thread, systems running on other threads inherit them with [`inherit_running`].
+#[cfg(all(feature = "parallel", feature = "std"))]
+pub(crate) fn running_workloads() -> Vec<usize> {
+    RUNNING.with(|running| running.borrow().clone())
+}
+
+/// Flags `workloads` as running on this thread until the returned guard is dropped.
+#[cfg(all(feature = "parallel", feature = "std"))]
+pub(crate) fn inherit_running(workloads: &[usize]) -> InheritedRunning {
+    RUNNING.with(|running| {
+        let mut running = running.borrow_mut();
+        let len = running.len();
+
+        running.extend_from_slice(workloads);
+
+        InheritedRunning { len }
+    })
+}
+
+/// Removes the inherited workloads when dropped, even if a system panics.
+#[cfg(all(feature = "parallel", feature = "std"))]
+pub(crate) struct InheritedRunning {
+    len: usize,
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+impl Drop for InheritedRunning {
+    fn drop(&mut self) {
+        let _ = RUNNING.try_with(|running| running.borrow_mut().truncate(self.len));
+    }
+}
+
+/// Removes a workload from the running ones when dropped, even if a system panics.
+#[cfg(feature = "std")]
+pub(crate) struct RunningGuard<'a> {
+    workload: usize,
+    _batches: core::marker::PhantomData<&'a Batches>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        // The thread local might already be destroyed if the thread is exiting
+        let _ = RUNNING.try_with(|running| {
+            let mut running = running.borrow_mut();
+
+            if let Some(index) = running
+                .iter()
+                .rposition(|&workload| workload == self.workload)
+            {
+                running.remove(index);
+            }
+        });
+    }
+}
+
+/// Clears the running flag of a workload when dropped, even if a system panics.
+#[cfg(not(feature = "std"))]
+pub(crate) struct RunningGuard<'a> {
+    running: &'a AtomicBool,
+}
+
+#[cfg(not(feature = "std"))]
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Release);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,206 @@
+use crate::atomic_refcell::AtomicRefCell;
+use crate::borrow::{BorrowInfo, WorldBorrow};
+use crate::error;
+use crate::scheduler::info::DedupedLabels;
+use crate::scheduler::into_workload_system::{check_borrows, Nothing};
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::{AsLabel, IntoWorkloadSystem, Label, WorkloadSystem};
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use crate::world::World;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::{type_name, TypeId};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Allows a `FnMut` closure to be used as a system.
+///
+/// The closure can own state modified at each run, like a counter or a cache, without using a unique storage.
+///
+/// Each `FnMutSystem` owns its state, two systems created from the same closure type don't share it.\
+/// The state is borrowed exclusively while the system runs.
+/// A workload contains each system once so it never runs it in parallel with itself,
+/// but if the same workload is run at the same time from two threads, one of them will fail with a [`GetStorage::StorageBorrow`](error::GetStorage::StorageBorrow) error.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{scheduler::FnMutSystem, Component, View, Workload, World};
+///
+/// #[derive(Component)]
+/// struct U32(u32);
+///
+/// let world = World::new();
+///
+/// world.add_entity(U32(0));
+///
+/// let mut run_count = 0;
+///
+/// Workload::new("")
+///     .with_system(FnMutSystem(move |u32s: View<U32>| {
+///         run_count += 1;
+///
+///         assert_eq!(u32s.len(), 1);
+///         assert!(run_count <= 2);
+///     }))
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_default_workload().unwrap();
+/// world.run_default_workload().unwrap();
+/// ```
+pub struct FnMutSystem<F>(pub F);
+
+fn state_borrow_error<F: 'static>(borrow: error::Borrow) -> error::GetStorage {
+    error::GetStorage::StorageBorrow {
+        name: Some(type_name::<F>()),
+        id: StorageId::of::<FnMutSystem<F>>(),
+        borrow,
+    }
+}
+
+impl<R, F> IntoWorkloadSystem<Nothing, R> for FnMutSystem<F>
+where
+    R: 'static,
+    F: 'static + Send + FnMut() -> R,
+{
+    fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let label = self.label();
+        let state = AtomicRefCell::new(self.0);
+
+        Ok(WorkloadSystem {
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            display_name: label.clone(),
+            tags: vec![label],
+            system_fn: Box::new(move |_: &World| {
+                let mut system = state.borrow_mut().map_err(state_borrow_error::<F>)?;
+
+                (*system)();
+
+                Ok(())
+            }),
+            type_id: TypeId::of::<F>(),
+            generator: Box::new(|_| TypeId::of::<F>()),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            after: Vec::new(),
+            before: Vec::new(),
+            unique_id: 0,
+            run_if: None,
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+            priority: 0,
+            stateful: true,
+            run_if_name: None,
+        })
+    }
+    fn label(&self) -> Box<dyn Label> {
+        Box::new(SystemLabel {
+            type_id: TypeId::of::<F>(),
+            name: type_name::<F>().as_label(),
+        })
+    }
+    fn call(mut self) -> R {
+        (self.0)()
+    }
+}
+
+macro_rules! impl_into_workload_fn_mut_system {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: WorldBorrow + BorrowInfo,)+ Ret, Func> IntoWorkloadSystem<($($type,)+), Ret> for FnMutSystem<Func>
+        where
+            Ret: 'static,
+            Func: 'static + Send,
+            for<'a, 'b> &'b mut Func:
+                FnMut($($type),+) -> Ret
+                + FnMut($($type::WorldView<'a>),+) -> Ret {
+
+            fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                let label = self.label();
+                let state = AtomicRefCell::new(self.0);
+                let last_run = AtomicU64::new(0);
+                Ok(WorkloadSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    display_name: label.clone(),
+                    tags: vec![label],
+                    system_fn: Box::new(move |world: &World| {
+                        let mut system = state.borrow_mut().map_err(state_borrow_error::<Func>)?;
+                        let mut system: &mut Func = &mut system;
+
+                        let current = world.get_current();
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        Ok(drop((&mut system)($($type::world_borrow(&world, Some(last_run), current)?),+)))
+                    }),
+                    type_id: TypeId::of::<Func>(),
+                    before_all: DedupedLabels::new(),
+                    after_all: DedupedLabels::new(),
+                    after: Vec::new(),
+                    before: Vec::new(),
+                    unique_id: 0,
+                    generator: Box::new(|constraints| {
+                        $(
+                            $type::borrow_info(constraints);
+                        )+
+
+                        TypeId::of::<Func>()
+                    }),
+                    run_if: None,
+                    require_in_workload: DedupedLabels::new(),
+                    require_before: DedupedLabels::new(),
+                    require_after: DedupedLabels::new(),
+                    priority: 0,
+                    stateful: true,
+                    run_if_name: None,
+                })
+            }
+            fn label(&self) -> Box<dyn Label> {
+                Box::new(SystemLabel {
+                    type_id: TypeId::of::<Func>(),
+                    name: type_name::<Func>().as_label(),
+                })
+            }
+            fn call(self) -> Ret {
+                unreachable!()
+            }
+        }
+    }
+}
+
+macro_rules! into_workload_fn_mut_system {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_workload_fn_mut_system![$(($type, $index))*];
+        into_workload_fn_mut_system![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_into_workload_fn_mut_system![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+into_workload_fn_mut_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
+#[cfg(feature = "extended_tuple")]
+into_workload_fn_mut_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
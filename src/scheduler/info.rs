@@ -159,6 +159,51 @@ impl core::hash::Hash for TypeInfo {
     }
 }
 
+/// Parallelism of a workload, returned by [`World::run_workload_stats`].
+///
+/// [`World::run_workload_stats`]: crate::World::run_workload_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkloadStats {
+    /// Number of batches, systems in the same batch can run in parallel.
+    pub batches: usize,
+    /// Number of systems in the largest batch.
+    pub max_batch_width: usize,
+    /// Number of systems in the workload.
+    pub systems: usize,
+    /// Number of systems in the longest chain of systems that have to run one after the other,\
+    /// because of an ordering constraint or a conflicting borrow.
+    pub critical_path: usize,
+    /// Number of systems that ran to completion during the last run.
+    pub systems_run: usize,
+}
+
+/// Sequence of decisions taken while running a workload, returned by [`World::run_workload_traced`].
+///
+/// Two runs taking the same decisions produce equal traces.
+///
+/// [`World::run_workload_traced`]: crate::World::run_workload_traced
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunTrace {
+    #[allow(missing_docs)]
+    pub workload: String,
+    /// Decisions in the order they were taken.
+    pub events: Vec<TraceEvent>,
+}
+
+/// A single decision part of a [`RunTrace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum TraceEvent {
+    /// The workload's run if returned `false`, no system ran.
+    WorkloadSkipped,
+    /// The system ran.
+    Ran(String),
+    /// The system's run if returned `false`.
+    SkippedByRunIf(String),
+}
+
 /// Contains a list of workloads, their systems and which storages these systems borrow.
 #[derive(Default, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
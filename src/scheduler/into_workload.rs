@@ -175,13 +175,8 @@ where
                 name: name.clone(),
                 tags: vec![name],
                 systems: vec![system],
-                run_if: None,
-                before_all: DedupedLabels::new(),
-                after_all: DedupedLabels::new(),
                 overwritten_name: false,
-                require_before: DedupedLabels::new(),
-                require_after: DedupedLabels::new(),
-                barriers: Vec::new(),
+                ..Default::default()
             }
         }
     }
@@ -215,13 +210,8 @@ macro_rules! impl_into_workload {
                     tags: vec![name.clone()],
                     name,
                     systems: Vec::new(),
-                    run_if: None,
-                    before_all: DedupedLabels::new(),
-                    after_all: DedupedLabels::new(),
                     overwritten_name: false,
-                    require_before: DedupedLabels::new(),
-                    require_after: DedupedLabels::new(),
-                    barriers: Vec::new(),
+                    ..Default::default()
                 };
 
                 $(
@@ -243,13 +233,8 @@ macro_rules! impl_into_workload {
                     tags: vec![name.clone()],
                     name,
                     systems: Vec::new(),
-                    run_if: None,
-                    before_all: DedupedLabels::new(),
-                    after_all: DedupedLabels::new(),
                     overwritten_name: false,
-                    require_before: DedupedLabels::new(),
-                    require_after: DedupedLabels::new(),
-                    barriers: Vec::new(),
+                    ..Default::default()
                 };
 
                 let mut sequential_tags = Vec::new();
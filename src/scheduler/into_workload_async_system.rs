@@ -0,0 +1,302 @@
+use crate::borrow::{BorrowInfo, WorldBorrow};
+use crate::component::Unique;
+use crate::error;
+use crate::scheduler::info::DedupedLabels;
+use crate::scheduler::into_workload_system::check_borrows;
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::{AsLabel, WorkloadSystem};
+use crate::tracking::TrackingTimestamp;
+use crate::views::UniqueView;
+use crate::World;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::{type_name, TypeId};
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Drives the future of async systems to completion.
+pub trait Executor: Send + Sync + 'static {
+    /// Polls `future` until it completes.
+    fn block_on(&self, future: Pin<&mut dyn Future<Output = ()>>);
+}
+
+/// Unique storage holding the [`Executor`] used to run async systems.
+///
+/// It is looked up each time an async system runs.
+pub struct AsyncExecutor(pub Box<dyn Executor>);
+
+impl AsyncExecutor {
+    /// Wraps `executor`.
+    pub fn new<E: Executor>(executor: E) -> AsyncExecutor {
+        AsyncExecutor(Box::new(executor))
+    }
+}
+
+impl Unique for AsyncExecutor {}
+
+/// Function returning a future whose lifetime is tied to its arguments.
+///
+/// Implemented for all functions taking views and returning a future, like `async fn`.
+pub trait AsyncSystemFn<'a, Views, R> {
+    #[allow(missing_docs)]
+    type Future: Future<Output = R> + 'a;
+
+    #[allow(missing_docs)]
+    fn call(&self, views: Views) -> Self::Future;
+}
+
+/// Validates an async function can become a system.
+pub trait IntoWorkloadAsyncSystem<Views, R> {
+    /// Wraps an async function in a struct containing all information required by a workload.
+    ///
+    /// All views are borrowed before the future is created and released once it completes.\
+    /// The future is driven by the [`AsyncExecutor`] present in the `World` when the system runs.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AsyncExecutor`] (shared) in addition to the function's views
+    ///
+    /// ### Errors
+    ///
+    /// - The function's views are not valid, see [`IntoWorkloadSystem`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use core::future::Future;
+    /// use core::pin::Pin;
+    /// use core::task::{Context, Wake};
+    /// use shipyard::scheduler::{AsyncExecutor, Executor, IntoWorkloadAsyncSystem};
+    /// use shipyard::{Component, IntoIter, ViewMut, Workload, World};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Component)]
+    /// struct Asset(u32);
+    ///
+    /// struct NoopWaker;
+    /// impl Wake for NoopWaker {
+    ///     fn wake(self: Arc<Self>) {}
+    /// }
+    ///
+    /// struct BlockOn;
+    /// impl Executor for BlockOn {
+    ///     fn block_on(&self, mut future: Pin<&mut dyn Future<Output = ()>>) {
+    ///         let waker = Arc::new(NoopWaker).into();
+    ///         let mut cx = Context::from_waker(&waker);
+    ///
+    ///         while future.as_mut().poll(&mut cx).is_pending() {}
+    ///     }
+    /// }
+    ///
+    /// async fn load() -> u32 {
+    ///     1
+    /// }
+    ///
+    /// async fn load_assets(mut assets: ViewMut<'_, Asset>) {
+    ///     for mut asset in (&mut assets).iter() {
+    ///         asset.0 = load().await;
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_unique(AsyncExecutor::new(BlockOn));
+    /// let entity = world.add_entity(Asset(0));
+    ///
+    /// Workload::new("Load")
+    ///     .with_system(load_assets.into_workload_async_system().unwrap())
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Load").unwrap();
+    ///
+    /// assert_eq!(world.get::<&Asset>(entity).unwrap().0, 1);
+    /// ```
+    ///
+    /// [`IntoWorkloadSystem`]: crate::scheduler::IntoWorkloadSystem
+    fn into_workload_async_system(self) -> Result<WorkloadSystem, error::InvalidSystem>;
+}
+
+impl<'a, Ret, Fut, Func> AsyncSystemFn<'a, (), Ret> for Func
+where
+    Func: Fn() -> Fut,
+    Fut: Future<Output = Ret> + 'a,
+{
+    type Future = Fut;
+
+    fn call(&self, _: ()) -> Fut {
+        (self)()
+    }
+}
+
+impl<Ret, Func> IntoWorkloadAsyncSystem<(), Ret> for Func
+where
+    Func: 'static + Send + Sync,
+    for<'a> Func: AsyncSystemFn<'a, (), Ret>,
+{
+    fn into_workload_async_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let mut borrows = Vec::new();
+        UniqueView::<AsyncExecutor>::borrow_info(&mut borrows);
+
+        Ok(WorkloadSystem {
+            borrow_constraints: borrows,
+            tracking_to_enable: Vec::new(),
+            system_fn: Box::new(move |world: &World| {
+                let executor =
+                    UniqueView::<AsyncExecutor>::world_borrow(world, None, world.get_current())?;
+                let future = <Func as AsyncSystemFn<'_, (), Ret>>::call(&self, ());
+
+                let mut future = core::pin::pin!(async move {
+                    drop(future.await);
+                });
+
+                executor.0.block_on(future.as_mut());
+
+                Ok(())
+            }),
+            type_id: TypeId::of::<Func>(),
+            display_name: Box::new(SystemLabel {
+                type_id: TypeId::of::<Func>(),
+                name: type_name::<Func>().as_label(),
+            }),
+            generator: Box::new(|constraints| {
+                UniqueView::<AsyncExecutor>::borrow_info(constraints);
+
+                TypeId::of::<Func>()
+            }),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            after: Vec::new(),
+            before: Vec::new(),
+            unique_id: 0,
+            tags: vec![Box::new(SystemLabel {
+                type_id: TypeId::of::<Func>(),
+                name: type_name::<Func>().as_label(),
+            })],
+            run_if: None,
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+            priority: 0,
+            stateful: false,
+            run_if_name: None,
+        })
+    }
+}
+
+macro_rules! impl_into_workload_async_system {
+    ($(($type: ident, $index: tt))+) => {
+        impl<'a, $($type,)+ Ret, Fut, Func> AsyncSystemFn<'a, ($($type,)+), Ret> for Func
+        where
+            Func: Fn($($type),+) -> Fut,
+            Fut: Future<Output = Ret> + 'a,
+        {
+            type Future = Fut;
+
+            fn call(&self, views: ($($type,)+)) -> Fut {
+                (self)($(views.$index),+)
+            }
+        }
+
+        impl<$($type: WorldBorrow + BorrowInfo,)+ Ret, Func> IntoWorkloadAsyncSystem<($($type,)+), Ret> for Func
+        where
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a> Func: AsyncSystemFn<'a, ($($type,)+), Ret>
+                + AsyncSystemFn<'a, ($($type::WorldView<'a>,)+), Ret>,
+        {
+            fn into_workload_async_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+                let mut borrows = Vec::new();
+                UniqueView::<AsyncExecutor>::borrow_info(&mut borrows);
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                let last_run = AtomicU64::new(0);
+                Ok(WorkloadSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    system_fn: Box::new(move |world: &World| {
+                        let current = world.get_current();
+                        let executor = UniqueView::<AsyncExecutor>::world_borrow(world, None, current)?;
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        let future = <Func as AsyncSystemFn<'_, ($($type::WorldView<'_>,)+), Ret>>::call(
+                            &self,
+                            ($($type::world_borrow(&world, Some(last_run), current)?,)+),
+                        );
+
+                        let mut future = core::pin::pin!(async move {
+                            drop(future.await);
+                        });
+
+                        executor.0.block_on(future.as_mut());
+
+                        Ok(())
+                    }),
+                    type_id: TypeId::of::<Func>(),
+                    display_name: Box::new(SystemLabel {
+                        type_id: TypeId::of::<Func>(),
+                        name: type_name::<Func>().as_label(),
+                    }),
+                    generator: Box::new(|constraints| {
+                        UniqueView::<AsyncExecutor>::borrow_info(constraints);
+                        $(
+                            $type::borrow_info(constraints);
+                        )+
+
+                        TypeId::of::<Func>()
+                    }),
+                    before_all: DedupedLabels::new(),
+                    after_all: DedupedLabels::new(),
+                    after: Vec::new(),
+                    before: Vec::new(),
+                    unique_id: 0,
+                    tags: vec![Box::new(SystemLabel {
+                        type_id: TypeId::of::<Func>(),
+                        name: type_name::<Func>().as_label(),
+                    })],
+                    run_if: None,
+                    require_in_workload: DedupedLabels::new(),
+                    require_before: DedupedLabels::new(),
+                    require_after: DedupedLabels::new(),
+                    priority: 0,
+                    stateful: false,
+                    run_if_name: None,
+                })
+            }
+        }
+    }
+}
+
+macro_rules! into_workload_async_system {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_workload_async_system![$(($type, $index))*];
+        into_workload_async_system![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_into_workload_async_system![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+into_workload_async_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
+#[cfg(feature = "extended_tuple")]
+into_workload_async_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
@@ -1,9 +1,7 @@
-use crate::all_storages::AllStorages;
-use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::error;
+use crate::scheduler::into_workload_system::check_borrows;
 use crate::scheduler::system::{RunIf, WorkloadRunIfFn};
-use crate::scheduler::TypeInfo;
-use crate::storage::StorageId;
 use crate::tracking::TrackingTimestamp;
 use crate::World;
 use alloc::boxed::Box;
@@ -51,34 +49,7 @@ macro_rules! impl_into_workload_run_if {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                let mid = borrows.len() / 2 + (borrows.len() % 2 != 0) as usize;
-
-                for a_type_info in &borrows[..mid] {
-                    for b_type_info in &borrows[mid..] {
-                        if a_type_info.storage_id == b_type_info.storage_id {
-                            match (a_type_info.mutability, b_type_info.mutability) {
-                                (Mutability::Exclusive, Mutability::Exclusive) => {
-                                    return Err(error::InvalidSystem::MultipleViewsMut)
-                                }
-                                (Mutability::Exclusive, Mutability::Shared)
-                                | (Mutability::Shared, Mutability::Exclusive) => {
-                                    return Err(error::InvalidSystem::MultipleViews)
-                                }
-                                (Mutability::Shared, Mutability::Shared) => {}
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let last_run = AtomicU64::new(0);
                 Ok(RunIf {
@@ -104,7 +75,10 @@ macro_rules! into_workload_run_if {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_workload_run_if![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_workload_run_if![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_workload_run_if![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
@@ -144,34 +118,7 @@ macro_rules! impl_into_workload_run_if {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let last_run = Arc::new(AtomicU64::new(0));
                 Ok(Box::new(move |world: &World| {
@@ -195,7 +142,10 @@ macro_rules! into_workload_run_if {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_workload_run_if![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_workload_run_if![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_workload_run_if![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
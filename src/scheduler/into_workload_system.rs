@@ -24,11 +24,49 @@ pub trait IntoWorkloadSystem<B, R> {
     #[doc(hidden)]
     fn label(&self) -> Box<dyn Label>;
     #[doc(hidden)]
-    fn call(&self) -> R;
+    fn call(self) -> R;
 }
 
 pub struct Nothing;
 
+/// Checks a system's borrows can be held at the same time.
+pub(crate) fn check_borrows(borrows: &[TypeInfo]) -> Result<(), error::InvalidSystem> {
+    if borrows.contains(&TypeInfo {
+        name: "".into(),
+        storage_id: StorageId::of::<AllStorages>(),
+        mutability: Mutability::Exclusive,
+        thread_safe: true,
+    }) && borrows.len() > 1
+    {
+        return Err(error::InvalidSystem::AllStorages);
+    }
+
+    for (i, a_type_info) in borrows.iter().enumerate() {
+        for b_type_info in &borrows[i + 1..] {
+            if a_type_info.storage_id == b_type_info.storage_id {
+                match (a_type_info.mutability, b_type_info.mutability) {
+                    (Mutability::Exclusive, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViewsMut(
+                            error::ConflictingBorrow::new(a_type_info),
+                            error::ConflictingBorrow::new(b_type_info),
+                        ))
+                    }
+                    (Mutability::Exclusive, Mutability::Shared)
+                    | (Mutability::Shared, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViews(
+                            error::ConflictingBorrow::new(a_type_info),
+                            error::ConflictingBorrow::new(b_type_info),
+                        ))
+                    }
+                    (Mutability::Shared, Mutability::Shared) => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 impl<R, F> IntoWorkloadSystem<Nothing, R> for F
 where
     R: 'static,
@@ -55,6 +93,9 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            stateful: false,
+            run_if_name: None,
         })
     }
     fn label(&self) -> Box<dyn Label> {
@@ -70,7 +111,7 @@ where
             })
         }
     }
-    fn call(&self) -> R {
+    fn call(self) -> R {
         (self)()
     }
 }
@@ -85,7 +126,7 @@ impl IntoWorkloadSystem<WorkloadSystem, ()> for WorkloadSystem {
             name: self.display_name.clone(),
         })
     }
-    fn call(&self) {
+    fn call(self) {
         unreachable!()
     }
 }
@@ -108,34 +149,7 @@ macro_rules! impl_into_workload_system {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
@@ -170,6 +184,9 @@ macro_rules! impl_into_workload_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    stateful: false,
+                    run_if_name: None,
                 })
             }
             fn label(&self) -> Box<dyn Label> {
@@ -178,7 +195,7 @@ macro_rules! impl_into_workload_system {
                     name: type_name::<Func>().as_label(),
                 })
             }
-            fn call(&self) -> Ret {
+            fn call(self) -> Ret {
                 unreachable!()
             }
         }
@@ -196,7 +213,10 @@ macro_rules! into_workload_system {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_workload_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_workload_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_workload_system![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
@@ -204,3 +224,56 @@ into_workload_system![
     (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
     (EE, 30) (FF, 31)
 ];
+
+/// Asserts a function can be used as a system.
+///
+/// Meant to be used in tests, it catches invalid borrows like [`MultipleViewsMut`](crate::error::InvalidSystem::MultipleViewsMut)
+/// without having to build a workload.\
+/// Only available with the `test_utils` feature, usually enabled in `[dev-dependencies]`.
+///
+/// ### Panics
+///
+/// - The function can't be used as a system, the panic message names the function and the reason.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{assert_valid_system, Component, View, ViewMut};
+///
+/// #[derive(Component)]
+/// struct U32(u32);
+///
+/// #[derive(Component)]
+/// struct USIZE(usize);
+///
+/// fn sys(_: View<U32>, _: ViewMut<USIZE>) {}
+///
+/// assert_valid_system!(sys);
+/// ```
+///
+/// ```should_panic
+/// use shipyard::{assert_valid_system, Component, ViewMut};
+///
+/// #[derive(Component)]
+/// struct U32(u32);
+///
+/// fn sys(_: ViewMut<U32>, _: ViewMut<U32>) {}
+///
+/// assert_valid_system!(sys);
+/// ```
+#[cfg(feature = "test_utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test_utils")))]
+#[macro_export]
+macro_rules! assert_valid_system {
+    ($system: expr) => {
+        if let ::core::result::Result::Err(err) =
+            $crate::scheduler::IntoWorkloadSystem::into_workload_system($system)
+        {
+            ::core::panic!(
+                "`{}` is not a valid system: {:?}",
+                ::core::stringify!($system),
+                err
+            );
+        }
+    };
+}
@@ -1,11 +1,9 @@
-use crate::all_storages::AllStorages;
-use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::error;
 use crate::scheduler::info::DedupedLabels;
-use crate::scheduler::into_workload_system::Nothing;
+use crate::scheduler::into_workload_system::{check_borrows, Nothing};
 use crate::scheduler::label::SystemLabel;
-use crate::scheduler::{AsLabel, TypeInfo, WorkloadSystem};
-use crate::storage::StorageId;
+use crate::scheduler::{AsLabel, WorkloadSystem};
 use crate::tracking::TrackingTimestamp;
 use crate::World;
 use alloc::boxed::Box;
@@ -19,16 +17,89 @@ use core::sync::atomic::{AtomicU64, Ordering};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+/// Return type of failable systems.
+///
+/// The `Ok` and `Err` types are read from the function's signature,\
+/// no type annotation is needed when adding a system returning a `Result`.
+pub trait SystemResult {
+    /// Value returned on success.
+    type Ok;
+    /// Error returned on failure.
+    type Err;
+
+    /// Converts the system's return value to a `Result`.
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<Ok, Err> SystemResult for Result<Ok, Err> {
+    type Ok = Ok;
+    type Err = Err;
+
+    #[inline]
+    fn into_result(self) -> Result<Ok, Err> {
+        self
+    }
+}
+
 /// Validates a function can become a failable system.
 pub trait IntoWorkloadTrySystem<Views, R> {
     /// Wraps a fallible function in a struct containing all information required by a workload.  
     /// The workload will stop if an error is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::error::MissingComponent;
+    /// use shipyard::scheduler::IntoWorkloadTrySystem;
+    /// use shipyard::{Component, Get, IntoIter, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Target(shipyard::EntityId);
+    ///
+    /// #[derive(Debug)]
+    /// struct TargetLost;
+    ///
+    /// impl std::fmt::Display for TargetLost {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("Target lost")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for TargetLost {}
+    ///
+    /// impl From<MissingComponent> for TargetLost {
+    ///     fn from(_: MissingComponent) -> Self {
+    ///         TargetLost
+    ///     }
+    /// }
+    ///
+    /// fn follow(targets: View<Target>) -> Result<(), TargetLost> {
+    ///     for target in targets.iter() {
+    ///         targets.get(target.0)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.add_entity(Target(entity));
+    ///
+    /// Workload::new("Follow")
+    ///     .with_system(follow.into_workload_try_system().unwrap())
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let err = world.run_workload("Follow").unwrap_err();
+    /// assert!(err.custom_error().unwrap().is::<TargetLost>());
+    /// ```
     #[cfg(feature = "std")]
     fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(
         self,
     ) -> Result<WorkloadSystem, error::InvalidSystem>
     where
-        R: Into<Result<Ok, Err>>;
+        R: SystemResult<Ok = Ok, Err = Err>;
     /// Wraps a fallible function in a struct containing all information required by a workload.  
     /// The workload will stop if an error is returned.
     #[cfg(not(feature = "std"))]
@@ -36,7 +107,7 @@ pub trait IntoWorkloadTrySystem<Views, R> {
         self,
     ) -> Result<WorkloadSystem, error::InvalidSystem>
     where
-        R: Into<Result<Ok, Err>>;
+        R: SystemResult<Ok = Ok, Err = Err>;
 }
 
 impl<R: 'static, F> IntoWorkloadTrySystem<Nothing, R> for F
@@ -48,7 +119,7 @@ where
         self,
     ) -> Result<WorkloadSystem, error::InvalidSystem>
     where
-        R: Into<Result<Ok, Err>>,
+        R: SystemResult<Ok = Ok, Err = Err>,
     {
         let system_type_name = type_name::<F>();
 
@@ -56,7 +127,7 @@ where
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
             system_fn: Box::new(move |_: &World| {
-                (self)().into().map_err(error::Run::from_custom)?;
+                (self)().into_result().map_err(error::Run::from_custom)?;
                 Ok(())
             }),
             type_id: TypeId::of::<F>(),
@@ -78,6 +149,9 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            stateful: false,
+            run_if_name: None,
         })
     }
     #[cfg(not(feature = "std"))]
@@ -85,7 +159,7 @@ where
         self,
     ) -> Result<WorkloadSystem, error::InvalidSystem>
     where
-        R: Into<Result<Ok, Err>>,
+        R: SystemResult<Ok = Ok, Err = Err>,
     {
         let system_type_name = type_name::<F>();
 
@@ -93,7 +167,7 @@ where
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
             system_fn: Box::new(move |_: &World| {
-                (self)().into().map_err(error::Run::from_custom)?;
+                (self)().into_result().map_err(error::Run::from_custom)?;
                 Ok(())
             }),
             type_id: TypeId::of::<F>(),
@@ -115,6 +189,9 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            stateful: false,
+            run_if_name: None,
         })
     }
 }
@@ -151,40 +228,13 @@ macro_rules! impl_into_workload_try_system {
                 + Fn($($type::WorldView<'a>),+) -> Ret
         {
             #[cfg(feature = "std")]
-            fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(self) -> Result<WorkloadSystem, error::InvalidSystem> where Ret: Into<Result<Ok, Err>> {
+            fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(self) -> Result<WorkloadSystem, error::InvalidSystem> where Ret: SystemResult<Ok = Ok, Err = Err> {
                 let mut borrows = Vec::new();
                 $(
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
@@ -198,7 +248,7 @@ macro_rules! impl_into_workload_try_system {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
                         let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
+                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into_result().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(SystemLabel {
@@ -225,43 +275,19 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    stateful: false,
+                    run_if_name: None,
                 })
             }
             #[cfg(not(feature = "std"))]
-            fn into_workload_try_system<Ok, Err: 'static + Send + Any>(self) -> Result<WorkloadSystem, error::InvalidSystem> where Ret: Into<Result<Ok, Err>> {
+            fn into_workload_try_system<Ok, Err: 'static + Send + Any>(self) -> Result<WorkloadSystem, error::InvalidSystem> where Ret: SystemResult<Ok = Ok, Err = Err> {
                 let mut borrows = Vec::new();
                 $(
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
@@ -275,7 +301,7 @@ macro_rules! impl_into_workload_try_system {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
                         let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
+                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into_result().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(SystemLabel {
@@ -302,6 +328,9 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    stateful: false,
+                    run_if_name: None,
                 })
             }
         }
@@ -319,7 +348,10 @@ macro_rules! into_workload_try_system {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_workload_try_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_workload_try_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_workload_try_system![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
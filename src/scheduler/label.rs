@@ -8,6 +8,30 @@ use core::fmt::{Debug, Formatter};
 use core::hash::{Hash, Hasher};
 
 /// Workload identifier
+///
+/// Implemented for string types and [`TypeId`].\
+/// Unit structs and enums can implement it with `#[derive(Label)]`, typos are then caught at compile time.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Label, Workload, World};
+///
+/// #[derive(Label, Clone, Copy, Debug, Hash, PartialEq)]
+/// enum Stage {
+///     Update,
+///     Render,
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new(Stage::Update).add_to_world(&world).unwrap();
+/// Workload::new("Render").add_to_world(&world).unwrap();
+///
+/// world.run_workload(Stage::Update).unwrap();
+/// world.run_workload("Render").unwrap();
+/// assert!(world.run_workload(Stage::Render).is_err());
+/// ```
 pub trait Label: 'static + Send + Sync {
     #[allow(missing_docs)]
     fn as_any(&self) -> &dyn Any;
@@ -0,0 +1,334 @@
+use crate::error;
+use crate::scheduler::info::DedupedLabels;
+use crate::scheduler::into_workload_run_if::IntoRunIf;
+use crate::scheduler::label::Label;
+use crate::scheduler::system::RunIf;
+use crate::scheduler::{IntoWorkloadSystem, SystemModificator, Workload, WorkloadSystem};
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Description of a [`Workload`]'s structure, without its systems' functions.
+///
+/// Created with [`Workload::to_manifest`] and turned back into a workload with [`Workload::from_manifest`].\
+/// Systems are referenced by name and looked up in a [`SystemRegistry`].
+///
+/// Labels are stored as strings, run if conditions are stored by name.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkloadManifest {
+    #[allow(missing_docs)]
+    pub name: String,
+    #[allow(missing_docs)]
+    pub systems: Vec<SystemManifest>,
+    /// Number of systems before each barrier.
+    pub barriers: Vec<usize>,
+    /// Number of systems before each flush.
+    pub flushes: Vec<usize>,
+    /// Set with [`Workload::max_threads`].
+    pub max_threads: Option<usize>,
+    /// Number of groups set with [`Workload::staggered`].
+    pub staggered: Option<usize>,
+}
+
+/// Description of a system inside a [`WorkloadManifest`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemManifest {
+    /// Name the system is registered with in the [`SystemRegistry`].
+    pub name: String,
+    #[allow(missing_docs)]
+    pub tags: Vec<String>,
+    #[allow(missing_docs)]
+    pub before_all: Vec<String>,
+    #[allow(missing_docs)]
+    pub after_all: Vec<String>,
+    #[allow(missing_docs)]
+    pub require_in_workload: Vec<String>,
+    #[allow(missing_docs)]
+    pub require_before: Vec<String>,
+    #[allow(missing_docs)]
+    pub require_after: Vec<String>,
+    /// Set with [`SystemModificator::priority`].
+    pub priority: i32,
+    /// Name the run if condition is registered with in the [`SystemRegistry`].
+    pub run_if: Option<String>,
+}
+
+/// Maps names to systems and run if conditions, used by [`Workload::from_manifest`].
+#[derive(Default)]
+pub struct SystemRegistry {
+    #[allow(clippy::type_complexity)]
+    systems: ShipHashMap<String, Box<dyn Fn() -> WorkloadSystem + Send + Sync>>,
+    #[allow(clippy::type_complexity)]
+    run_ifs: ShipHashMap<String, Box<dyn Fn() -> RunIf + Send + Sync>>,
+}
+
+impl SystemRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> SystemRegistry {
+        SystemRegistry::default()
+    }
+    /// Registers `system` under the name [`Workload::to_manifest`] gives it.
+    ///
+    /// ### Errors
+    ///
+    /// - `system` is not a valid system.
+    pub fn register<B, R, S>(&mut self, system: S) -> Result<&mut Self, error::InvalidSystem>
+    where
+        S: IntoWorkloadSystem<B, R> + Clone + Send + Sync + 'static,
+    {
+        let name = format!("{:?}", system.clone().into_workload_system()?.display_name);
+
+        self.register_as(name, system)
+    }
+    /// Registers `system` under `name`.
+    ///
+    /// ### Errors
+    ///
+    /// - `system` is not a valid system.
+    pub fn register_as<B, R, S>(
+        &mut self,
+        name: impl Into<String>,
+        system: S,
+    ) -> Result<&mut Self, error::InvalidSystem>
+    where
+        S: IntoWorkloadSystem<B, R> + Clone + Send + Sync + 'static,
+    {
+        system.clone().into_workload_system()?;
+
+        self.systems.insert(
+            name.into(),
+            Box::new(move || system.clone().into_workload_system().unwrap()),
+        );
+
+        Ok(self)
+    }
+    /// Registers `run_if` under `name`.
+    ///
+    /// ### Errors
+    ///
+    /// - `run_if` is not a valid run if condition.
+    pub fn register_run_if<B, Run>(
+        &mut self,
+        name: impl Into<String>,
+        run_if: Run,
+    ) -> Result<&mut Self, error::InvalidSystem>
+    where
+        Run: IntoRunIf<B> + Clone + Send + Sync + 'static,
+    {
+        run_if.clone().into_workload_run_if()?;
+
+        self.run_ifs.insert(
+            name.into(),
+            Box::new(move || run_if.clone().into_workload_run_if().unwrap()),
+        );
+
+        Ok(self)
+    }
+}
+
+impl Workload {
+    /// Describes the structure of this workload: its systems' names, tags, ordering, requirements, barriers and flushes.
+    ///
+    /// ### Errors
+    ///
+    /// Workloads that wouldn't be rebuilt identically by [`Workload::from_manifest`] are rejected:
+    /// - The workload has a run if condition or a system has one not set with [`SystemModificator::run_if_as`], closures can't be named.
+    /// - A label would not match the same systems once turned into a string.
+    /// - The workload is ordered relative to other workloads or a system is ordered by id.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::{SystemModificator, SystemRegistry};
+    /// use shipyard::Workload;
+    ///
+    /// fn physics() {}
+    /// fn render() {}
+    ///
+    /// let workload = Workload::new("Frame")
+    ///     .with_system(render.after_all(physics))
+    ///     .with_system(physics);
+    ///
+    /// let manifest = workload.to_manifest().unwrap();
+    ///
+    /// let mut registry = SystemRegistry::new();
+    /// registry.register(physics).unwrap().register(render).unwrap();
+    ///
+    /// let rebuilt = Workload::from_manifest(&manifest, &registry).unwrap();
+    ///
+    /// assert_eq!(rebuilt.to_manifest().unwrap(), manifest);
+    /// ```
+    pub fn to_manifest(&self) -> Result<WorkloadManifest, error::ToManifest> {
+        if self.run_if.is_some() {
+            return Err(error::ToManifest::RunIf(format!("{:?}", self.name)));
+        }
+
+        if !self.before_all.is_empty()
+            || !self.after_all.is_empty()
+            || !self.require_before.is_empty()
+            || !self.require_after.is_empty()
+        {
+            return Err(error::ToManifest::Ordering(format!("{:?}", self.name)));
+        }
+
+        let systems = self
+            .systems
+            .iter()
+            .map(|system| {
+                let name = format!("{:?}", system.display_name);
+                let system_label = format!("System({})", name);
+
+                SystemManifest {
+                    tags: system
+                        .tags
+                        .iter()
+                        .map(|tag| format!("{:?}", tag))
+                        .filter(|tag| *tag != name && *tag != system_label)
+                        .collect(),
+                    before_all: labels_to_strings(&system.before_all),
+                    after_all: labels_to_strings(&system.after_all),
+                    require_in_workload: labels_to_strings(&system.require_in_workload),
+                    require_before: labels_to_strings(&system.require_before),
+                    require_after: labels_to_strings(&system.require_after),
+                    priority: system.priority,
+                    run_if: system.run_if_name.clone(),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for (system, system_manifest) in self.systems.iter().zip(&systems) {
+            if system.run_if.is_some() && system.run_if_name.is_none() {
+                return Err(error::ToManifest::RunIf(system_manifest.name.clone()));
+            }
+
+            if !system.before.is_empty() || !system.after.is_empty() {
+                return Err(error::ToManifest::Ordering(system_manifest.name.clone()));
+            }
+
+            let labels = system
+                .before_all
+                .iter()
+                .chain(&system.after_all)
+                .chain(&system.require_in_workload)
+                .chain(&system.require_before)
+                .chain(&system.require_after);
+
+            for label in labels {
+                let label_str = format!("{:?}", label);
+
+                if !matches_same_systems(&**label, &label_str, &self.systems, &systems) {
+                    return Err(error::ToManifest::Label {
+                        system: system_manifest.name.clone(),
+                        label: label_str,
+                    });
+                }
+            }
+        }
+
+        Ok(WorkloadManifest {
+            name: format!("{:?}", self.name),
+            systems,
+            barriers: self.barriers.clone(),
+            flushes: self.flushes.clone(),
+            max_threads: self.max_threads,
+            staggered: self.staggered,
+        })
+    }
+    /// Creates a workload from `manifest`, looking up systems and run if conditions in `registry`.
+    ///
+    /// All labels are turned into `String`s, each system is tagged with both its manifest name
+    /// and `System(<name>)` so ordering written by [`Workload::to_manifest`] still applies.
+    ///
+    /// ### Errors
+    ///
+    /// - A system or run if condition is not in `registry`.
+    /// - A barrier or flush is placed after the last system.
+    pub fn from_manifest(
+        manifest: &WorkloadManifest,
+        registry: &SystemRegistry,
+    ) -> Result<Workload, error::FromManifest> {
+        let mut workload = Workload::new(manifest.name.clone());
+
+        for system_manifest in &manifest.systems {
+            let factory = registry
+                .systems
+                .get(&system_manifest.name)
+                .ok_or_else(|| error::FromManifest::UnknownSystem(system_manifest.name.clone()))?;
+
+            let mut system = factory()
+                .tag(system_manifest.name.clone())
+                .tag(format!("System({})", system_manifest.name));
+
+            for tag in &system_manifest.tags {
+                system = system.tag(tag.clone());
+            }
+            for label in &system_manifest.before_all {
+                system = system.before_all(label.clone());
+            }
+            for label in &system_manifest.after_all {
+                system = system.after_all(label.clone());
+            }
+            for label in &system_manifest.require_in_workload {
+                system = system.require_in_workload(label.clone());
+            }
+            for label in &system_manifest.require_before {
+                system = system.require_before(label.clone());
+            }
+            for label in &system_manifest.require_after {
+                system = system.require_after(label.clone());
+            }
+            system = system.priority(system_manifest.priority);
+
+            if let Some(run_if_name) = &system_manifest.run_if {
+                let run_if = registry
+                    .run_ifs
+                    .get(run_if_name)
+                    .ok_or_else(|| error::FromManifest::UnknownRunIf(run_if_name.clone()))?;
+
+                system = system.run_if_as(run_if_name.clone(), run_if());
+            }
+
+            workload = workload.with_system(system);
+        }
+
+        if let Some(&index) = manifest
+            .barriers
+            .iter()
+            .chain(&manifest.flushes)
+            .find(|&&index| index > manifest.systems.len())
+        {
+            return Err(error::FromManifest::OutOfBounds(index));
+        }
+
+        workload.barriers = manifest.barriers.clone();
+        workload.flushes = manifest.flushes.clone();
+        workload.max_threads = manifest.max_threads;
+        workload.staggered = manifest.staggered;
+
+        Ok(workload)
+    }
+}
+
+fn labels_to_strings(labels: &DedupedLabels) -> Vec<String> {
+    labels.iter().map(|label| format!("{:?}", label)).collect()
+}
+
+/// Returns `true` if `label` and its string version are found in the same systems.
+fn matches_same_systems(
+    label: &dyn Label,
+    label_str: &str,
+    systems: &[WorkloadSystem],
+    manifests: &[SystemManifest],
+) -> bool {
+    systems.iter().zip(manifests).all(|(system, manifest)| {
+        let matched_by_str = label_str == manifest.name
+            || label_str == format!("System({})", manifest.name)
+            || manifest.tags.iter().any(|tag| tag == label_str);
+
+        system.tags.iter().any(|tag| **tag == *label) == matched_by_str
+    })
+}
@@ -1,25 +1,39 @@
 mod batches;
+mod fn_mut_system;
 pub mod info;
 mod into_workload;
+mod into_workload_async_system;
 mod into_workload_run_if;
 mod into_workload_system;
 mod into_workload_try_system;
 mod label;
+mod manifest;
 mod system;
 mod system_modificator;
 mod workload;
 mod workload_modificator;
+mod workload_runner;
+mod workload_stage;
 
+pub use fn_mut_system::FnMutSystem;
 pub use into_workload::IntoWorkload;
+pub use into_workload_async_system::{
+    AsyncExecutor, AsyncSystemFn, Executor, IntoWorkloadAsyncSystem,
+};
 pub use into_workload_system::IntoWorkloadSystem;
-pub use into_workload_try_system::IntoWorkloadTrySystem;
+pub use into_workload_try_system::{IntoWorkloadTrySystem, SystemResult};
 pub use label::{AsLabel, Label};
+pub use manifest::{SystemManifest, SystemRegistry, WorkloadManifest};
 pub use system::WorkloadSystem;
 pub use system_modificator::SystemModificator;
 pub use workload::{ScheduledWorkload, Workload};
 pub use workload_modificator::WorkloadModificator;
+pub use workload_runner::WorkloadRunner;
+pub use workload_stage::WorkloadStage;
 
-pub(crate) use batches::Batches;
+#[cfg(all(feature = "parallel", feature = "std"))]
+pub(crate) use batches::{inherit_running, running_workloads};
+pub(crate) use batches::{AdvanceOnExit, Batches};
 pub(crate) use info::TypeInfo;
 
 use crate::scheduler::info::WorkloadInfo;
@@ -6,6 +6,7 @@ use crate::scheduler::label::Label;
 use crate::scheduler::workload::Workload;
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::any::TypeId;
 
@@ -67,6 +68,22 @@ pub struct WorkloadSystem {
     pub require_before: DedupedLabels,
     #[allow(missing_docs)]
     pub require_after: DedupedLabels,
+    /// Systems with a higher priority are dispatched first within their batch
+    pub(crate) priority: i32,
+    /// Stateful systems are never merged with another system of the same type, each one keeps its own state
+    pub(crate) stateful: bool,
+    /// Name given with [`SystemModificator::run_if_as`](crate::scheduler::SystemModificator::run_if_as),
+    /// only kept while this condition is the system's only one
+    pub(crate) run_if_name: Option<String>,
+}
+
+impl WorkloadSystem {
+    /// Returns the priority set with [`SystemModificator::priority`](crate::scheduler::SystemModificator::priority).\
+    /// Defaults to 0.
+    #[inline]
+    pub fn get_priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 impl Extend<WorkloadSystem> for Workload {
@@ -8,12 +8,54 @@ use crate::storage::StorageId;
 use crate::unique::UniqueStorage;
 use crate::views::AllStoragesViewMut;
 use alloc::boxed::Box;
+use alloc::string::String;
 use core::ops::Not;
 
 /// Allows to set if and when a systems runs.
 pub trait SystemModificator<B, R> {
     /// Only run the system if the function evaluates to `true`.
     fn run_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem;
+    /// Same as [`run_if`](SystemModificator::run_if) but names the condition.\
+    /// [`Workload::to_manifest`](crate::Workload::to_manifest) writes this name,
+    /// [`Workload::from_manifest`](crate::Workload::from_manifest) looks it up in the [`SystemRegistry`](crate::scheduler::SystemRegistry).
+    ///
+    /// The name is dropped if the system gets other conditions.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::scheduler::{SystemModificator, SystemRegistry};
+    /// use shipyard::{Unique, UniqueView, Workload};
+    ///
+    /// #[derive(Unique)]
+    /// struct Paused(bool);
+    ///
+    /// fn physics() {}
+    /// fn is_running(paused: UniqueView<Paused>) -> bool {
+    ///     !paused.0
+    /// }
+    ///
+    /// let workload = Workload::new("Frame").with_system(physics.run_if_as("is_running", is_running));
+    ///
+    /// let manifest = workload.to_manifest().unwrap();
+    /// assert_eq!(manifest.systems[0].run_if.as_deref(), Some("is_running"));
+    ///
+    /// let mut registry = SystemRegistry::new();
+    /// registry
+    ///     .register(physics)
+    ///     .unwrap()
+    ///     .register_run_if("is_running", is_running)
+    ///     .unwrap();
+    ///
+    /// let rebuilt = Workload::from_manifest(&manifest, &registry).unwrap();
+    ///
+    /// assert_eq!(rebuilt.to_manifest().unwrap(), manifest);
+    /// ```
+    fn run_if_as<RunB, Run: IntoRunIf<RunB>>(
+        self,
+        name: impl Into<String>,
+        run_if: Run,
+    ) -> WorkloadSystem;
     /// Only run the system if the `T` storage is empty.
     ///
     /// If the storage is not present it is considered empty.
@@ -115,6 +157,74 @@ pub trait SystemModificator<B, R> {
     ///
     /// Does not change system ordering.
     fn require_after<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
+    /// Systems with a higher priority are dispatched first to the thread pool within their batch.\
+    /// Defaults to 0.
+    ///
+    /// Does not change system ordering, only when systems that can run concurrently start.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::scheduler::SystemModificator;
+    /// use shipyard::{Component, View, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// fn pathfinding(_: View<Position>) {}
+    /// fn animation(_: View<Position>) {}
+    ///
+    /// let (_, info) = Workload::new("Update")
+    ///     .with_system(animation)
+    ///     .with_system(pathfinding.priority(10))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut systems = info.batches_info[0].systems();
+    /// assert!(systems.next().unwrap().name.contains("pathfinding"));
+    /// assert!(systems.next().unwrap().name.contains("animation"));
+    /// ```
+    fn priority(self, priority: i32) -> WorkloadSystem;
+    /// Only run the system when the [`World`'s tick](crate::World::tick) is a multiple of `n`, starting with the first run.\
+    /// `n` is raised to 1 if it's 0.
+    ///
+    /// The tick goes up once at the end of every workload run on the `World`, whatever the workload.\
+    /// Systems declared `every(n)` are in phase with each other, even in different workloads,
+    /// but a system only sees the ticks of the runs of its workload.\
+    /// In a [`Workload::staggered`](crate::Workload::staggered) workload the system's group only runs on some ticks,
+    /// `every` is only checked on these.
+    ///
+    /// The other `run_if` conditions are only checked on the ticks allowed by `every`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::scheduler::SystemModificator;
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Collections(u32);
+    ///
+    /// fn collect_garbage(mut collections: UniqueViewMut<Collections>) {
+    ///     collections.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_unique(Collections(0));
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(collect_garbage.every(3))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// for _ in 0..7 {
+    ///     world.run_workload("Update").unwrap();
+    /// }
+    ///
+    /// assert_eq!(world.borrow::<UniqueView<Collections>>().unwrap().0, 3);
+    /// ```
+    fn every(self, n: usize) -> WorkloadSystem;
 }
 
 pub struct Nothing;
@@ -133,6 +243,14 @@ where
         system
     }
     #[track_caller]
+    fn run_if_as<RunB, Run: IntoRunIf<RunB>>(
+        self,
+        name: impl Into<String>,
+        run_if: Run,
+    ) -> WorkloadSystem {
+        self.into_workload_system().unwrap().run_if_as(name, run_if)
+    }
+    #[track_caller]
     fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
         let mut run_if = run_if.into_workload_run_if().unwrap();
 
@@ -196,6 +314,18 @@ where
 
         system
     }
+    #[track_caller]
+    fn priority(self, priority: i32) -> WorkloadSystem {
+        let mut system = self.into_workload_system().unwrap();
+
+        system.priority = priority;
+
+        system
+    }
+    #[track_caller]
+    fn every(self, n: usize) -> WorkloadSystem {
+        self.into_workload_system().unwrap().every(n)
+    }
 }
 
 impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
@@ -210,10 +340,26 @@ impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
         } else {
             Some(run_if.system_fn)
         };
+        self.run_if_name = None;
 
         self
     }
     #[track_caller]
+    fn run_if_as<RunB, Run: IntoRunIf<RunB>>(
+        self,
+        name: impl Into<String>,
+        run_if: Run,
+    ) -> WorkloadSystem {
+        let only_condition = self.run_if.is_none();
+        let mut system = self.run_if(run_if);
+
+        if only_condition {
+            system.run_if_name = Some(name.into());
+        }
+
+        system
+    }
+    #[track_caller]
     fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
         let mut run_if = run_if.into_workload_run_if().unwrap();
 
@@ -257,6 +403,29 @@ impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
     fn require_after<T>(mut self, other: impl AsLabel<T>) -> WorkloadSystem {
         self.require_after.add(other);
 
+        self
+    }
+    fn priority(mut self, priority: i32) -> WorkloadSystem {
+        self.priority = priority;
+
+        self
+    }
+    fn every(mut self, n: usize) -> WorkloadSystem {
+        let n = n.max(1) as u64;
+        let prev_run_if = self.run_if.take();
+        self.run_if_name = None;
+
+        self.run_if = Some(Box::new(move |world| {
+            if world.tick() % n != 0 {
+                return Ok(false);
+            }
+
+            match &prev_run_if {
+                Some(prev_run_if) => (prev_run_if)(world),
+                None => Ok(true),
+            }
+        }));
+
         self
     }
 }
@@ -283,6 +452,16 @@ macro_rules! impl_into_workload_system {
                 system
             }
             #[track_caller]
+            fn run_if_as<RunB, Run: IntoRunIf<RunB>>(
+                self,
+                name: impl Into<String>,
+                run_if: Run,
+            ) -> WorkloadSystem {
+                IntoWorkloadSystem::<($($type,)+), Ret>::into_workload_system(self)
+                    .unwrap()
+                    .run_if_as(name, run_if)
+            }
+            #[track_caller]
             fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
                 let mut run_if = run_if.into_workload_run_if().unwrap();
 
@@ -346,6 +525,18 @@ macro_rules! impl_into_workload_system {
 
                 system
             }
+            #[track_caller]
+            fn priority(self, priority: i32) -> WorkloadSystem {
+                let mut system = IntoWorkloadSystem::<($($type,)+), Ret>::into_workload_system(self).unwrap();
+
+                system.priority = priority;
+
+                system
+            }
+            #[track_caller]
+            fn every(self, n: usize) -> WorkloadSystem {
+                IntoWorkloadSystem::<($($type,)+), Ret>::into_workload_system(self).unwrap().every(n)
+            }
         }
     }
 }
@@ -361,7 +552,10 @@ macro_rules! into_workload_system {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-into_workload_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+into_workload_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 into_workload_system![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
@@ -1,17 +1,18 @@
 mod create_workload;
 
 use crate::all_storages::AllStorages;
+use crate::borrow::Mutability;
 use crate::component::{Component, Unique};
 use crate::scheduler::info::{DedupedLabels, TypeInfo, WorkloadInfo};
 use crate::scheduler::label::WorkloadLabel;
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
 use crate::scheduler::{
     AsLabel, Batches, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label, Scheduler,
-    WorkloadSystem,
+    SystemResult, WorkloadStage, WorkloadSystem,
 };
 use crate::storage::StorageId;
 use crate::unique::UniqueStorage;
-use crate::world::World;
+use crate::world::{RunOptions, World};
 use crate::{error, ShipHashMap};
 use alloc::boxed::Box;
 use core::any::TypeId;
@@ -57,7 +58,13 @@ impl ScheduledWorkload {
     /// - Storage borrow failed.
     /// - User error returned by system.
     pub fn run_with_world(&self, world: &World) -> Result<(), error::RunWorkload> {
-        world.run_batches(&self.systems, &self.system_names, &self.batches, &self.name)
+        world.run_batches(
+            &self.systems,
+            &self.system_names,
+            &self.batches,
+            &self.name,
+            RunOptions::default(),
+        )
     }
 
     /// Apply tracking to all storages using it during this workload.
@@ -117,6 +124,9 @@ impl World {
 /// A workload is a collection of systems. They will execute as much in parallel as possible.\
 /// They are evaluated first to last when they can't be parallelized.\
 /// The default workload will automatically be set to the first workload added.
+///
+/// Operations recorded with [`Commands`](crate::Commands) are applied at each [`Workload::flush`] and once all systems ran, never between batches.\
+/// A system running after another one, even in a later batch, only sees its commands if there is a flush between them.
 pub struct Workload {
     pub(super) name: Box<dyn Label>,
     pub(super) tags: Vec<Box<dyn Label>>,
@@ -128,6 +138,19 @@ pub struct Workload {
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) flushes: Vec<usize>,
+    pub(super) max_threads: Option<usize>,
+    /// Number of groups set with [`Workload::staggered`]
+    pub(super) staggered: Option<usize>,
+    /// Stages declared with [`Workload::stage`], in order
+    pub(super) stages: Vec<Box<dyn Label>>,
+}
+
+impl Default for Workload {
+    /// Creates a new empty [`Workload`] named `""`, same as `Workload::new("")`.
+    fn default() -> Self {
+        Workload::new("")
+    }
 }
 
 impl Workload {
@@ -186,6 +209,10 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            flushes: Vec::new(),
+            max_threads: None,
+            staggered: None,
+            stages: Vec::new(),
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.
@@ -196,6 +223,13 @@ impl Workload {
         }
 
         self.systems.append(&mut other.systems);
+        // The lowest limit applies to all systems
+        self.max_threads = match (self.max_threads, other.max_threads) {
+            (Some(max_threads), Some(other_max_threads)) => {
+                Some(max_threads.min(other_max_threads))
+            }
+            (max_threads, other_max_threads) => max_threads.or(other_max_threads),
+        };
 
         self
     }
@@ -212,6 +246,8 @@ impl Workload {
                 .drain(..)
                 .map(|barrier| barrier + systems_len),
         );
+        self.flushes
+            .extend(other.flushes.drain(..).map(|flush| flush + systems_len));
 
         self.append(&mut other)
     }
@@ -219,6 +255,10 @@ impl Workload {
     /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
     fn propagate(&mut self) {
         for system in &mut self.systems {
+            if self.run_if.is_some() {
+                system.run_if_name = None;
+            }
+
             system.run_if = match (system.run_if.take(), self.run_if.clone()) {
                 (None, None) => None,
                 (None, Some(run_if)) => Some(run_if.to_non_clone()),
@@ -348,7 +388,7 @@ impl Workload {
         B,
         Ok,
         Err: 'static + Into<Box<dyn Error + Send + Sync>>,
-        R: Into<Result<Ok, Err>>,
+        R: SystemResult<Ok = Ok, Err = Err>,
         S: IntoWorkloadTrySystem<B, R>,
     >(
         mut self,
@@ -403,7 +443,7 @@ impl Workload {
         B,
         Ok,
         Err: 'static + Send + Any,
-        R: Into<Result<Ok, Err>>,
+        R: SystemResult<Ok = Ok, Err = Err>,
         S: IntoWorkloadTrySystem<B, R>,
     >(
         mut self,
@@ -548,12 +588,287 @@ impl Workload {
         Ok((workload, workload_info))
     }
 
+    /// Runs the same checks as [`build`](Workload::build) and [`add_to_world`](Workload::add_to_world) without consuming the workload.\
+    /// Returns every problem found instead of only the first one.
+    ///
+    /// Invalid systems are already rejected when they are added to the workload.\
+    /// Checks depending on a `World`, like a workload with the same name being present, are not performed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, scheduler::SystemModificator, Component, View, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// fn sys1(_: View<U32>) {}
+    /// fn sys2(_: View<U32>) {}
+    ///
+    /// let workload = Workload::new("Validate")
+    ///     .with_system(sys1.require_before(sys2))
+    ///     .with_system(sys2.require_in_workload("Missing"));
+    ///
+    /// let errors = workload.validate().unwrap_err();
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// assert!(matches!(errors[0], error::AddWorkload::MissingInWorkload(..)));
+    /// assert!(matches!(errors[1], error::AddWorkload::MissingBefore(..)));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<error::AddWorkload>> {
+        create_workload::validate_workload(self)
+    }
+
     /// Stop parallelism between systems before and after the barrier.
     pub fn with_barrier(mut self) -> Self {
         self.barriers.push(self.systems.len());
 
         self
     }
+    /// Applies the commands recorded by systems added before the flush.\
+    /// Systems added after the flush see their effects, it also acts as a barrier.
+    ///
+    /// Without any flush, commands are only applied once all systems ran.\
+    /// Ordering systems with barriers, `before_all`, `after_all`,... doesn't apply commands, only flushes do.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Commands, Component, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn spawn(mut commands: Commands) {
+    ///     commands.add_entity(Enemy);
+    /// }
+    ///
+    /// fn count(enemies: View<Enemy>) {
+    ///     assert_eq!(enemies.len(), 1);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("")
+    ///     .with_system(spawn)
+    ///     .flush()
+    ///     .with_system(count)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// ```
+    pub fn flush(mut self) -> Self {
+        self.barriers.push(self.systems.len());
+        self.flushes.push(self.systems.len());
+
+        self
+    }
+    /// Limits the number of threads running this workload's systems at the same time to `n`, even if the thread pool is larger.\
+    /// A limit of 1 runs each batch on the calling thread. `n` is raised to 1 if it's 0.
+    ///
+    /// When workloads are merged or appended, the lowest limit applies to the whole workload.\
+    /// Without limit, batches use the whole thread pool.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    /// fn sys3() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Small")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .with_system(sys3)
+    ///     .max_threads(2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Small").unwrap();
+    /// ```
+    pub fn max_threads(mut self, n: usize) -> Self {
+        self.max_threads = Some(n.max(1));
+
+        self
+    }
+    /// Splits this workload's systems into `k` groups and only runs one group each time the workload runs.\
+    /// Groups are visited in turn, each system runs once every `k` runs. `k` is raised to 1 if it's 0.
+    ///
+    /// Systems are assigned to groups in the order they are added: the first system to the first group, the second to the second and so on.\
+    /// The position in the cycle is kept by the workload and moves at the end of each run,
+    /// even when a system fails or a [`WorkloadRunner`](crate::scheduler::WorkloadRunner) is dropped before the end.\
+    /// Skipped systems are treated as if their `run_if` returned `false`, the count of a system using [`every`](crate::scheduler::SystemModificator::every) only goes up on the runs of its group.
+    ///
+    /// Only applies to the workload added to the `World`, nested workloads are split with the rest of the systems.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Runs(u32, u32);
+    ///
+    /// fn a(mut runs: UniqueViewMut<Runs>) {
+    ///     runs.0 += 1;
+    /// }
+    /// fn b(mut runs: UniqueViewMut<Runs>) {
+    ///     runs.1 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_unique(Runs(0, 0));
+    ///
+    /// Workload::new("AI")
+    ///     .with_system(a)
+    ///     .with_system(b)
+    ///     .staggered(2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("AI").unwrap();
+    /// world.run_workload("AI").unwrap();
+    /// world.run_workload("AI").unwrap();
+    ///
+    /// let runs = world.borrow::<UniqueView<Runs>>().unwrap();
+    /// assert_eq!((runs.0, runs.1), (2, 1));
+    /// ```
+    pub fn staggered(mut self, k: usize) -> Self {
+        self.staggered = Some(k.max(1));
+
+        self
+    }
+    /// Starts the `name` stage, or goes back to it if it was already declared.
+    ///
+    /// Stages run in the order they are first declared: all systems of a stage run after all systems of the previous stages.\
+    /// Within a stage, systems are run in parallel when their borrows allow it.\
+    /// Systems added to the workload directly are not part of any stage and are only ordered by their borrows.
+    ///
+    /// Stage names are added as tags to their systems, they can be used with `before_all`/`after_all` like any tag.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Input;
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// #[derive(Component)]
+    /// struct Sprite;
+    ///
+    /// fn read_keyboard(_: ViewMut<Input>) {}
+    /// fn physics(_: ViewMut<Position>) {}
+    /// fn render(_: View<Sprite>) {}
+    ///
+    /// let (_, info) = Workload::new("Frame")
+    ///     .stage("Input")
+    ///     .add_system(read_keyboard)
+    ///     .stage("Simulation")
+    ///     .add_system(physics)
+    ///     .stage("Render")
+    ///     .add_system(render)
+    ///     .end_stage()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // the systems don't share any storage but each stage waits for the previous one
+    /// assert_eq!(info.batches_info.len(), 3);
+    /// ```
+    pub fn stage<T>(mut self, name: impl AsLabel<T>) -> WorkloadStage {
+        let label = name.as_label();
+
+        let index = match self.stages.iter().position(|stage| *stage == label) {
+            Some(index) => index,
+            None => {
+                self.stages.push(label.clone());
+
+                self.stages.len() - 1
+            }
+        };
+
+        WorkloadStage::new(self, label, index)
+    }
+    /// Returns the number of systems in this workload, including systems of nested workloads.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::Workload;
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let workload = Workload::new("").with_system(sys1).with_system(sys2);
+    ///
+    /// assert_eq!(workload.len(), 2);
+    /// assert!(!workload.is_empty());
+    /// assert!(Workload::new("").is_empty());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+    /// Returns `true` if this workload contains no system.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+    /// Returns the storages borrowed by this workload's systems, as `(shared, exclusive)`.
+    ///
+    /// Both lists are sorted and deduplicated, a storage borrowed both ways is only listed as exclusive.\
+    /// Systems borrowing `AllStorages` exclusively list `StorageId::of::<AllStorages>()`.\
+    /// Run if conditions' borrows are not included.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::advanced::StorageId;
+    /// use shipyard::sparse_set::SparseSet;
+    /// use shipyard::{Component, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity;
+    ///
+    /// fn read(_: View<Position>, _: View<Velocity>) {}
+    /// fn write(_: ViewMut<Position>) {}
+    ///
+    /// let workload = Workload::new("").with_system(read).with_system(write);
+    ///
+    /// let (shared, exclusive) = workload.accessed_storages();
+    ///
+    /// assert_eq!(shared, [StorageId::of::<SparseSet<Velocity>>()]);
+    /// assert_eq!(exclusive, [StorageId::of::<SparseSet<Position>>()]);
+    /// ```
+    pub fn accessed_storages(&self) -> (Vec<StorageId>, Vec<StorageId>) {
+        let mut shared = Vec::new();
+        let mut exclusive = Vec::new();
+
+        for type_info in self
+            .systems
+            .iter()
+            .flat_map(|system| &system.borrow_constraints)
+        {
+            match type_info.mutability {
+                Mutability::Shared => shared.push(type_info.storage_id),
+                Mutability::Exclusive => exclusive.push(type_info.storage_id),
+            }
+        }
+
+        exclusive.sort_unstable();
+        exclusive.dedup();
+
+        shared.sort_unstable();
+        shared.dedup();
+        shared.retain(|storage_id| exclusive.binary_search(storage_id).is_err());
+
+        (shared, exclusive)
+    }
 }
 
 fn check_uniques_in_systems(
@@ -625,8 +940,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -654,8 +972,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -685,8 +1006,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -716,8 +1040,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -747,8 +1074,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -772,8 +1102,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -810,8 +1143,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -840,8 +1176,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -864,8 +1203,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -889,8 +1231,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -913,8 +1258,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -956,8 +1304,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -985,8 +1336,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1009,8 +1363,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1033,8 +1390,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1062,8 +1422,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1093,8 +1456,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1117,8 +1483,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1157,8 +1526,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                ..Default::default()
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1442,8 +1814,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                parallel_flushes: Vec::new(),
+                sequential_flushes: Vec::new(),
                 workload_run_if: None,
-                systems_run_if: Vec::new()
+                systems_run_if: Vec::new(),
+                ..Default::default()
             }
         );
     }
@@ -1545,6 +1920,24 @@ mod tests {
         assert_eq!(workload.1.batches_info.len(), 1);
     }
 
+    #[test]
+    fn flush() {
+        let (workload, _) = Workload::new("")
+            .flush()
+            .with_system(|| {})
+            .with_system(|| {})
+            .flush()
+            .with_system(|| {})
+            .flush()
+            .build()
+            .unwrap();
+
+        let batches = &workload.batches;
+        assert_eq!(batches.parallel, &[(None, vec![0, 1]), (None, vec![2])]);
+        assert_eq!(batches.parallel_flushes, &[0]);
+        assert_eq!(batches.sequential_flushes, &[1]);
+    }
+
     #[test]
     fn with_system_return_type() {
         Workload::new("").with_system(|| 0usize).build().unwrap();
@@ -1779,13 +2172,43 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn priority() {
+        fn sys_a() {}
+        fn sys_b() {}
+        fn sys_c() {}
+
+        let (workload, _) = Workload::new("")
+            .with_system(sys_a)
+            .with_system(sys_b.priority(1))
+            .with_system(sys_c.priority(2))
+            .with_barrier()
+            .with_system(sys_a.priority(-1))
+            .with_system(sys_b)
+            .build()
+            .unwrap();
+
+        let batches = &workload.batches;
+        assert_eq!(
+            batches.parallel,
+            &[(None, vec![2, 1, 0]), (None, vec![1, 0])]
+        );
+        assert_eq!(
+            batches.parallel_run_if,
+            &[
+                (usize::MAX, vec![usize::MAX; 3]),
+                (usize::MAX, vec![usize::MAX; 2])
+            ]
+        );
+        assert_eq!(batches.sequential, &[0, 1, 2, 0, 1]);
+    }
 }
 
 /// Tests related to `WorkloadInfo` and not system ordering.
 #[cfg(test)]
 mod info_tests {
     use super::*;
-    use crate::borrow::Mutability;
     use crate::scheduler::info::{BeforeAfterConstraint, Conflict, SystemInfo};
     use crate::scheduler::system_modificator::SystemModificator;
     use crate::sparse_set::SparseSet;
@@ -1,6 +1,7 @@
 use crate::all_storages::AllStorages;
 use crate::borrow::Mutability;
 use crate::error;
+use crate::scheduler::batches::Staggered;
 use crate::scheduler::info::{
     BatchInfo, BeforeAfterConstraint, Conflict, DedupedLabels, SystemInfo,
 };
@@ -27,6 +28,8 @@ struct ToBePlacedSystem {
     after_all_info: Vec<BeforeAfterConstraint>,
     /// Copy of `hard_after` to be returned in `SystemInfo`.
     after_info: DedupedUniqueIds,
+    /// Union of `soft_after` and `hard_after`, used to compute the critical path.
+    after: DedupedUniqueIds,
     // System must be after in the sequential order
     // System can run in parallel in the parallel order
     soft_after: DedupedUniqueIds,
@@ -39,6 +42,63 @@ struct ToBePlacedSystem {
     require_after: DedupedLabels,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>>,
     confict: Option<Conflict>,
+    priority: i32,
+}
+
+impl ToBePlacedSystem {
+    /// Copies the placement constraints of `system`, its run if is left to the caller.
+    fn new(index: usize, system: &WorkloadSystem) -> ToBePlacedSystem {
+        let mut hard_before = DedupedLabels::new();
+        hard_before.extend(system.before.iter().map(|&id| {
+            let id: Box<dyn Label> = Box::new(UniqueSystemId(id));
+
+            id
+        }));
+
+        let mut tags = system.tags.clone();
+        tags.push(Box::new(UniqueSystemId(system.unique_id)));
+
+        ToBePlacedSystem {
+            index,
+            display_name: system.display_name.clone(),
+            borrow_constraints: system.borrow_constraints.clone(),
+            tags,
+            before_all: system.before_all.clone(),
+            after_all_info: Vec::new(),
+            after_all: system.after_all.clone(),
+            before_all_info: Vec::new(),
+            after_info: DedupedUniqueIds::new(),
+            after: DedupedUniqueIds::new(),
+            hard_after: DedupedUniqueIds::new(),
+            hard_before,
+            soft_after: DedupedUniqueIds::new(),
+            require_in_workload: system.require_in_workload.clone(),
+            require_before: system.require_before.clone(),
+            require_after: system.require_after.clone(),
+            run_if: None,
+            confict: None,
+            priority: system.priority,
+        }
+    }
+}
+
+/// Returns the index `system` has in the scheduler, `len` if it isn't part of it yet.\
+/// Stateful systems are never merged with another system of the same type.
+fn system_index(
+    lookup_table: &mut ShipHashMap<TypeId, usize>,
+    system: &WorkloadSystem,
+    len: usize,
+) -> usize {
+    match lookup_table.get(&system.type_id) {
+        Some(&index) if !system.stateful => index,
+        _ => {
+            if !system.stateful {
+                lookup_table.insert(system.type_id, len);
+            }
+
+            len
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -97,14 +157,13 @@ pub(super) fn create_workload(
         tracking_to_enable,
     );
 
-    if let Err(err) = check_require_in_workload(&mut to_be_placed_systems) {
-        return Err(err);
-    }
-
     let Workload {
         name: workload_name,
         run_if: workload_run_if,
         barriers,
+        flushes,
+        max_threads,
+        staggered,
         // Systems were emptied by insert_systems_in_scheduler
         systems: _,
         // This workload will not be ordered with anything else
@@ -114,28 +173,36 @@ pub(super) fn create_workload(
         overwritten_name: _,
         require_before: _,
         require_after: _,
+        // Stages were turned into tags and after_all constraints when adding systems
+        stages: _,
     } = builder;
 
-    propagate_barriers(&mut to_be_placed_systems, barriers);
-    propagate_implicit_hard_ordering(&mut to_be_placed_systems);
-    propagate_implicit_soft_ordering(&mut to_be_placed_systems);
-    map_before_all_to_after(&mut to_be_placed_systems);
-    map_after_all_to_after(&mut to_be_placed_systems);
-    map_before_to_after(&mut to_be_placed_systems);
-
-    if let Err(err) = check_require_before(&mut to_be_placed_systems) {
-        return Err(err);
-    }
-    if let Err(err) = check_require_after(&mut to_be_placed_systems) {
+    if let Some(err) = apply_constraints(&mut to_be_placed_systems, barriers)
+        .into_iter()
+        .next()
+    {
         return Err(err);
     }
 
+    let staggered = staggered.map(|groups| {
+        let staggered = Staggered::new(groups);
+
+        for (position, system) in to_be_placed_systems.iter_mut().enumerate() {
+            system.run_if = Some(staggered.run_if(position % groups, system.run_if.take()));
+        }
+
+        staggered
+    });
+
     let mut batches = Batches {
         workload_run_if,
+        max_threads,
+        staggered,
         ..Default::default()
     };
 
     let batches_info = order_systems(&mut to_be_placed_systems, &mut batches)?;
+    place_flushes(&mut batches, flushes);
     let workload_info = WorkloadInfo {
         name: format!("{:?}", workload_name),
         batches_info,
@@ -149,9 +216,63 @@ pub(super) fn create_workload(
     Ok(workload_info)
 }
 
+/// Runs all checks `create_workload` would without inserting anything.
+pub(super) fn validate_workload(builder: &Workload) -> Result<(), Vec<error::AddWorkload>> {
+    let mut lookup_table = ShipHashMap::new();
+    let mut system_count = 0;
+
+    let mut to_be_placed_systems = builder
+        .systems
+        .iter()
+        .map(|system| {
+            let index = system_index(&mut lookup_table, system, system_count);
+            if index == system_count {
+                system_count += 1;
+            }
+
+            ToBePlacedSystem::new(index, system)
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = apply_constraints(&mut to_be_placed_systems, builder.barriers.clone());
+
+    if let Err(err) = order_systems(&mut to_be_placed_systems, &mut Batches::default()) {
+        errors.push(err);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Turns all ordering constraints into `after` constraints and returns every unmet requirement.
+fn apply_constraints(
+    to_be_placed_systems: &mut [ToBePlacedSystem],
+    barriers: Vec<usize>,
+) -> Vec<error::AddWorkload> {
+    let mut errors = Vec::new();
+
+    check_require_in_workload(to_be_placed_systems, &mut errors);
+
+    propagate_barriers(to_be_placed_systems, barriers);
+    propagate_implicit_hard_ordering(to_be_placed_systems);
+    propagate_implicit_soft_ordering(to_be_placed_systems);
+    map_before_all_to_after(to_be_placed_systems);
+    map_after_all_to_after(to_be_placed_systems);
+    map_before_to_after(to_be_placed_systems);
+
+    check_require_before(to_be_placed_systems, &mut errors);
+    check_require_after(to_be_placed_systems, &mut errors);
+
+    errors
+}
+
 fn check_require_in_workload(
     to_be_placed_systems: &mut [ToBePlacedSystem],
-) -> Result<(), error::AddWorkload> {
+    errors: &mut Vec<error::AddWorkload>,
+) {
     'outer: for i in 0..to_be_placed_systems.len() {
         let mut require_in_workload =
             core::mem::take(&mut to_be_placed_systems[i].require_in_workload);
@@ -165,19 +286,18 @@ fn check_require_in_workload(
         }
 
         if !require_in_workload.is_empty() {
-            return Err(error::AddWorkload::MissingInWorkload(
+            errors.push(error::AddWorkload::MissingInWorkload(
                 to_be_placed_systems[i].display_name.clone(),
                 require_in_workload.into_iter().collect(),
             ));
         }
     }
-
-    Ok(())
 }
 
 fn check_require_before(
     to_be_placed_systems: &mut [ToBePlacedSystem],
-) -> Result<(), error::AddWorkload> {
+    errors: &mut Vec<error::AddWorkload>,
+) {
     fn recursive_add_systems_before(
         systems_before: &mut DedupedUniqueIds,
         to_be_placed_systems: &[ToBePlacedSystem],
@@ -212,19 +332,18 @@ fn check_require_before(
         }
 
         if !require_before.is_empty() {
-            return Err(error::AddWorkload::MissingBefore(
+            errors.push(error::AddWorkload::MissingBefore(
                 to_be_placed_systems[i].display_name.clone(),
                 require_before.into_iter().collect(),
             ));
         }
     }
-
-    Ok(())
 }
 
 fn check_require_after(
     to_be_placed_systems: &mut [ToBePlacedSystem],
-) -> Result<(), error::AddWorkload> {
+    errors: &mut Vec<error::AddWorkload>,
+) {
     fn recursive_add_systems_after(
         systems_after: &mut DedupedUniqueIds,
         to_be_placed_systems: &[ToBePlacedSystem],
@@ -273,14 +392,12 @@ fn check_require_after(
         }
 
         if !require_after.is_empty() {
-            return Err(error::AddWorkload::MissingAfter(
+            errors.push(error::AddWorkload::MissingAfter(
                 to_be_placed_systems[i].display_name.clone(),
                 require_after.into_iter().collect(),
             ));
         }
     }
-
-    Ok(())
 }
 
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
@@ -295,71 +412,30 @@ fn insert_systems_in_scheduler(
     builder
         .systems
         .drain(..)
-        .map(
-            |WorkloadSystem {
-                 type_id,
-                 display_name,
-                 system_fn,
-                 borrow_constraints,
-                 mut tracking_to_enable,
-                 generator,
-                 run_if,
-                 mut tags,
-                 before_all,
-                 after_all,
-                 after,
-                 before,
-                 unique_id,
-                 require_in_workload,
-                 require_before,
-                 require_after,
-             }| {
-                let system_index = *lookup_table.entry(type_id).or_insert_with(|| {
-                    systems.push(system_fn);
-                    system_names.push(display_name.clone());
-                    system_generators.push(generator);
-
-                    systems.len() - 1
-                });
+        .map(|system| {
+            let index = system_index(lookup_table, &system, systems.len());
+            let mut to_be_placed_system = ToBePlacedSystem::new(index, &system);
+
+            let WorkloadSystem {
+                display_name,
+                system_fn,
+                mut tracking_to_enable,
+                generator,
+                run_if,
+                ..
+            } = system;
+
+            if index == systems.len() {
+                systems.push(system_fn);
+                system_names.push(display_name);
+                system_generators.push(generator);
+            }
 
-                all_tracking_to_enable.append(&mut tracking_to_enable);
-
-                let mut hard_after = DedupedLabels::new();
-                hard_after.extend(after.into_iter().map(|id| {
-                    let id: Box<dyn Label> = Box::new(UniqueSystemId(id));
-
-                    id
-                }));
-                let mut hard_before = DedupedLabels::new();
-                hard_before.extend(before.into_iter().map(|id| {
-                    let id: Box<dyn Label> = Box::new(UniqueSystemId(id));
-
-                    id
-                }));
-
-                tags.push(Box::new(UniqueSystemId(unique_id)));
-
-                ToBePlacedSystem {
-                    index: system_index,
-                    display_name,
-                    borrow_constraints,
-                    tags,
-                    before_all,
-                    after_all_info: Vec::new(),
-                    after_all,
-                    before_all_info: Vec::new(),
-                    after_info: DedupedUniqueIds::new(),
-                    hard_after: DedupedUniqueIds::new(),
-                    hard_before,
-                    soft_after: DedupedUniqueIds::new(),
-                    require_in_workload,
-                    require_before,
-                    require_after,
-                    run_if,
-                    confict: None,
-                }
-            },
-        )
+            all_tracking_to_enable.append(&mut tracking_to_enable);
+            to_be_placed_system.run_if = run_if;
+
+            to_be_placed_system
+        })
         .collect()
 }
 
@@ -407,6 +483,36 @@ fn propagate_barriers(to_be_placed_systems: &mut [ToBePlacedSystem], barriers: V
     }
 }
 
+/// Finds the batch and system after which each flush applies commands.
+///
+/// Flushes are also barriers, systems before a flush are always placed before the ones after it.
+fn place_flushes(batches: &mut Batches, flushes: Vec<usize>) {
+    for flush in flushes {
+        // Flushing before any system or after the last one is already done by the workload
+        if flush == 0 || flush >= batches.sequential.len() {
+            continue;
+        }
+
+        batches.sequential_flushes.push(flush - 1);
+
+        let mut placed = 0;
+        for (batch_index, batch) in batches.parallel.iter().enumerate() {
+            placed += batch.0.is_some() as usize + batch.1.len();
+
+            if placed >= flush {
+                batches.parallel_flushes.push(batch_index);
+
+                break;
+            }
+        }
+    }
+
+    batches.sequential_flushes.sort_unstable();
+    batches.sequential_flushes.dedup();
+    batches.parallel_flushes.sort_unstable();
+    batches.parallel_flushes.dedup();
+}
+
 /// Translate the implicit ordering's rules to tags and after constraints.
 fn propagate_implicit_hard_ordering(to_be_placed_systems: &mut [ToBePlacedSystem]) {
     for i in (1..to_be_placed_systems.len()).rev() {
@@ -565,8 +671,15 @@ fn order_systems(
 
     for system in &mut *to_be_placed_systems {
         system.after_info = system.hard_after.clone();
+        system.after = system.hard_after.clone();
+        for &id in &system.soft_after.0 {
+            system.after.add(id);
+        }
     }
 
+    // Tags, borrows and length of the longest chain ending with each placed system
+    let mut chains: Vec<(Vec<Box<dyn Label>>, Vec<TypeInfo>, usize)> = Vec::new();
+
     let mut constraint_free_systems = alloc::collections::VecDeque::new();
 
     // Create a list with systems without constraints.
@@ -584,11 +697,33 @@ fn order_systems(
     let mut latest_batch = &mut batches.parallel[0];
     let mut latest_batch_run_if = &mut batches.parallel_run_if[0];
     let mut latest_batch_info = &mut batches_info[0];
+    let mut priorities = vec![Vec::new()];
     let mut to_delete_tags = Vec::new();
     loop {
         while let Some(mut system) = constraint_free_systems.pop_front() {
             batches.sequential.push(system.index);
 
+            // A system waits for the systems it has to run after and the ones it conflicts with
+            let chain = 1 + chains
+                .iter()
+                .filter(|(tags, borrow, _)| {
+                    system
+                        .after
+                        .0
+                        .iter()
+                        .any(|&id| tags.iter().any(|tag| tag.dyn_eq(&UniqueSystemId(id))))
+                        || check_conflict(&system, 0, borrow).is_some()
+                })
+                .map(|&(_, _, chain)| chain)
+                .max()
+                .unwrap_or(0);
+            batches.critical_path = batches.critical_path.max(chain);
+            chains.push((
+                system.tags.clone(),
+                system.borrow_constraints.clone(),
+                chain,
+            ));
+
             let conflict = check_can_go_in_parallel_batch(latest_batch_info, &system);
             if conflict.is_some() {
                 batches.parallel.push((None, Vec::new()));
@@ -596,6 +731,7 @@ fn order_systems(
                 batches_info.push(BatchInfo {
                     systems: (None, Vec::new()),
                 });
+                priorities.push(Vec::new());
                 system.confict = conflict;
 
                 latest_batch = batches.parallel.last_mut().unwrap();
@@ -620,6 +756,7 @@ fn order_systems(
                 });
             } else {
                 latest_batch.1.push(system.index);
+                priorities.last_mut().unwrap().push(system.priority);
                 latest_batch_info.systems.1.push(SystemInfo {
                     name: format!("{:?}", system.display_name),
                     borrow: system.borrow_constraints,
@@ -675,6 +812,7 @@ fn order_systems(
             batches_info.push(BatchInfo {
                 systems: (None, Vec::new()),
             });
+            priorities.push(Vec::new());
             to_delete_tags.clear();
 
             latest_batch = batches.parallel.last_mut().unwrap();
@@ -694,9 +832,47 @@ fn order_systems(
         ));
     }
 
+    sort_batches_by_priority(batches, &mut batches_info, priorities);
+
     Ok(batches_info)
 }
 
+/// Reorders the parallel part of each batch, higher priority first.
+///
+/// Systems with the same priority keep their relative order.
+fn sort_batches_by_priority(
+    batches: &mut Batches,
+    batches_info: &mut [BatchInfo],
+    priorities: Vec<Vec<i32>>,
+) {
+    for (((batch, batch_run_if), batch_info), priorities) in batches
+        .parallel
+        .iter_mut()
+        .zip(&mut batches.parallel_run_if)
+        .zip(batches_info)
+        .zip(priorities)
+    {
+        if priorities.windows(2).all(|pair| pair[0] >= pair[1]) {
+            continue;
+        }
+
+        let mut order = (0..priorities.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| core::cmp::Reverse(priorities[index]));
+
+        batch.1 = order.iter().map(|&index| batch.1[index]).collect();
+        batch_run_if.1 = order.iter().map(|&index| batch_run_if.1[index]).collect();
+
+        let mut systems_info = core::mem::take(&mut batch_info.systems.1)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        batch_info.systems.1 = order
+            .iter()
+            .map(|&index| systems_info[index].take().unwrap())
+            .collect();
+    }
+}
+
 fn check_can_go_in_parallel_batch(
     batch_info: &BatchInfo,
     tested_system: &ToBePlacedSystem,
@@ -0,0 +1,234 @@
+use crate::error;
+use crate::scheduler::batches::AdvanceOnExit;
+use crate::scheduler::{Batches, Label, Scheduler};
+use crate::world::World;
+use alloc::boxed::Box;
+use core::sync::atomic::Ordering;
+
+/// Runs a workload one system at a time.
+///
+/// Created with [`World::workload_runner`].\
+/// Systems run in the same order as when the workload runs without the `parallel` feature.\
+/// The `World` is not borrowed between steps, it can be inspected and modified freely.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Component, IntoIter, View, ViewMut, Workload, World};
+///
+/// #[derive(Component)]
+/// struct U32(u32);
+///
+/// fn increment(mut u32s: ViewMut<U32>) {
+///     for mut i in (&mut u32s).iter() {
+///         i.0 += 1;
+///     }
+/// }
+///
+/// fn double(mut u32s: ViewMut<U32>) {
+///     for mut i in (&mut u32s).iter() {
+///         i.0 *= 2;
+///     }
+/// }
+///
+/// let world = World::new();
+///
+/// let entity = world.add_entity(U32(0));
+///
+/// Workload::new("Update")
+///     .with_system(increment)
+///     .with_system(double)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// let mut runner = world.workload_runner("Update").unwrap();
+///
+/// assert!(runner.step(&world).unwrap().is_some());
+/// assert_eq!(world.borrow::<View<U32>>().unwrap()[entity].0, 1);
+///
+/// assert!(runner.step(&world).unwrap().is_some());
+/// assert_eq!(world.borrow::<View<U32>>().unwrap()[entity].0, 2);
+///
+/// assert!(runner.step(&world).unwrap().is_none());
+/// ```
+///
+/// [`World::workload_runner`]: crate::World::workload_runner
+pub struct WorkloadRunner {
+    workload: Box<dyn Label>,
+    position: usize,
+    started: bool,
+    finished: bool,
+    /// Held from the start of the run until it finishes, fails or the runner is dropped
+    advance: Option<AdvanceOnExit>,
+}
+
+impl WorkloadRunner {
+    pub(crate) fn new(workload: Box<dyn Label>) -> WorkloadRunner {
+        WorkloadRunner {
+            workload,
+            position: 0,
+            started: false,
+            finished: false,
+            advance: None,
+        }
+    }
+
+    /// Runs the next system and returns its name.\
+    /// Systems whose run if condition evaluates to `false` are skipped.\
+    /// Returns `None` once all systems ran.\
+    /// A run is over once a system fails, the following steps return `None`.
+    ///
+    /// Commands are applied at each [`Workload::flush`](crate::Workload::flush) and once all systems ran.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - System's borrow
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    pub fn step(&mut self, world: &World) -> Result<Option<Box<dyn Label>>, error::RunWorkload> {
+        Ok(self.step_then(world, || ())?.map(|(system, ())| system))
+    }
+    /// Runs the next system and returns its name along with the result of `f`, called right after the system ran.
+    pub(crate) fn step_then<R>(
+        &mut self,
+        world: &World,
+        f: impl FnOnce() -> R,
+    ) -> Result<Option<(Box<dyn Label>, R)>, error::RunWorkload> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let scheduler = world
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let batches = scheduler.workload(&*self.workload)?;
+
+        let _running = batches
+            .start_running()
+            .ok_or_else(|| error::RunWorkload::Reentrant(self.workload.clone()))?;
+
+        if !self.started {
+            self.advance = Some(world.start_run(batches));
+        }
+
+        let result = self.step_started(world, &scheduler, batches);
+
+        if let Err(err) = &result {
+            // A failed run is over, like when all systems run at once
+            self.finish();
+
+            if let error::RunWorkload::Run(run_system) = err {
+                batches.set_last_error(Some((
+                    run_system.system.clone(),
+                    run_system.error.duplicate(),
+                )));
+            }
+        }
+
+        Ok(result?.map(|index| {
+            let output = f();
+
+            (scheduler.system_names[index].clone(), output)
+        }))
+    }
+    /// Runs the next system once the workload has been flagged as running and returns its index.
+    fn step_started(
+        &mut self,
+        world: &World,
+        scheduler: &Scheduler,
+        batches: &Batches,
+    ) -> Result<Option<usize>, error::RunWorkload> {
+        if !self.started {
+            self.started = true;
+
+            if let Some(run_if) = &batches.workload_run_if {
+                if !run_if.run(world).map_err(|err| {
+                    error::RunWorkload::Run(error::RunSystem::new(
+                        &*self.workload,
+                        self.workload.clone(),
+                        err,
+                    ))
+                })? {
+                    self.finish();
+
+                    return Ok(None);
+                }
+            }
+        }
+
+        while let Some((&index, &run_if_index)) = batches
+            .sequential
+            .get(self.position)
+            .zip(batches.sequential_run_if.get(self.position))
+        {
+            let flush = batches.sequential_flushes.contains(&self.position);
+            self.position += 1;
+
+            let should_run = if run_if_index == usize::MAX {
+                // There is no run_if for this system
+
+                true
+            } else {
+                (batches.systems_run_if[run_if_index])(world).map_err(|err| {
+                    error::RunWorkload::Run(error::RunSystem::new(
+                        &*self.workload,
+                        scheduler.system_names[index].clone(),
+                        err,
+                    ))
+                })?
+            };
+
+            if !should_run {
+                if flush {
+                    world.try_apply_commands()?;
+                }
+
+                continue;
+            }
+
+            (scheduler.systems[index])(world).map_err(|err| {
+                error::RunWorkload::Run(error::RunSystem::new(
+                    &*self.workload,
+                    scheduler.system_names[index].clone(),
+                    err,
+                ))
+            })?;
+            batches.systems_run.fetch_add(1, Ordering::Relaxed);
+
+            if flush {
+                world.try_apply_commands()?;
+            } else {
+                world
+                    .try_run_observers()
+                    .map_err(error::RunWorkload::Observe)?;
+            }
+
+            return Ok(Some(index));
+        }
+
+        self.finish();
+
+        world.try_apply_commands()?;
+
+        Ok(None)
+    }
+    /// Ends the run, a staggered workload moves to its next group.
+    fn finish(&mut self) {
+        self.finished = true;
+        self.advance = None;
+    }
+    /// Returns `true` once all systems ran.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
@@ -0,0 +1,47 @@
+use crate::scheduler::{AsLabel, IntoWorkloadSystem, Label, Workload};
+use alloc::boxed::Box;
+
+/// A stage of a [`Workload`], created with [`Workload::stage`].
+///
+/// Systems added to a stage run after all systems of the previous stages and before all systems of the next ones.\
+/// Within a stage, systems are still run in parallel when their borrows allow it.
+pub struct WorkloadStage {
+    workload: Workload,
+    label: Box<dyn Label>,
+    /// Position of the stage in the workload's stages
+    index: usize,
+}
+
+impl WorkloadStage {
+    pub(super) fn new(workload: Workload, label: Box<dyn Label>, index: usize) -> WorkloadStage {
+        WorkloadStage {
+            workload,
+            label,
+            index,
+        }
+    }
+    /// Adds a system to this stage.
+    ///
+    /// The system is tagged with the stage's name and placed after all systems of the previous stages.
+    #[track_caller]
+    pub fn add_system<B, R, S: IntoWorkloadSystem<B, R>>(mut self, system: S) -> WorkloadStage {
+        let mut system = system.into_workload_system().unwrap();
+
+        system.tags.push(self.label.clone());
+        system
+            .after_all
+            .extend(self.workload.stages[..self.index].iter().cloned());
+
+        self.workload = self.workload.with_system(system);
+
+        self
+    }
+    /// Starts or goes back to the `name` stage, see [`Workload::stage`].
+    pub fn stage<T>(self, name: impl AsLabel<T>) -> WorkloadStage {
+        self.workload.stage(name)
+    }
+    /// Returns the workload, systems added to it directly are not part of any stage.
+    pub fn end_stage(self) -> Workload {
+        self.workload
+    }
+}
@@ -1,11 +1,14 @@
-use crate::all_storages::AllStorages;
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::ARefMut;
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::error;
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
 use crate::tracking::TrackingTimestamp;
 #[cfg(doc)]
 use crate::world::World;
+use core::any::type_name;
 
 /// Trait used as bound for [`World::add_entity`], [`World::add_component`], [`AllStorages::add_entity`] and [`AllStorages::add_component`].
 pub trait TupleAddComponent {
@@ -16,6 +19,42 @@ pub trait TupleAddComponent {
         entity: EntityId,
         current: TrackingTimestamp,
     );
+    /// Checks the storages have room for `entity`'s components when the `World` has a fixed capacity.\
+    /// `entity` is `None` for new entities.
+    #[inline]
+    #[allow(unused_variables)]
+    fn check_capacity(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: Option<EntityId>,
+    ) -> Result<(), error::AddComponent> {
+        Ok(())
+    }
+}
+
+/// Trait used as bound for [`AllStorages::try_add_component`].
+pub trait TupleTryAddComponent {
+    /// Storages borrowed before any component is added.
+    type Storages<'a>;
+
+    /// Borrows the storages of all components, creating the missing ones.
+    fn borrow_storages<'a>(
+        &self,
+        all_storages: &'a AllStorages,
+    ) -> Result<Self::Storages<'a>, error::GetStorage>;
+    /// Checks the borrowed storages have room for `entity`'s components when the `World` has a fixed capacity.
+    fn check_storages_capacity(
+        &self,
+        storages: &Self::Storages<'_>,
+        entity: EntityId,
+    ) -> Result<(), error::AddComponent>;
+    /// Adds the components to the borrowed storages.
+    fn add_to_storages(
+        self,
+        storages: &mut Self::Storages<'_>,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    );
 }
 
 impl TupleAddComponent for () {
@@ -23,6 +62,21 @@ impl TupleAddComponent for () {
     fn add_component(self, _: &mut AllStorages, _: EntityId, _: TrackingTimestamp) {}
 }
 
+impl TupleTryAddComponent for () {
+    type Storages<'a> = ();
+
+    #[inline]
+    fn borrow_storages(&self, _: &AllStorages) -> Result<(), error::GetStorage> {
+        Ok(())
+    }
+    #[inline]
+    fn check_storages_capacity(&self, _: &(), _: EntityId) -> Result<(), error::AddComponent> {
+        Ok(())
+    }
+    #[inline]
+    fn add_to_storages(self, _: &mut (), _: EntityId, _: TrackingTimestamp) {}
+}
+
 impl<T: Send + Sync + Component> TupleAddComponent for T {
     #[inline]
     #[track_caller]
@@ -37,6 +91,48 @@ impl<T: Send + Sync + Component> TupleAddComponent for T {
             .insert(entity, self, current)
             .assert_inserted();
     }
+    #[inline]
+    fn check_capacity(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: Option<EntityId>,
+    ) -> Result<(), error::AddComponent> {
+        check_capacity(
+            all_storages
+                .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new),
+            entity,
+        )
+    }
+}
+
+impl<T: Send + Sync + Component> TupleTryAddComponent for T {
+    type Storages<'a> = ARefMut<'a, &'a mut SparseSet<T>>;
+
+    #[inline]
+    fn borrow_storages<'a>(
+        &self,
+        all_storages: &'a AllStorages,
+    ) -> Result<Self::Storages<'a>, error::GetStorage> {
+        all_storages.custom_storage_or_insert_mut(SparseSet::<T>::new)
+    }
+    #[inline]
+    fn check_storages_capacity(
+        &self,
+        storages: &Self::Storages<'_>,
+        entity: EntityId,
+    ) -> Result<(), error::AddComponent> {
+        check_capacity(storages, Some(entity))
+    }
+    #[inline]
+    #[track_caller]
+    fn add_to_storages(
+        self,
+        storages: &mut Self::Storages<'_>,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) {
+        storages.insert(entity, self, current).assert_inserted();
+    }
 }
 
 impl<T: Send + Sync + Component> TupleAddComponent for Option<T> {
@@ -55,6 +151,80 @@ impl<T: Send + Sync + Component> TupleAddComponent for Option<T> {
                 .assert_inserted();
         }
     }
+    #[inline]
+    fn check_capacity(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: Option<EntityId>,
+    ) -> Result<(), error::AddComponent> {
+        if self.is_some() {
+            check_capacity(
+                all_storages.exclusive_storage_or_insert_mut(
+                    StorageId::of::<SparseSet<T>>(),
+                    SparseSet::new,
+                ),
+                entity,
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: Send + Sync + Component> TupleTryAddComponent for Option<T> {
+    type Storages<'a> = Option<ARefMut<'a, &'a mut SparseSet<T>>>;
+
+    #[inline]
+    fn borrow_storages<'a>(
+        &self,
+        all_storages: &'a AllStorages,
+    ) -> Result<Self::Storages<'a>, error::GetStorage> {
+        if self.is_some() {
+            all_storages
+                .custom_storage_or_insert_mut(SparseSet::<T>::new)
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+    #[inline]
+    fn check_storages_capacity(
+        &self,
+        storages: &Self::Storages<'_>,
+        entity: EntityId,
+    ) -> Result<(), error::AddComponent> {
+        match storages {
+            Some(sparse_set) => check_capacity(sparse_set, Some(entity)),
+            None => Ok(()),
+        }
+    }
+    #[inline]
+    #[track_caller]
+    fn add_to_storages(
+        self,
+        storages: &mut Self::Storages<'_>,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) {
+        if let (Some(component), Some(sparse_set)) = (self, storages) {
+            sparse_set
+                .insert(entity, component, current)
+                .assert_inserted();
+        }
+    }
+}
+
+fn check_capacity<T: Component>(
+    sparse_set: &SparseSet<T>,
+    entity: Option<EntityId>,
+) -> Result<(), error::AddComponent> {
+    if sparse_set.remaining_capacity() > 0
+        || entity.is_some_and(|entity| sparse_set.contains(entity))
+    {
+        Ok(())
+    } else {
+        Err(error::AddComponent::StorageFull(type_name::<T>()))
+    }
 }
 
 macro_rules! impl_add_component {
@@ -66,6 +236,34 @@ macro_rules! impl_add_component {
                     self.$index.add_component(all_storages, entity, current);
                 )+
             }
+            fn check_capacity(&self, all_storages: &mut AllStorages, entity: Option<EntityId>) -> Result<(), error::AddComponent> {
+                $(
+                    self.$index.check_capacity(all_storages, entity)?;
+                )+
+
+                Ok(())
+            }
+        }
+
+        impl<$($type: TupleTryAddComponent,)+> TupleTryAddComponent for ($($type,)+) {
+            type Storages<'a> = ($($type::Storages<'a>,)+);
+
+            fn borrow_storages<'a>(&self, all_storages: &'a AllStorages) -> Result<Self::Storages<'a>, error::GetStorage> {
+                Ok(($(self.$index.borrow_storages(all_storages)?,)+))
+            }
+            fn check_storages_capacity(&self, storages: &Self::Storages<'_>, entity: EntityId) -> Result<(), error::AddComponent> {
+                $(
+                    self.$index.check_storages_capacity(&storages.$index, entity)?;
+                )+
+
+                Ok(())
+            }
+            #[track_caller]
+            fn add_to_storages(self, storages: &mut Self::Storages<'_>, entity: EntityId, current: TrackingTimestamp) {
+                $(
+                    self.$index.add_to_storages(&mut storages.$index, entity, current);
+                )+
+            }
         }
     };
 }
@@ -81,7 +279,10 @@ macro_rules! add_component {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-add_component![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+add_component![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
 #[cfg(feature = "extended_tuple")]
 add_component![
     (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
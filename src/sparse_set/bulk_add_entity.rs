@@ -2,6 +2,7 @@ use crate::all_storages::{AllStorages, CustomStorageAccess};
 use crate::component::Component;
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
+use crate::error;
 use crate::reserve::BulkEntityIter;
 use crate::sparse_set::SparseSet;
 use crate::tracking::TrackingTimestamp;
@@ -24,6 +25,11 @@ where
     }
 }
 
+/// Returns `true` if `iter` still yields items once `added` items filled the `room` left by the fixed capacity of the `World`.
+fn exceeds_capacity(iter: &mut impl Iterator, added: usize, room: usize) -> bool {
+    added == room && iter.next().is_some()
+}
+
 pub trait BulkInsert {
     fn bulk_insert<I: IntoIterator<Item = Self>>(
         all_storages: &mut AllStorages,
@@ -63,7 +69,7 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         all_storages: &mut AllStorages,
         iter: I,
     ) -> BulkEntityIter<'_> {
-        let iter = iter.into_iter();
+        let mut iter = iter.into_iter();
         let current = all_storages.get_current();
         let mut entities = all_storages.entities_mut().unwrap();
         let mut sparse_set = all_storages
@@ -71,7 +77,21 @@ impl<T: Send + Sync + Component> BulkInsert for T {
             .unwrap();
 
         // add components to the storage
-        sparse_set.data.extend(iter);
+        let room = entities
+            .remaining_capacity()
+            .min(sparse_set.remaining_capacity());
+        sparse_set.data.extend(iter.by_ref().take(room));
+
+        if exceeds_capacity(
+            &mut iter,
+            sparse_set.data.len() - sparse_set.dense.len(),
+            room,
+        ) {
+            let len = sparse_set.dense.len();
+            sparse_set.data.truncate(len);
+
+            panic!("{:?}", error::AddEntity::EntitiesFull);
+        }
 
         // generate new EntityId for the entities created
         let entities_len = entities.data.len();
@@ -81,6 +101,7 @@ impl<T: Send + Sync + Component> BulkInsert for T {
 
         // add new EntityId to the storage for the components we added above
         sparse_set.dense.extend_from_slice(new_entities);
+        sparse_set.bulk_on_insertion(old_len);
 
         // add tracking info if needed
         if sparse_set.is_tracking_insertion() {
@@ -129,7 +150,7 @@ macro_rules! impl_bulk_insert {
         impl<$type1: Send + Sync + Component, $($type: Send + Sync + Component,)*> BulkInsert for ($type1, $($type,)*) {
             #[allow(non_snake_case)]
             fn bulk_insert<Source: IntoIterator<Item = Self>>(all_storages: &mut AllStorages, iter: Source) -> BulkEntityIter<'_> {
-                let iter = iter.into_iter();
+                let mut iter = iter.into_iter();
                 let size_hint = iter.size_hint().0;
                 let mut entities = all_storages.entities_mut().unwrap();
                 let mut $sparse_set1 = all_storages.custom_storage_or_insert_mut(SparseSet::<$type1>::new).unwrap();
@@ -142,20 +163,39 @@ macro_rules! impl_bulk_insert {
                     $sparse_set.reserve(size_hint);
                 )*
 
-                for ($type1, $($type,)*) in iter {
+                let room = entities.remaining_capacity().min($sparse_set1.remaining_capacity())$(.min($sparse_set.remaining_capacity()))*;
+
+                for ($type1, $($type,)*) in iter.by_ref().take(room) {
                     $sparse_set1.data.push($type1);
                     $(
                         $sparse_set.data.push($type);
                     )*
                 }
 
+                if exceeds_capacity(&mut iter, $sparse_set1.data.len() - $sparse_set1.dense.len(), room) {
+                    let len = $sparse_set1.dense.len();
+                    $sparse_set1.data.truncate(len);
+                    $(
+                        let len = $sparse_set.dense.len();
+                        $sparse_set.data.truncate(len);
+                    )*
+
+                    panic!("{:?}", error::AddEntity::EntitiesFull);
+                }
+
                 let entities_len = entities.data.len();
                 let new_entities_count = $sparse_set1.data.len() - $sparse_set1.dense.len();
                 let new_entities = entities.bulk_generate(new_entities_count);
 
+                let old_len = $sparse_set1.dense.len();
+
                 $sparse_set1.dense.extend_from_slice(new_entities);
+                $sparse_set1.bulk_on_insertion(old_len);
                 $(
+                    let old_len = $sparse_set.dense.len();
+
                     $sparse_set.dense.extend_from_slice(new_entities);
+                    $sparse_set.bulk_on_insertion(old_len);
                 )*
 
                 if $sparse_set1.is_tracking_insertion() {
@@ -218,7 +258,10 @@ macro_rules! bulk_insert {
 }
 
 #[cfg(not(feature = "extended_tuple"))]
-bulk_insert![(A, sparse_set0, 0) (B, sparse_set1, 1); (C, sparse_set2, 2) (D, sparse_set3, 3) (E, sparse_set4, 4) (F, sparse_set5, 5) (G, sparse_set6, 6) (H, sparse_set7, 7) (I, sparse_set8, 8) (J, sparse_set9, 9)];
+bulk_insert![
+    (A, sparse_set0, 0) (B, sparse_set1, 1); (C, sparse_set2, 2) (D, sparse_set3, 3) (E, sparse_set4, 4) (F, sparse_set5, 5) (G, sparse_set6, 6) (H, sparse_set7, 7) (I, sparse_set8, 8) (J, sparse_set9, 9)
+    (K, sparse_set10, 10) (L, sparse_set11, 11) (M, sparse_set12, 12) (N, sparse_set13, 13) (O, sparse_set14, 14) (P, sparse_set15, 15)
+];
 #[cfg(feature = "extended_tuple")]
 bulk_insert![
     (A, sparse_set0, 0) (B, sparse_set1, 1); (C, sparse_set2, 2) (D, sparse_set3, 3) (E, sparse_set4, 4) (F, sparse_set5, 5) (G, sparse_set6, 6) (H, sparse_set7, 7) (I, sparse_set8, 8) (J, sparse_set9, 9)
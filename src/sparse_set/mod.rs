@@ -3,22 +3,25 @@ mod bulk_add_entity;
 mod delete;
 mod drain;
 mod memory_usage;
+mod pack;
 mod remove;
 mod sparse_array;
 #[cfg(feature = "thread_local")]
 mod thread_local;
 mod window;
 
-pub use add_component::TupleAddComponent;
+pub use add_component::{TupleAddComponent, TupleTryAddComponent};
 pub use bulk_add_entity::BulkAddEntity;
 pub use delete::TupleDelete;
 pub use drain::SparseSetDrain;
 pub use memory_usage::{SparseSetMemory, SparseSetMemoryUsage};
+pub use pack::TuplePack;
 pub use remove::TupleRemove;
 pub use sparse_array::SparseArray;
 #[doc(hidden)]
 pub use window::RawEntityIdAccess;
 
+pub(crate) use pack::TightPack;
 pub(crate) use window::{FullRawWindow, FullRawWindowMut};
 
 use crate::all_storages::AllStorages;
@@ -28,10 +31,15 @@ use crate::error;
 use crate::memory_usage::StorageMemoryUsage;
 use crate::r#mut::Mut;
 use crate::storage::{SBoxBuilder, Storage, StorageId};
-use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::tracking::{StorageTrackingSnapshot, Tracking, TrackingTimestamp};
+use crate::ShipHashMap;
 use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
-use core::any::type_name;
+use core::any::{type_name, Any};
 use core::mem::size_of;
 use core::{
     cmp::{Ord, Ordering},
@@ -57,17 +65,30 @@ pub struct SparseSet<T: Component> {
     pub(crate) last_modified: TrackingTimestamp,
     pub(crate) insertion_data: Vec<TrackingTimestamp>,
     pub(crate) modification_data: Vec<TrackingTimestamp>,
-    pub(crate) deletion_data: Vec<(EntityId, TrackingTimestamp, T)>,
-    pub(crate) removal_data: Vec<(EntityId, TrackingTimestamp)>,
+    pub(crate) deletion_data: VecDeque<(EntityId, TrackingTimestamp, T)>,
+    pub(crate) removal_data: VecDeque<(EntityId, TrackingTimestamp)>,
     pub(crate) is_tracking_insertion: bool,
     pub(crate) is_tracking_modification: bool,
     pub(crate) is_tracking_deletion: bool,
     pub(crate) is_tracking_removal: bool,
+    /// Maximum length of `deletion_data` and `removal_data`
+    pub(crate) tracking_capacity: Option<usize>,
+    pub(crate) is_deletion_truncated: bool,
+    pub(crate) is_removal_truncated: bool,
+    pub(crate) pack: Option<TightPack>,
+    fixed_capacity: Option<usize>,
     #[allow(clippy::type_complexity)]
     on_insertion: Option<Box<dyn FnMut(EntityId, &T) + Send + Sync>>,
+    /// Set by [`World::observe_insert`](crate::World::observe_insert), called after `on_insertion` once the component is inserted
+    insertion_observer: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
     #[allow(clippy::type_complexity)]
     on_removal: Option<Box<dyn FnMut(EntityId, &T) + Send + Sync>>,
     clone: Option<fn(&T) -> T>,
+    take: Option<fn(T) -> Box<dyn Any>>,
+    debug: Option<fn(&T) -> String>,
+    /// Conversions from older serialization formats, tried in order
+    #[cfg(feature = "serde1")]
+    pub(crate) migrations: Vec<crate::views::serde::de::content::Migration<T>>,
 }
 
 impl<T: fmt::Debug + Component> fmt::Debug for SparseSet<T> {
@@ -89,15 +110,25 @@ impl<T: Component> SparseSet<T> {
             last_modified: TrackingTimestamp::new(0),
             insertion_data: Vec::new(),
             modification_data: Vec::new(),
-            deletion_data: Vec::new(),
-            removal_data: Vec::new(),
+            deletion_data: VecDeque::new(),
+            removal_data: VecDeque::new(),
             is_tracking_insertion: T::Tracking::track_insertion(),
             is_tracking_modification: T::Tracking::track_modification(),
             is_tracking_deletion: T::Tracking::track_deletion(),
             is_tracking_removal: T::Tracking::track_removal(),
+            tracking_capacity: None,
+            is_deletion_truncated: false,
+            is_removal_truncated: false,
+            pack: None,
+            fixed_capacity: None,
             on_insertion: None,
+            insertion_observer: None,
             on_removal: None,
             clone: None,
+            take: None,
+            debug: None,
+            #[cfg(feature = "serde1")]
+            migrations: Vec::new(),
         }
     }
     /// Returns a new [`SparseSet`] to be used in custom storage.
@@ -159,8 +190,41 @@ impl<T: Component> SparseSet<T> {
     pub fn id_at(&self, index: usize) -> Option<EntityId> {
         self.dense.get(index).copied()
     }
+    /// Binary searches the components with a comparator function.\
+    /// Returns the index of the matching component in the `dense` and `data` vectors, see [`slice::binary_search_by`] for more details.
+    ///
+    /// The result is only meaningful if the storage is sorted by a key compatible with `f`, for example with [`sort_unstable_by`](SparseSet::sort_unstable_by).\
+    /// Adding or removing components can break the order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(5));
+    /// let entity = world.add_entity(U32(1));
+    /// world.add_entity(U32(3));
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    /// u32s.sort_unstable();
+    ///
+    /// let index = u32s.binary_search_by(|u| u.0.cmp(&1)).unwrap();
+    /// assert_eq!(u32s.id_at(index), Some(entity));
+    /// assert_eq!(u32s.binary_search_by(|u| u.0.cmp(&4)), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.data.binary_search_by(f)
+    }
 
     /// Sets the on insertion callback.
+    ///
+    /// Observers registered with [`World::observe_insert`](crate::World::observe_insert) are not affected and keep being called after it.
     pub fn on_insertion(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) {
         self.on_insertion = Some(Box::new(f));
     }
@@ -173,6 +237,14 @@ impl<T: Component> SparseSet<T> {
         self.on_insertion.take()
     }
 
+    /// Sets the callback notifying the `World`'s insertion observers.
+    pub(crate) fn set_insertion_observer(
+        &mut self,
+        f: impl FnMut(EntityId) + Send + Sync + 'static,
+    ) {
+        self.insertion_observer = Some(Box::new(f));
+    }
+
     /// Sets the on removal and deletion callback.
     pub fn on_removal(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) {
         self.on_removal = Some(Box::new(f));
@@ -245,6 +317,13 @@ impl<T: Component> SparseSet<T> {
         let old_component;
 
         if sparse_entity.is_dead() {
+            if self
+                .fixed_capacity
+                .is_some_and(|capacity| self.dense.len() >= capacity)
+            {
+                panic!("{:?}", error::AddComponent::StorageFull(type_name::<T>()));
+            }
+
             if let Some(on_insertion) = &mut self.on_insertion {
                 on_insertion(entity, &value);
             }
@@ -298,6 +377,7 @@ impl<T: Component> SparseSet<T> {
 
             old_component = InsertionResult::OtherComponentOverride;
 
+            let index = sparse_entity.uindex();
             sparse_entity.copy_gen(entity);
 
             let dense_entity = unsafe { self.dense.get_unchecked_mut(sparse_entity.uindex()) };
@@ -311,12 +391,34 @@ impl<T: Component> SparseSet<T> {
             }
 
             dense_entity.copy_index_gen(entity);
+
+            // Another entity now lives at this index
+            self.unpack_at(index);
         } else {
-            old_component = InsertionResult::NotInserted;
+            return InsertionResult::NotInserted;
+        }
+
+        if let Some(insertion_observer) = &mut self.insertion_observer {
+            insertion_observer(entity);
         }
 
         old_component
     }
+    /// Calls the on insertion callback for the components pushed at the end of the storage, starting at `start`.
+    #[inline]
+    pub(crate) fn bulk_on_insertion(&mut self, start: usize) {
+        if let Some(on_insertion) = &mut self.on_insertion {
+            for (&entity, component) in self.dense[start..].iter().zip(&self.data[start..]) {
+                on_insertion(entity, component);
+            }
+        }
+
+        if let Some(insertion_observer) = &mut self.insertion_observer {
+            for &entity in &self.dense[start..] {
+                insertion_observer(entity);
+            }
+        }
+    }
 }
 
 impl<T: Component> SparseSet<T> {
@@ -325,7 +427,8 @@ impl<T: Component> SparseSet<T> {
     pub(crate) fn dyn_delete(&mut self, entity: EntityId, current: TrackingTimestamp) -> bool {
         if let Some(component) = self.actual_remove(entity) {
             if self.is_tracking_deletion() {
-                self.deletion_data.push((entity, current, component));
+                self.deletion_data.push_back((entity, current, component));
+                self.enforce_tracking_capacity();
             }
 
             true
@@ -340,12 +443,81 @@ impl<T: Component> SparseSet<T> {
         let component = self.actual_remove(entity);
 
         if component.is_some() && self.is_tracking_removal() {
-            self.removal_data.push((entity, current));
+            self.removal_data.push_back((entity, current));
+            self.enforce_tracking_capacity();
         }
 
         component
     }
 
+    /// Deletes `entity`'s component and returns it type erased if [`SparseSet::register_take`] was called.
+    ///
+    /// When deletion is tracked the component is kept by the tracking and not returned.
+    pub(crate) fn private_delete_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        match self.take {
+            Some(take) if !self.is_tracking_deletion() => self.actual_remove(entity).map(take),
+            _ => {
+                self.dyn_delete(entity, current);
+
+                None
+            }
+        }
+    }
+
+    /// Formats the first `max` components if [`SparseSet::register_debug`] was called.
+    pub(crate) fn private_debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        let debug = self.debug?;
+
+        Some(
+            self.dense
+                .iter()
+                .zip(&self.data)
+                .take(max)
+                .map(|(&entity, component)| (entity, debug(component)))
+                .collect(),
+        )
+    }
+
+    /// Swaps the components of `a` and `b`, moving it when only one of them has a component.
+    ///
+    /// Swapped components keep their insertion timestamp and are flagged *modified*,
+    /// moved components are flagged *removed* then *inserted*.
+    pub(crate) fn private_swap_entities(
+        &mut self,
+        a: EntityId,
+        b: EntityId,
+        current: TrackingTimestamp,
+    ) {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(a_index), Some(b_index)) => {
+                self.data.swap(a_index, b_index);
+
+                if self.is_tracking_insertion() {
+                    self.insertion_data.swap(a_index, b_index);
+                }
+                if self.is_tracking_modification() {
+                    self.modification_data[a_index] = current;
+                    self.modification_data[b_index] = current;
+                }
+            }
+            (Some(_), None) => {
+                if let Some(component) = self.dyn_remove(a, current) {
+                    self.insert(b, component, current);
+                }
+            }
+            (None, Some(_)) => {
+                if let Some(component) = self.dyn_remove(b, current) {
+                    self.insert(a, component, current);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
     #[inline]
     pub(crate) fn actual_remove(&mut self, entity: EntityId) -> Option<T> {
         let sparse_entity = self.sparse.get(entity)?;
@@ -355,6 +527,8 @@ impl<T: Component> SparseSet<T> {
                 *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
             }
 
+            self.unpack_at(sparse_entity.uindex());
+
             self.dense.swap_remove(sparse_entity.uindex());
             if self.is_tracking_insertion() {
                 self.insertion_data.swap_remove(sparse_entity.uindex());
@@ -406,6 +580,7 @@ impl<T: Component> SparseSet<T> {
     /// Clear all deletion tracking data.
     pub fn clear_all_deleted(&mut self) {
         self.deletion_data.clear();
+        self.is_deletion_truncated = false;
     }
     /// Clear all deletion tracking data older than some timestamp.
     pub fn clear_all_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
@@ -415,6 +590,7 @@ impl<T: Component> SparseSet<T> {
     /// Clear all removal tracking data.
     pub fn clear_all_removed(&mut self) {
         self.removal_data.clear();
+        self.is_removal_truncated = false;
     }
     /// Clear all removal tracking data older than some timestamp.
     pub fn clear_all_removed_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
@@ -424,6 +600,7 @@ impl<T: Component> SparseSet<T> {
     /// Clear all deletion and removal tracking data.
     pub fn clear_all_removed_and_deleted(&mut self) {
         self.removal_data.clear();
+        self.is_removal_truncated = false;
     }
     /// Clear all deletion and removal tracking data older than some timestamp.
     pub fn clear_all_removed_and_deleted_older_than_timestamp(
@@ -437,6 +614,79 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: Component> SparseSet<T> {
+    /// Limits the deletion and removal tracking data to `capacity` entries each.\
+    /// `None` removes the limit, which is the default.
+    ///
+    /// When the limit is exceeded, the oldest entries are dropped and
+    /// [`is_deletion_truncated`](SparseSet::is_deletion_truncated) or
+    /// [`is_removal_truncated`](SparseSet::is_removal_truncated) return `true`
+    /// until the tracking data is cleared.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{track, Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Deletion)]
+    /// struct Bullet;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.borrow::<ViewMut<Bullet>>().unwrap().set_tracking_capacity(Some(2));
+    ///
+    /// for _ in 0..3 {
+    ///     let bullet = world.add_entity(Bullet);
+    ///     world.delete_entity(bullet);
+    /// }
+    ///
+    /// let mut bullets = world.borrow::<ViewMut<Bullet>>().unwrap();
+    /// assert_eq!(bullets.deleted().count(), 2);
+    /// assert!(bullets.is_deletion_truncated());
+    ///
+    /// bullets.clear_all_deleted();
+    /// assert!(!bullets.is_deletion_truncated());
+    /// ```
+    pub fn set_tracking_capacity(&mut self, capacity: Option<usize>) -> &mut SparseSet<T> {
+        self.tracking_capacity = capacity;
+        self.enforce_tracking_capacity();
+
+        self
+    }
+    /// Returns the maximum number of deletion and removal tracking entries kept by this storage.
+    pub fn tracking_capacity(&self) -> Option<usize> {
+        self.tracking_capacity
+    }
+    /// Returns `true` if deletion tracking entries were dropped since the last clear.
+    pub fn is_deletion_truncated(&self) -> bool {
+        self.is_deletion_truncated
+    }
+    /// Returns `true` if removal tracking entries were dropped since the last clear.
+    pub fn is_removal_truncated(&self) -> bool {
+        self.is_removal_truncated
+    }
+    /// Drops the oldest deletion and removal tracking entries over the capacity.\
+    /// Both are ring buffers, dropping from the front doesn't move the remaining entries.
+    fn enforce_tracking_capacity(&mut self) {
+        let Some(capacity) = self.tracking_capacity else {
+            return;
+        };
+
+        if self.deletion_data.len() > capacity {
+            let excess = self.deletion_data.len() - capacity;
+            self.deletion_data.drain(..excess);
+            self.is_deletion_truncated = true;
+        }
+
+        if self.removal_data.len() > capacity {
+            let excess = self.removal_data.len() - capacity;
+            self.removal_data.drain(..excess);
+            self.is_removal_truncated = true;
+        }
+    }
+}
+
 impl<T: Component> SparseSet<T> {
     /// Make this storage track insertions.
     #[allow(clippy::manual_repeat_n, reason = "Too recent version")]
@@ -538,12 +788,49 @@ impl<T: Component> SparseSet<T> {
 }
 
 impl<T: Component> SparseSet<T> {
-    /// Reserves memory for at least `additional` components. Adding components can still allocate though.
+    /// Reserves memory for at least `additional` components. Adding components can still allocate though.\
+    /// In a `World` with a fixed capacity, memory is never reserved beyond the capacity.
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
+        let additional = additional.min(self.remaining_capacity());
+
         self.dense.reserve(additional);
         self.data.reserve(additional);
     }
+    /// Returns how many components can still be added before reaching the fixed capacity of the `World`.\
+    /// Returns `usize::MAX` if the `World` doesn't have a fixed capacity.
+    #[inline]
+    pub(crate) fn remaining_capacity(&self) -> usize {
+        self.fixed_capacity.map_or(usize::MAX, |capacity| {
+            capacity.saturating_sub(self.dense.len())
+        })
+    }
+    /// Limits the storage to `capacity` components and allocates them upfront.
+    pub(crate) fn private_set_fixed_capacity(&mut self, capacity: usize) {
+        self.reserve_total(capacity);
+        self.fixed_capacity = Some(capacity);
+    }
+    /// Shrinks the capacity of the storage as much as possible, including tracking data.\
+    /// Does nothing in a `World` with a fixed capacity.
+    pub fn shrink_to_fit(&mut self) {
+        if self.fixed_capacity.is_some() {
+            return;
+        }
+
+        self.sparse.shrink_to_fit();
+        self.dense.shrink_to_fit();
+        self.data.shrink_to_fit();
+        self.insertion_data.shrink_to_fit();
+        self.modification_data.shrink_to_fit();
+        self.deletion_data.shrink_to_fit();
+        self.removal_data.shrink_to_fit();
+    }
+    /// Reserves memory for at least `capacity` components in total, including the sparse array.
+    #[inline]
+    pub(crate) fn reserve_total(&mut self, capacity: usize) {
+        self.reserve(capacity.saturating_sub(self.len()));
+        self.sparse.reserve(capacity);
+    }
     /// Sorts the `SparseSet` with a comparator function, but may not preserve the order of equal elements.
     pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
         let mut transform: Vec<usize> = (0..self.dense.len()).collect();
@@ -555,6 +842,107 @@ impl<T: Component> SparseSet<T> {
             })
         });
 
+        self.apply_permutation(&transform);
+    }
+
+    /// Reorders the `SparseSet` to follow the order of `other`.\
+    /// Entities present in both storages are placed first, in the same order as in `other`.
+    /// The other entities are placed after them and keep their relative order.
+    ///
+    /// Systems iterating both storages will access memory more linearly.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid0 = world.add_entity(U32(0));
+    /// let eid1 = world.add_entity((U32(1), USIZE(1)));
+    /// let eid2 = world.add_entity((U32(2), USIZE(2)));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>, mut usizes: ViewMut<USIZE>| {
+    ///     usizes.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+    ///
+    ///     u32s.defragment_against(&usizes);
+    ///
+    ///     assert_eq!(u32s.index_of(eid2), Some(0));
+    ///     assert_eq!(u32s.index_of(eid1), Some(1));
+    ///     assert_eq!(u32s.index_of(eid0), Some(2));
+    /// });
+    /// ```
+    pub fn defragment_against<U: Component>(&mut self, other: &SparseSet<U>) {
+        self.reorder_like(&other.dense);
+    }
+
+    /// Returns the number of components at the start of this storage that are tightly packed with other storages.\
+    /// Returns `None` if the storage is not packed.
+    ///
+    /// Components in `0..len` belong to the same entities, in the same order, in all storages of the pack.\
+    /// See [`World::tight_pack`](crate::World::tight_pack).
+    #[inline]
+    pub fn packed_len(&self) -> Option<usize> {
+        self.pack.as_ref().and_then(TightPack::len)
+    }
+
+    /// Places `entities` at the start of the storage, in the same order, and flags them as packed.\
+    /// All `entities` have to be present in the storage.
+    pub(crate) fn private_tight_pack(&mut self, entities: &[EntityId], pack: TightPack) {
+        self.reorder_like(entities);
+        self.pack = Some(pack);
+    }
+
+    /// Unpacks the storage and the other storages of its pack if `index` is part of the packed components.
+    #[inline]
+    fn unpack_at(&mut self, index: usize) {
+        if self.packed_len().is_some_and(|len| index < len) {
+            self.unpack();
+        }
+    }
+
+    /// Unpacks the storage and the other storages of its pack.
+    #[inline]
+    fn unpack(&mut self) {
+        if let Some(pack) = self.pack.take() {
+            pack.unpack();
+        }
+    }
+
+    /// Places the components of `entities` first, in the same order, and the others after them.
+    fn reorder_like(&mut self, entities: &[EntityId]) {
+        let mut transform: Vec<usize> = Vec::with_capacity(self.dense.len());
+        let mut placed = vec![false; self.dense.len()];
+
+        for &entity in entities {
+            if let Some(index) = self.index_of(entity) {
+                transform.push(index);
+                placed[index] = true;
+            }
+        }
+
+        if transform.len() != self.dense.len() {
+            transform.extend(
+                placed
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, &placed)| (!placed).then_some(index)),
+            );
+        }
+
+        self.apply_permutation(&transform);
+    }
+
+    /// Moves the component at `transform[i]` to `i` and updates the sparse array.
+    fn apply_permutation(&mut self, transform: &[usize]) {
+        self.unpack();
+
         let mut pos;
         for i in 0..transform.len() {
             // SAFE we're in bound
@@ -667,6 +1055,8 @@ impl<T: Component> SparseSet<T> {
 
     /// Deletes all components in this storage.
     pub(crate) fn private_clear(&mut self, current: TrackingTimestamp) {
+        self.unpack();
+
         for &id in &self.dense {
             unsafe {
                 *self.sparse.get_mut_unchecked(id) = EntityId::dead();
@@ -686,14 +1076,18 @@ impl<T: Component> SparseSet<T> {
                 .zip(data)
                 .map(|(entity, component)| (entity, current, component));
             self.deletion_data.extend(iter);
+            self.enforce_tracking_capacity();
         }
     }
 
     /// Creates a draining iterator that empties the storage and yields the removed items.
     pub(crate) fn private_drain(&mut self, current: TrackingTimestamp) -> SparseSetDrain<'_, T> {
+        self.unpack();
+
         if self.is_tracking_removal {
             self.removal_data
                 .extend(self.dense.iter().map(|&entity| (entity, current)));
+            self.enforce_tracking_capacity();
         }
 
         for id in &self.dense {
@@ -778,6 +1172,37 @@ impl<T: Clone + Component> SparseSet<T> {
     }
 }
 
+impl<T: fmt::Debug + Component> SparseSet<T> {
+    /// Registers the function to format this component in [`AllStorages::debug_dump`].
+    #[inline]
+    pub fn register_debug(&mut self) {
+        self.debug = Some(|component| format!("{:?}", component))
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Registers the function to collect this component when its entity is deleted
+    /// with [`AllStorages::delete_entity_collect`].
+    #[inline]
+    pub fn register_take(&mut self) {
+        self.take = Some(|component| Box::new(component) as Box<dyn Any>);
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T: Component> SparseSet<T> {
+    /// Registers a conversion from an older serialization format of this component.\
+    /// Migrations are tried in registration order when the current format fails to deserialize.
+    #[inline]
+    pub fn register_migration<Old: serde::de::DeserializeOwned>(
+        &mut self,
+        f: impl Fn(Old) -> T + Send + Sync + 'static,
+    ) {
+        self.migrations
+            .push(crate::views::serde::de::content::migration(f));
+    }
+}
+
 impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     #[inline]
     fn delete(&mut self, entity: EntityId, current: TrackingTimestamp) {
@@ -787,6 +1212,29 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn delete_and_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        self.private_delete_take(entity, current)
+    }
+    #[inline]
+    fn swap_entities(&mut self, a: EntityId, b: EntityId, current: TrackingTimestamp) {
+        self.private_swap_entities(a, b, current);
+    }
+    fn debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        self.private_debug_components(max)
+    }
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+    #[inline]
+    fn set_fixed_capacity(&mut self, capacity: usize) {
+        self.private_set_fixed_capacity(capacity);
+    }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
@@ -796,6 +1244,12 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        Some(self.contains(entity))
+    }
     fn clear_all_inserted(&mut self, current: TrackingTimestamp) {
         self.last_insert = current;
     }
@@ -805,6 +1259,8 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
+        self.is_deletion_truncated = false;
+        self.is_removal_truncated = false;
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
         self.deletion_data
@@ -813,6 +1269,88 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
     }
+    fn tracking_snapshot(&self) -> Option<StorageTrackingSnapshot> {
+        Some(StorageTrackingSnapshot {
+            last_insert: self.last_insert,
+            last_modified: self.last_modified,
+            insertion: self
+                .dense
+                .iter()
+                .copied()
+                .zip(self.insertion_data.iter().copied())
+                .collect(),
+            modification: self
+                .dense
+                .iter()
+                .copied()
+                .zip(self.modification_data.iter().copied())
+                .collect(),
+            deletion: self
+                .deletion_data
+                .iter()
+                .map(|(entity, timestamp, _)| (*entity, *timestamp))
+                .collect(),
+            removal: self.removal_data.iter().copied().collect(),
+        })
+    }
+    fn restore_tracking_snapshot(&mut self, snapshot: &StorageTrackingSnapshot) {
+        self.last_insert = snapshot.last_insert;
+        self.last_modified = snapshot.last_modified;
+
+        if self.is_tracking_insertion {
+            for &(entity, timestamp) in &snapshot.insertion {
+                if let Some(index) = self.index_of(entity) {
+                    self.insertion_data[index] = timestamp;
+                }
+            }
+        }
+
+        if self.is_tracking_modification {
+            for &(entity, timestamp) in &snapshot.modification {
+                if let Some(index) = self.index_of(entity) {
+                    self.modification_data[index] = timestamp;
+                }
+            }
+        }
+
+        // Deleted components can't be brought back, only the ones still present are kept
+        let mut deletion = ShipHashMap::with_capacity(snapshot.deletion.len());
+        for &(entity, timestamp) in &snapshot.deletion {
+            deletion.entry(entity).or_insert(timestamp);
+        }
+
+        self.deletion_data
+            .retain_mut(|(entity, timestamp, _)| match deletion.get(entity) {
+                Some(&snapshot_timestamp) => {
+                    *timestamp = snapshot_timestamp;
+
+                    true
+                }
+                None => false,
+            });
+
+        self.removal_data.clear();
+        self.removal_data.extend(snapshot.removal.iter().copied());
+    }
+    #[inline]
+    fn check_move_capacity(
+        &self,
+        other_all_storages: &mut AllStorages,
+        entity: EntityId,
+    ) -> Result<(), error::TransferEntity> {
+        if other_all_storages.fixed_capacity.is_none() || !self.contains(entity) {
+            return Ok(());
+        }
+
+        let other_sparse_set = other_all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+        if other_sparse_set.remaining_capacity() > 0 {
+            Ok(())
+        } else {
+            Err(error::TransferEntity::StorageFull(type_name::<T>()))
+        }
+    }
     #[inline]
     fn move_component_from(
         &mut self,
@@ -1162,6 +1700,39 @@ mod tests {
         assert_eq!(sparse_set.removal_data.len(), 2);
     }
 
+    #[test]
+    fn tracking_capacity() {
+        let mut sparse_set = SparseSet::new();
+        sparse_set.track_all();
+
+        for index in 0..4 {
+            sparse_set
+                .insert(EntityId::new(index), I32(0), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+
+        sparse_set.dyn_remove(EntityId::new(0), TrackingTimestamp::new(1));
+        sparse_set.dyn_remove(EntityId::new(1), TrackingTimestamp::new(2));
+        assert!(!sparse_set.is_removal_truncated());
+
+        sparse_set.set_tracking_capacity(Some(1));
+        assert!(sparse_set.is_removal_truncated());
+        assert_eq!(sparse_set.removal_data.len(), 1);
+        assert_eq!(sparse_set.removal_data[0].0, EntityId::new(1));
+
+        sparse_set.dyn_delete(EntityId::new(2), TrackingTimestamp::new(3));
+        assert!(!sparse_set.is_deletion_truncated());
+        sparse_set.dyn_delete(EntityId::new(3), TrackingTimestamp::new(4));
+        assert!(sparse_set.is_deletion_truncated());
+        assert_eq!(sparse_set.deletion_data.len(), 1);
+        assert_eq!(sparse_set.deletion_data[0].0, EntityId::new(3));
+
+        sparse_set.clear_all_deleted();
+        sparse_set.clear_all_removed();
+        assert!(!sparse_set.is_deletion_truncated());
+        assert!(!sparse_set.is_removal_truncated());
+    }
+
     #[test]
     fn drain_with_id() {
         let mut sparse_set = SparseSet::new();
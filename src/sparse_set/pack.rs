@@ -0,0 +1,123 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+#[cfg(doc)]
+use crate::world::World;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared by all storages packed together, with the number of packed components.
+///
+/// When one storage of the pack is unpacked, all of them are.
+#[derive(Clone)]
+pub struct TightPack {
+    len: usize,
+    is_packed: Arc<AtomicBool>,
+}
+
+impl TightPack {
+    /// Returns a new pack of `len` components.
+    fn new(len: usize) -> TightPack {
+        TightPack {
+            len,
+            is_packed: Arc::new(AtomicBool::new(true)),
+        }
+    }
+    /// Returns the number of packed components, `None` if the pack was undone.
+    #[inline]
+    pub(crate) fn len(&self) -> Option<usize> {
+        self.is_packed.load(Ordering::Relaxed).then_some(self.len)
+    }
+    /// Undoes the pack for all its storages.
+    #[inline]
+    pub(crate) fn unpack(&self) {
+        self.is_packed.store(false, Ordering::Relaxed);
+    }
+    /// Returns the number of components packed in all `packs`.\
+    /// Returns `None` if one of them isn't packed or they aren't part of the same pack.
+    pub(crate) fn common_len(packs: &[Option<TightPack>]) -> Option<usize> {
+        let (first, others) = packs.split_first()?;
+        let first = first.as_ref()?;
+
+        let is_same_pack = others.iter().all(|pack| {
+            pack.as_ref()
+                .is_some_and(|pack| Arc::ptr_eq(&pack.is_packed, &first.is_packed))
+        });
+
+        if is_same_pack {
+            first.len()
+        } else {
+            None
+        }
+    }
+}
+
+/// Trait used as bound for [`World::tight_pack`] and [`AllStorages::tight_pack`].
+pub trait TuplePack {
+    /// Trait used as bound for [`World::tight_pack`] and [`AllStorages::tight_pack`].
+    fn tight_pack(all_storages: &mut AllStorages);
+    /// Trait used as bound for [`World::is_packed`] and [`AllStorages::is_packed`].
+    fn is_packed(all_storages: &AllStorages) -> bool;
+}
+
+macro_rules! impl_tuple_pack {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component,)+> TuplePack for ($($type,)+) {
+            fn tight_pack(all_storages: &mut AllStorages) {
+                let mut entities: Option<Vec<EntityId>> = None;
+
+                $(
+                    let sparse_set = all_storages
+                        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new);
+
+                    entities = Some(match entities {
+                        Some(mut entities) => {
+                            entities.retain(|&entity| sparse_set.contains(entity));
+                            entities
+                        }
+                        None => sparse_set.dense.clone(),
+                    });
+                )+
+
+                let entities = entities.unwrap_or_default();
+                let pack = TightPack::new(entities.len());
+
+                $(
+                    all_storages
+                        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .private_tight_pack(&entities, pack.clone());
+                )+
+            }
+
+            fn is_packed(all_storages: &AllStorages) -> bool {
+                TightPack::common_len(&[$(all_storages.sparse_set_pack::<$type>(),)+]).is_some()
+            }
+        }
+    };
+}
+
+macro_rules! tuple_pack {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_tuple_pack![$(($type, $index))*];
+        tuple_pack![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_tuple_pack![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+tuple_pack![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
+#[cfg(feature = "extended_tuple")]
+tuple_pack![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
@@ -27,6 +27,11 @@ impl<T, const N: usize> SparseArray<T, N> {
     pub(super) fn as_mut_ptr(&mut self) -> *mut Option<Box<[T; N]>> {
         self.0.as_mut_ptr()
     }
+    /// Reserves enough buckets to store `len` indices without reallocating the bucket list.
+    #[inline]
+    pub(super) fn reserve(&mut self, len: usize) {
+        self.0.reserve(len.div_ceil(N).saturating_sub(self.0.len()));
+    }
     pub(super) fn used_memory(&self) -> usize {
         self.0.len() * size_of::<Option<Box<T>>>()
             + self.0.iter().fold(0, |count, array| {
@@ -69,6 +74,23 @@ impl<const N: usize> SparseArray<EntityId, N> {
             }
         }
     }
+    /// Frees buckets without any entity and drops the bucket list's unused capacity.
+    pub(super) fn shrink_to_fit(&mut self) {
+        for bucket in &mut self.0 {
+            if bucket
+                .as_ref()
+                .is_some_and(|bucket| bucket.iter().all(|entity| entity.is_dead()))
+            {
+                *bucket = None;
+            }
+        }
+
+        while let Some(None) = self.0.last() {
+            self.0.pop();
+        }
+
+        self.0.shrink_to_fit();
+    }
     pub(crate) fn bulk_allocate(&mut self, start: EntityId, end: EntityId) {
         if end.bucket() >= self.0.len() {
             self.0.resize(end.bucket() + 1, None);
@@ -6,6 +6,10 @@ use crate::memory_usage::StorageMemoryUsage;
 use crate::sparse_set::{sparse_array::SparseArray, SparseSet, BUCKET_SIZE};
 use crate::storage::{SBoxBuilder, Storage, StorageId};
 use crate::tracking::TrackingTimestamp;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
 
 impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
     #[inline]
@@ -16,6 +20,29 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn delete_and_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        self.private_delete_take(entity, current)
+    }
+    #[inline]
+    fn swap_entities(&mut self, a: EntityId, b: EntityId, current: TrackingTimestamp) {
+        self.private_swap_entities(a, b, current);
+    }
+    fn debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        self.private_debug_components(max)
+    }
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+    #[inline]
+    fn set_fixed_capacity(&mut self, capacity: usize) {
+        self.0.private_set_fixed_capacity(capacity);
+    }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
@@ -25,6 +52,12 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        Some(self.0.contains(entity))
+    }
     fn clear_all_inserted(&mut self, current: TrackingTimestamp) {
         self.0.last_insert = current;
     }
@@ -34,6 +67,8 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
+        self.is_deletion_truncated = false;
+        self.is_removal_truncated = false;
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
         self.deletion_data
@@ -118,6 +153,29 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn delete_and_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        self.private_delete_take(entity, current)
+    }
+    #[inline]
+    fn swap_entities(&mut self, a: EntityId, b: EntityId, current: TrackingTimestamp) {
+        self.private_swap_entities(a, b, current);
+    }
+    fn debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        self.private_debug_components(max)
+    }
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+    #[inline]
+    fn set_fixed_capacity(&mut self, capacity: usize) {
+        self.0.private_set_fixed_capacity(capacity);
+    }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
@@ -127,6 +185,12 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        Some(self.0.contains(entity))
+    }
     fn clear_all_inserted(&mut self, current: TrackingTimestamp) {
         self.0.last_insert = current;
     }
@@ -136,6 +200,8 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
+        self.is_deletion_truncated = false;
+        self.is_removal_truncated = false;
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
         self.deletion_data
@@ -219,6 +285,29 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn delete_and_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        self.private_delete_take(entity, current)
+    }
+    #[inline]
+    fn swap_entities(&mut self, a: EntityId, b: EntityId, current: TrackingTimestamp) {
+        self.private_swap_entities(a, b, current);
+    }
+    fn debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        self.private_debug_components(max)
+    }
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+    #[inline]
+    fn set_fixed_capacity(&mut self, capacity: usize) {
+        self.0.private_set_fixed_capacity(capacity);
+    }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
@@ -228,6 +317,12 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        Some(self.0.contains(entity))
+    }
     fn clear_all_inserted(&mut self, current: TrackingTimestamp) {
         self.0.last_insert = current;
     }
@@ -237,6 +332,8 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
+        self.is_deletion_truncated = false;
+        self.is_removal_truncated = false;
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
         self.deletion_data
@@ -8,10 +8,14 @@ pub(crate) use sbox::SBox;
 
 use crate::all_storages::AllStorages;
 use crate::entity_id::EntityId;
+use crate::error;
 use crate::memory_usage::StorageMemoryUsage;
 use crate::sparse_set::SparseArray;
-use crate::tracking::TrackingTimestamp;
+use crate::tracking::{StorageTrackingSnapshot, TrackingTimestamp};
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::any::Any;
 
 pub trait SizedAny {
@@ -50,6 +54,35 @@ pub trait Storage: SizedAny {
     #[inline]
     #[allow(unused_variables)]
     fn clear(&mut self, current: TrackingTimestamp) {}
+    /// Deletes an entity from this storage and returns its component if the storage is set up to collect it.
+    #[inline]
+    fn delete_and_take(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<Box<dyn Any>> {
+        self.delete(entity, current);
+
+        None
+    }
+    /// Swaps the components of two entities, moving it when only one of them has a component.
+    #[inline]
+    #[allow(unused_variables)]
+    fn swap_entities(&mut self, a: EntityId, b: EntityId, current: TrackingTimestamp) {}
+    /// Shrinks the storage's capacity as much as possible.
+    #[inline]
+    fn shrink_to_fit(&mut self) {}
+    /// Limits the storage to `capacity` components and allocates them upfront.\
+    /// Called when the storage is created in a `World` with a fixed capacity.
+    #[inline]
+    #[allow(unused_variables)]
+    fn set_fixed_capacity(&mut self, capacity: usize) {}
+    /// Returns the first `max` components formatted with [`Debug`](core::fmt::Debug), if the storage is set up to format them.
+    #[inline]
+    #[allow(unused_variables)]
+    fn debug_components(&self, max: usize) -> Option<Vec<(EntityId, String)>> {
+        None
+    }
     /// Returns how much memory this storage uses.
     #[inline]
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
@@ -73,6 +106,19 @@ pub trait Storage: SizedAny {
     fn is_empty(&self) -> bool {
         false
     }
+    /// Returns the number of components in the storage.\
+    /// Returns `None` if the storage doesn't store components per entity.
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        None
+    }
+    /// Returns `true` if `entity` has a component in this storage.\
+    /// Returns `None` if the storage doesn't store components per entity.
+    #[inline]
+    #[allow(unused_variables)]
+    fn contains(&self, entity: EntityId) -> Option<bool> {
+        None
+    }
     /// Clear all insertion tracking data.
     #[inline]
     #[allow(unused_variables)]
@@ -88,6 +134,25 @@ pub trait Storage: SizedAny {
     #[allow(unused_variables)]
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
     }
+    /// Returns a copy of the tracking metadata, without components.
+    #[inline]
+    fn tracking_snapshot(&self) -> Option<StorageTrackingSnapshot> {
+        None
+    }
+    /// Restores tracking metadata previously returned by [`Storage::tracking_snapshot`].
+    #[inline]
+    #[allow(unused_variables)]
+    fn restore_tracking_snapshot(&mut self, snapshot: &StorageTrackingSnapshot) {}
+    /// Checks `other_all_storages` has room for `entity`'s component before it is moved there.
+    #[inline]
+    #[allow(unused_variables)]
+    fn check_move_capacity(
+        &self,
+        other_all_storages: &mut AllStorages,
+        entity: EntityId,
+    ) -> Result<(), error::TransferEntity> {
+        Ok(())
+    }
     /// Moves a component from a `World` to another.
     #[inline]
     #[allow(unused_variables)]
@@ -11,7 +11,10 @@ use alloc::sync::Arc;
 /// Abstract away `T` from `AtomicRefCell<T>` to be able to store
 /// different types in a `HashMap<TypeId, Storage>`.
 /// and box the `AtomicRefCell` so it doesn't move when the `HashMap` reallocates
-pub(crate) struct SBox(pub(crate) *mut AtomicRefCell<dyn Storage>);
+///
+/// The second field records if the storage holds components when it's created,
+/// it can then be read without borrowing the storage.
+pub(crate) struct SBox(pub(crate) *mut AtomicRefCell<dyn Storage>, bool);
 
 #[cfg(not(feature = "thread_local"))]
 unsafe impl Send for SBox {}
@@ -30,7 +33,12 @@ impl Drop for SBox {
 impl SBox {
     #[inline]
     pub(crate) fn new<T: Storage + Send + Sync + 'static>(value: T) -> SBox {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new(value))))
+        let is_component = value.sparse_array().is_some();
+
+        SBox(
+            Box::into_raw(Box::new(AtomicRefCell::new(value))),
+            is_component,
+        )
     }
 
     #[cfg(feature = "thread_local")]
@@ -39,15 +47,23 @@ impl SBox {
         value: T,
         thread_id: Arc<dyn Fn() -> u64 + Send + Sync>,
     ) -> SBox {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_send(
-            value, thread_id,
-        ))))
+        let is_component = value.sparse_array().is_some();
+
+        SBox(
+            Box::into_raw(Box::new(AtomicRefCell::new_non_send(value, thread_id))),
+            is_component,
+        )
     }
 
     #[cfg(feature = "thread_local")]
     #[inline]
     pub(crate) fn new_non_sync<T: Storage + Send + 'static>(value: T) -> SBox {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_sync(value))))
+        let is_component = value.sparse_array().is_some();
+
+        SBox(
+            Box::into_raw(Box::new(AtomicRefCell::new_non_sync(value))),
+            is_component,
+        )
     }
 
     #[cfg(feature = "thread_local")]
@@ -56,9 +72,18 @@ impl SBox {
         value: T,
         thread_id: Arc<dyn Fn() -> u64 + Send + Sync>,
     ) -> SBox {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_send_sync(
-            value, thread_id,
-        ))))
+        let is_component = value.sparse_array().is_some();
+
+        SBox(
+            Box::into_raw(Box::new(AtomicRefCell::new_non_send_sync(value, thread_id))),
+            is_component,
+        )
+    }
+
+    /// Returns `true` if the storage holds components, `Entities` and unique storages don't.
+    #[inline]
+    pub(crate) fn is_component(&self) -> bool {
+        self.1
     }
 }
 
@@ -36,10 +36,10 @@ impl RemovalOrDeletionTracking for Deletion {
         sparse_set: &SparseSet<T>,
     ) -> core::iter::Chain<
         core::iter::Map<
-            core::slice::Iter<'_, (EntityId, TrackingTimestamp, T)>,
+            alloc::collections::vec_deque::Iter<'_, (EntityId, TrackingTimestamp, T)>,
             for<'r> fn(&'r (EntityId, TrackingTimestamp, T)) -> (EntityId, TrackingTimestamp),
         >,
-        core::iter::Copied<core::slice::Iter<'_, (EntityId, TrackingTimestamp)>>,
+        core::iter::Copied<alloc::collections::vec_deque::Iter<'_, (EntityId, TrackingTimestamp)>>,
     > {
         sparse_set
             .deletion_data
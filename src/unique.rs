@@ -5,6 +5,7 @@ use crate::component::Unique;
 use crate::memory_usage::StorageMemoryUsage;
 use crate::storage::{SBoxBuilder, Storage};
 use crate::tracking::TrackingTimestamp;
+use alloc::boxed::Box;
 use core::any::type_name;
 use core::mem::size_of;
 
@@ -54,6 +55,12 @@ impl<T: Unique + Send + Sync> Storage for UniqueStorage<T> {
     }
 }
 
+/// Initializer of a unique storage, stored in the [`UniqueStorage`]'s slot and replaced by it the first time it is borrowed.
+#[allow(clippy::type_complexity)]
+pub(crate) struct LazyUnique<T>(pub(crate) Option<Box<dyn FnOnce() -> T + Send + Sync>>);
+
+impl<T: Unique> Storage for LazyUnique<T> {}
+
 impl<T: Unique> UniqueStorage<T> {
     pub(crate) fn new(value: T, current: TrackingTimestamp) -> Self {
         UniqueStorage {
@@ -3,7 +3,10 @@
 pub mod serde;
 
 mod all_storages;
+mod arena;
+mod commands;
 mod entities;
+mod entry;
 mod unique_or_default;
 mod unique_or_default_mut;
 mod unique_or_init;
@@ -12,9 +15,15 @@ mod unique_view;
 mod unique_view_mut;
 mod view;
 mod view_mut;
+mod world;
 
 pub use all_storages::{AllStoragesView, AllStoragesViewMut};
+pub use arena::Arena;
+pub use commands::Commands;
+#[cfg(all(feature = "parallel", feature = "std"))]
+pub use commands::ParCommands;
 pub use entities::{EntitiesView, EntitiesViewMut};
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
 pub use unique_or_default::UniqueOrDefaultView;
 pub use unique_or_default_mut::UniqueOrDefaultViewMut;
 pub use unique_or_init::UniqueOrInitView;
@@ -23,3 +32,4 @@ pub use unique_view::UniqueView;
 pub use unique_view_mut::UniqueViewMut;
 pub use view::View;
 pub use view_mut::ViewMut;
+pub use world::WorldView;
@@ -0,0 +1,129 @@
+use crate::arena::ScratchArena;
+use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
+use core::alloc::Layout;
+use core::cell::RefCell;
+
+/// Scratch memory for short-lived buffers.
+///
+/// Allocations are bump allocated and released all at once at the start of each workload run.\
+/// The memory is kept between runs, after the first few runs systems no longer hit the global allocator.\
+/// Outside workloads the arena keeps growing until [`Arena::reset`] is called.
+///
+/// Only `Copy` types can be allocated, destructors would never run.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Arena, Component, View, Workload, World};
+///
+/// #[derive(Component, Clone, Copy)]
+/// struct Pos(f32);
+///
+/// fn sorted_positions(positions: View<Pos>, arena: Arena) {
+///     let sorted = arena.alloc_slice_fill_with(positions.len(), |i| positions.as_slice()[i].0);
+///     sorted.sort_by(f32::total_cmp);
+///
+///     assert_eq!(sorted, &[0.5, 1.0, 2.0]);
+/// }
+///
+/// let mut world = World::new();
+///
+/// world.add_entity(Pos(2.0));
+/// world.add_entity(Pos(0.5));
+/// world.add_entity(Pos(1.0));
+///
+/// Workload::new("Frame")
+///     .with_system(sorted_positions)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Frame").unwrap();
+/// world.run_workload("Frame").unwrap();
+/// ```
+pub struct Arena<'a> {
+    /// `RefCell` makes `Arena` !Sync, a single thread can allocate at a time.
+    pub(crate) arena: RefCell<&'a mut ScratchArena>,
+    pub(crate) _borrow: ExclusiveBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl Arena<'_> {
+    /// Moves `value` into the arena.
+    #[inline]
+    #[allow(clippy::mut_from_ref, reason = "Bump allocation")]
+    pub fn alloc<T: Copy>(&self, value: T) -> &mut T {
+        let ptr = self
+            .arena
+            .borrow_mut()
+            .alloc_layout(Layout::new::<T>())
+            .cast::<T>();
+
+        // SAFE the memory is valid and aligned for a `T`, the arena never hands it out twice
+        unsafe {
+            ptr.as_ptr().write(value);
+            &mut *ptr.as_ptr()
+        }
+    }
+    /// Copies `slice` into the arena.
+    #[inline]
+    #[allow(clippy::mut_from_ref, reason = "Bump allocation")]
+    pub fn alloc_slice_copy<T: Copy>(&self, slice: &[T]) -> &mut [T] {
+        let new_slice = self.alloc_slice_uninit::<T>(slice.len());
+
+        // SAFE `new_slice` is valid for `slice.len()` elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(slice.as_ptr(), new_slice, slice.len());
+            core::slice::from_raw_parts_mut(new_slice, slice.len())
+        }
+    }
+    /// Allocates a slice of `len` elements initialized with `f(index)`.
+    #[inline]
+    #[allow(clippy::mut_from_ref, reason = "Bump allocation")]
+    pub fn alloc_slice_fill_with<T: Copy, F: FnMut(usize) -> T>(
+        &self,
+        len: usize,
+        mut f: F,
+    ) -> &mut [T] {
+        let new_slice = self.alloc_slice_uninit::<T>(len);
+
+        // SAFE `new_slice` is valid for `len` elements, `T: Copy` so nothing leaks if `f` panics
+        unsafe {
+            for i in 0..len {
+                new_slice.add(i).write(f(i));
+            }
+
+            core::slice::from_raw_parts_mut(new_slice, len)
+        }
+    }
+    /// Allocates a slice with the items of `iter`.
+    #[inline]
+    #[allow(clippy::mut_from_ref, reason = "Bump allocation")]
+    pub fn alloc_slice_fill_iter<T: Copy, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+
+        self.alloc_slice_fill_with(iter.len(), |_| {
+            iter.next()
+                .expect("ExactSizeIterator returned less items than its length")
+        })
+    }
+    /// Makes all the arena's memory available again, without freeing it.
+    ///
+    /// Workloads reset the arena automatically at the start of each run.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.arena.get_mut().reset();
+    }
+    fn alloc_slice_uninit<T>(&self, len: usize) -> *mut T {
+        let layout = Layout::array::<T>(len).expect("Arena allocation is too large");
+
+        self.arena
+            .borrow_mut()
+            .alloc_layout(layout)
+            .cast::<T>()
+            .as_ptr()
+    }
+}
@@ -0,0 +1,259 @@
+use crate::all_storages::AllStorages;
+#[cfg(not(feature = "std"))]
+use crate::atomic_refcell::ExclusiveBorrow;
+use crate::atomic_refcell::SharedBorrow;
+use crate::commands::{Command, CommandQueue};
+use crate::entity_id::EntityId;
+use crate::sparse_set::{TupleAddComponent, TupleDelete};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(all(feature = "parallel", feature = "std"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(all(feature = "parallel", feature = "std"))]
+use std::sync::{Mutex, PoisonError};
+
+/// Records structural changes to apply them later.
+///
+/// Adding or deleting entities requires an exclusive borrow of [`AllStorages`], which prevents
+/// any other storage to be borrowed at the same time.\
+/// `Commands` only records the operations, they are applied in the order they were recorded:
+/// - inside workloads, at each [`Workload::flush`] and once all systems ran
+/// - outside workloads, when calling [`World::apply_commands`] or [`AllStorages::apply_commands`]
+///
+/// A system never sees the effects of its own commands.
+/// Systems between two flushes never see each other's commands.
+/// Commands recorded by systems in the same batch are applied in an unspecified order.
+///
+/// Each `Commands` records in its own buffer, moved to the world's queue when it is dropped.\
+/// With the `std` feature the queue is only borrowed shared, systems using `Commands` can run in parallel.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Commands, Component, IntoIter, View, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Corpse;
+///
+/// let mut world = World::new();
+///
+/// world.add_entity(Health(0));
+/// world.add_entity(Health(10));
+///
+/// world.run(|healths: View<Health>, mut commands: Commands| {
+///     for (id, health) in healths.iter().with_id() {
+///         if health.0 == 0 {
+///             commands.delete_entity(id);
+///             commands.add_entity(Corpse);
+///         }
+///     }
+/// });
+///
+/// world.apply_commands();
+///
+/// assert_eq!(world.borrow::<View<Health>>().unwrap().len(), 1);
+/// assert_eq!(world.borrow::<View<Corpse>>().unwrap().len(), 1);
+/// ```
+///
+/// [`Workload::flush`]: crate::Workload::flush
+/// [`World::apply_commands`]: crate::World::apply_commands
+/// [`AllStorages::apply_commands`]: crate::all_storages::AllStorages::apply_commands
+pub struct Commands<'a> {
+    #[cfg(feature = "std")]
+    pub(crate) queue: &'a CommandQueue,
+    #[cfg(not(feature = "std"))]
+    pub(crate) queue: &'a mut CommandQueue,
+    pub(crate) buffer: Vec<Command>,
+    #[cfg(feature = "std")]
+    pub(crate) _borrow: SharedBorrow<'a>,
+    #[cfg(not(feature = "std"))]
+    pub(crate) _borrow: ExclusiveBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl Drop for Commands<'_> {
+    fn drop(&mut self) {
+        self.queue.append(&mut self.buffer);
+    }
+}
+
+impl Commands<'_> {
+    /// Records the creation of a new entity with the components passed as argument.
+    #[inline]
+    pub fn add_entity<C: TupleAddComponent + Send + Sync + 'static>(&mut self, component: C) {
+        self.run(move |all_storages| {
+            all_storages.add_entity(component);
+        });
+    }
+    /// Records the deletion of `entity` and all its components.
+    #[inline]
+    pub fn delete_entity(&mut self, entity: EntityId) {
+        self.run(move |all_storages| {
+            all_storages.delete_entity(entity);
+        });
+    }
+    /// Records the addition of components to `entity`.\
+    /// The components are only added if `entity` is still alive when the command is applied.
+    #[inline]
+    pub fn add_component<C: TupleAddComponent + Send + Sync + 'static>(
+        &mut self,
+        entity: EntityId,
+        component: C,
+    ) {
+        self.run(move |all_storages| {
+            if all_storages.is_entity_alive(entity) {
+                all_storages.add_component(entity, component);
+            }
+        });
+    }
+    /// Records the deletion of `C` components from `entity`.
+    #[inline]
+    pub fn delete_component<C: TupleDelete + 'static>(&mut self, entity: EntityId) {
+        self.run(move |all_storages| {
+            all_storages.delete_component::<C>(entity);
+        });
+    }
+    /// Records an arbitrary operation on [`AllStorages`].
+    #[inline]
+    pub fn run<F: FnOnce(&mut AllStorages) + Send + Sync + 'static>(&mut self, f: F) {
+        self.buffer.push(Box::new(f));
+    }
+    /// Returns the number of commands recorded by this `Commands`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+    /// Returns `true` if this `Commands` didn't record any command.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+    /// Returns a recorder usable from multiple threads at once, like inside `par_iter().for_each`.
+    ///
+    /// Threads record in one of several buffers, buffers are merged into `self` when [`ParCommands`] is dropped.\
+    /// Commands recorded by a thread keep their order, the order between threads is unspecified.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use shipyard::{Commands, Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for i in 0..100 {
+    ///     world.add_entity(Health(i % 2));
+    /// }
+    ///
+    /// world.run(|healths: View<Health>, mut commands: Commands| {
+    ///     let par_commands = commands.par_commands();
+    ///
+    ///     healths.par_iter().with_id().for_each(|(id, health)| {
+    ///         if health.0 == 0 {
+    ///             par_commands.delete_entity(id);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// world.apply_commands();
+    ///
+    /// assert_eq!(world.borrow::<View<Health>>().unwrap().len(), 50);
+    /// ```
+    #[cfg(all(feature = "parallel", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "parallel", feature = "std"))))]
+    pub fn par_commands(&mut self) -> ParCommands<'_> {
+        // As many buffers as threads in the current pool, threads sharing a buffer only contend on its lock
+        let buffers = (0..rayon::current_num_threads().max(1))
+            .map(|_| Mutex::new(Vec::new()))
+            .collect();
+
+        ParCommands {
+            buffer: &mut self.buffer,
+            buffers,
+        }
+    }
+}
+
+/// Records structural changes from multiple threads, created with [`Commands::par_commands`].
+///
+/// Recorded commands are moved to the [`Commands`] they come from when dropped.
+#[cfg(all(feature = "parallel", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "parallel", feature = "std"))))]
+pub struct ParCommands<'a> {
+    buffer: &'a mut Vec<Command>,
+    buffers: Vec<Mutex<Vec<Command>>>,
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+std::thread_local! {
+    /// Index used to pick a [`ParCommands`] buffer, unique per thread whatever pool it belongs to.
+    static THREAD_INDEX: usize = {
+        static NEXT_THREAD_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+        NEXT_THREAD_INDEX.fetch_add(1, Ordering::Relaxed)
+    };
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+impl ParCommands<'_> {
+    /// Records the creation of a new entity with the components passed as argument.
+    #[inline]
+    pub fn add_entity<C: TupleAddComponent + Send + Sync + 'static>(&self, component: C) {
+        self.run(move |all_storages| {
+            all_storages.add_entity(component);
+        });
+    }
+    /// Records the deletion of `entity` and all its components.
+    #[inline]
+    pub fn delete_entity(&self, entity: EntityId) {
+        self.run(move |all_storages| {
+            all_storages.delete_entity(entity);
+        });
+    }
+    /// Records the addition of components to `entity`.\
+    /// The components are only added if `entity` is still alive when the command is applied.
+    #[inline]
+    pub fn add_component<C: TupleAddComponent + Send + Sync + 'static>(
+        &self,
+        entity: EntityId,
+        component: C,
+    ) {
+        self.run(move |all_storages| {
+            if all_storages.is_entity_alive(entity) {
+                all_storages.add_component(entity, component);
+            }
+        });
+    }
+    /// Records the deletion of `C` components from `entity`.
+    #[inline]
+    pub fn delete_component<C: TupleDelete + 'static>(&self, entity: EntityId) {
+        self.run(move |all_storages| {
+            all_storages.delete_component::<C>(entity);
+        });
+    }
+    /// Records an arbitrary operation on [`AllStorages`].
+    pub fn run<F: FnOnce(&mut AllStorages) + Send + Sync + 'static>(&self, f: F) {
+        let index = THREAD_INDEX.with(|index| *index) % self.buffers.len();
+
+        self.buffers[index]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Box::new(f));
+    }
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+impl Drop for ParCommands<'_> {
+    fn drop(&mut self) {
+        for buffer in &mut self.buffers {
+            self.buffer
+                .append(buffer.get_mut().unwrap_or_else(PoisonError::into_inner));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get::Get;
+use crate::r#mut::Mut;
+use crate::tracking::Tracking;
+use crate::views::ViewMut;
+
+/// A view into a single entity's component, which may either be present or not.
+///
+/// Created with [`ViewMut::entry`].
+pub enum Entry<'a, 'v, T: Component, Track = <T as Component>::Tracking> {
+    /// The entity has a component in this storage.
+    Occupied(OccupiedEntry<'a, 'v, T, Track>),
+    /// The entity doesn't have a component in this storage.
+    Vacant(VacantEntry<'a, 'v, T, Track>),
+    /// The entity is dead, a more recent entity with the same index has a component in this storage.
+    Dead(EntityId),
+}
+
+impl<'a, 'v, T: Component, Track: Tracking> Entry<'a, 'v, T, Track> {
+    /// Returns the entity of this entry.
+    pub fn entity(&self) -> EntityId {
+        match self {
+            Entry::Occupied(entry) => entry.entity,
+            Entry::Vacant(entry) => entry.entity,
+            Entry::Dead(entity) => *entity,
+        }
+    }
+    /// Inserts `component` if the entity doesn't have one and returns the entity's component.
+    ///
+    /// The component is flagged *inserted* only when the vacant path is taken.
+    ///
+    /// ### Errors
+    ///
+    /// - The entry is [`Entry::Dead`].
+    #[inline]
+    pub fn or_insert(self, component: T) -> Result<Mut<'a, T>, error::AddComponent> {
+        self.or_insert_with(|| component)
+    }
+    /// Inserts the result of `f` if the entity doesn't have a component and returns the entity's component.
+    ///
+    /// `f` is only called when the vacant path is taken.
+    ///
+    /// ### Errors
+    ///
+    /// - The entry is [`Entry::Dead`].
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> Result<Mut<'a, T>, error::AddComponent> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(f())),
+            Entry::Dead(_) => Err(error::AddComponent::EntityIsNotAlive),
+        }
+    }
+    /// Calls `f` with the entity's component if it has one.
+    ///
+    /// The component is flagged *modified* if `f` mutably dereferences it.
+    #[inline]
+    pub fn and_modify<F: FnOnce(Mut<'_, T>)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, 'v, T: Component + Default, Track: Tracking> Entry<'a, 'v, T, Track> {
+    /// Inserts `T::default()` if the entity doesn't have a component and returns the entity's component.
+    ///
+    /// ### Errors
+    ///
+    /// - The entry is [`Entry::Dead`].
+    #[inline]
+    pub fn or_default(self) -> Result<Mut<'a, T>, error::AddComponent> {
+        self.or_insert_with(T::default)
+    }
+}
+
+/// An entity with a component in the storage, part of [`Entry`].
+pub struct OccupiedEntry<'a, 'v, T: Component, Track = <T as Component>::Tracking> {
+    pub(super) view: &'a mut ViewMut<'v, T, Track>,
+    pub(super) entity: EntityId,
+}
+
+impl<'a, 'v, T: Component, Track: Tracking> OccupiedEntry<'a, 'v, T, Track> {
+    /// Returns the entity of this entry.
+    pub fn entity(&self) -> EntityId {
+        self.entity
+    }
+    /// Returns a reference to the component.
+    pub fn get(&self) -> &T {
+        &self.view[self.entity]
+    }
+    /// Returns a mutable reference to the component.
+    pub fn get_mut(&mut self) -> Mut<'_, T> {
+        // The entity has a component in this storage.
+        Get::get(&mut *self.view, self.entity).unwrap()
+    }
+    /// Converts the entry into a mutable reference to the component, bound to the view's borrow.
+    pub fn into_mut(self) -> Mut<'a, T> {
+        // The entity has a component in this storage.
+        Get::get(self.view, self.entity).unwrap()
+    }
+}
+
+/// An entity without component in the storage, part of [`Entry`].
+pub struct VacantEntry<'a, 'v, T: Component, Track = <T as Component>::Tracking> {
+    pub(super) view: &'a mut ViewMut<'v, T, Track>,
+    pub(super) entity: EntityId,
+}
+
+impl<'a, 'v, T: Component, Track: Tracking> VacantEntry<'a, 'v, T, Track> {
+    /// Returns the entity of this entry.
+    pub fn entity(&self) -> EntityId {
+        self.entity
+    }
+    /// Inserts `component`, flagging it *inserted*, and returns a mutable reference to it.
+    pub fn insert(self, component: T) -> Mut<'a, T> {
+        self.view
+            .sparse_set
+            .insert(self.entity, component, self.view.current);
+
+        // The component was just inserted.
+        Get::get(self.view, self.entity).unwrap()
+    }
+}
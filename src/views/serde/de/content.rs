@@ -0,0 +1,439 @@
+use crate::entity_id::EntityId;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::Deserialize;
+
+/// Turns a component saved in an older format into the current one.
+pub(crate) type Migration<T> =
+    Box<dyn Fn(&Content) -> Result<(EntityId, T), ContentError> + Send + Sync>;
+
+/// Creates a [`Migration`] reading `Old` and converting it with `f`.
+pub(crate) fn migration<Old: DeserializeOwned, T>(
+    f: impl Fn(Old) -> T + Send + Sync + 'static,
+) -> Migration<T> {
+    Box::new(move |content| {
+        let (entity, old) =
+            <(EntityId, Old)>::deserialize(ContentDeserializer::<ContentError>::new(content))?;
+
+        Ok((entity, f(old)))
+    })
+}
+
+/// Deserializes one element in the current format then with each migration, in registration order.\
+/// Returns the error of the current format when no migration succeeds.
+///
+/// The element is buffered once, all formats are read from the same buffer.
+pub(crate) struct ElementSeed<'a, T> {
+    pub(crate) migrations: &'a [Migration<T>],
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for ElementSeed<'_, T> {
+    type Value = (EntityId, T);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(EntityId, T), D::Error> {
+        let content = Content::deserialize(deserializer)?;
+
+        match <(EntityId, T)>::deserialize(ContentDeserializer::<ContentError>::new(&content)) {
+            Ok(element) => Ok(element),
+            Err(err) => self
+                .migrations
+                .iter()
+                .find_map(|migration| migration(&content).ok())
+                .ok_or_else(|| de::Error::custom(err)),
+        }
+    }
+}
+
+/// Deserializes a sequence of elements with [`ElementSeed`].
+pub(crate) struct ElementsSeed<'a, T> {
+    pub(crate) migrations: &'a [Migration<T>],
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for ElementsSeed<'_, T> {
+    type Value = Vec<(EntityId, T)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for ElementsSeed<'_, T> {
+    type Value = Vec<(EntityId, T)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of entity_id-component pairs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(element) = seq.next_element_seed(ElementSeed {
+            migrations: self.migrations,
+        })? {
+            elements.push(element);
+        }
+
+        Ok(elements)
+    }
+}
+
+/// Self-describing copy of a deserialized value, it can be deserialized multiple times.
+pub(crate) enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    Newtype(Box<Content>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Content, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Content, E> {
+        Ok(Content::Bool(v))
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Content, E> {
+        Ok(Content::I64(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Content, E> {
+        Ok(Content::U64(v))
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Content, E> {
+        Ok(Content::F64(v))
+    }
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Content, E> {
+        Ok(Content::Char(v))
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Content, E> {
+        Ok(Content::String(v.to_string()))
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Content, E> {
+        Ok(Content::String(v))
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Content, E> {
+        Ok(Content::Bytes(v.to_vec()))
+    }
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Content, E> {
+        Ok(Content::Bytes(v))
+    }
+    fn visit_none<E: de::Error>(self) -> Result<Content, E> {
+        Ok(Content::None)
+    }
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|content| Content::Some(Box::new(content)))
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Content, E> {
+        Ok(Content::Unit)
+    }
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|content| Content::Newtype(Box::new(content)))
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Content, A::Error> {
+        let mut contents = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(content) = seq.next_element()? {
+            contents.push(content);
+        }
+
+        Ok(Content::Seq(contents))
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Error returned when deserializing a [`Content`].
+#[derive(Debug)]
+pub(crate) struct ContentError(String);
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl de::StdError for ContentError {}
+
+impl de::Error for ContentError {
+    fn custom<T: fmt::Display>(msg: T) -> ContentError {
+        ContentError(msg.to_string())
+    }
+}
+
+/// Replays a borrowed [`Content`] to a visitor, the content can be replayed again afterwards.
+pub(crate) struct ContentDeserializer<'a, E> {
+    content: &'a Content,
+    error: PhantomData<E>,
+}
+
+impl<'a, E> ContentDeserializer<'a, E> {
+    pub(crate) fn new(content: &'a Content) -> ContentDeserializer<'a, E> {
+        ContentDeserializer {
+            content,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<'_, E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(*v),
+            Content::U64(v) => visitor.visit_u64(*v),
+            Content::I64(v) => visitor.visit_i64(*v),
+            Content::F64(v) => visitor.visit_f64(*v),
+            Content::Char(v) => visitor.visit_char(*v),
+            Content::String(v) => visitor.visit_str(v),
+            Content::Bytes(v) => visitor.visit_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => visitor.visit_newtype_struct(ContentDeserializer::new(v)),
+            Content::Seq(v) => visitor.visit_seq(SeqDeserializer {
+                iter: v.iter(),
+                error: PhantomData,
+            }),
+            Content::Map(v) => visitor.visit_map(MapDeserializer {
+                iter: v.iter(),
+                value: None,
+                error: PhantomData,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Newtype(v) => visitor.visit_newtype_struct(ContentDeserializer::new(v)),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::String(_) => visitor.visit_enum(EnumDeserializer {
+                variant: self.content,
+                value: None,
+                error: PhantomData,
+            }),
+            Content::Map(entries) if entries.len() == 1 => visitor.visit_enum(EnumDeserializer {
+                variant: &entries[0].0,
+                value: Some(&entries[0].1),
+                error: PhantomData,
+            }),
+            _ => Err(E::invalid_type(
+                de::Unexpected::Other("content"),
+                &"an enum",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<'a, E> {
+    iter: core::slice::Iter<'a, Content>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> SeqAccess<'de> for SeqDeserializer<'_, E> {
+    type Error = E;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, E> {
+        match self.iter.next() {
+            Some(content) => seed
+                .deserialize(ContentDeserializer::new(content))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct MapDeserializer<'a, E> {
+    iter: core::slice::Iter<'a, (Content, Content)>,
+    value: Option<&'a Content>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> MapAccess<'de> for MapDeserializer<'_, E> {
+    type Error = E;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, E> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+
+                seed.deserialize(ContentDeserializer::new(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, E> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| E::custom("value is missing"))?;
+
+        seed.deserialize(ContentDeserializer::new(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct EnumDeserializer<'a, E> {
+    variant: &'a Content,
+    value: Option<&'a Content>,
+    error: PhantomData<E>,
+}
+
+impl<'a, 'de, E: de::Error> EnumAccess<'de> for EnumDeserializer<'a, E> {
+    type Error = E;
+    type Variant = VariantDeserializer<'a, E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantDeserializer<'a, E>), E> {
+        let variant = seed.deserialize(ContentDeserializer::new(self.variant))?;
+
+        Ok((
+            variant,
+            VariantDeserializer {
+                value: self.value,
+                error: PhantomData,
+            },
+        ))
+    }
+}
+
+struct VariantDeserializer<'a, E> {
+    value: Option<&'a Content>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> VariantAccess<'de> for VariantDeserializer<'_, E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            Some(value) => <()>::deserialize(ContentDeserializer::new(value)),
+            None => Ok(()),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        match self.value {
+            Some(value) => seed.deserialize(ContentDeserializer::new(value)),
+            None => Err(E::invalid_type(
+                de::Unexpected::UnitVariant,
+                &"a newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, E> {
+        match self.value {
+            Some(value) => ContentDeserializer::new(value).deserialize_any(visitor),
+            None => Err(E::invalid_type(
+                de::Unexpected::UnitVariant,
+                &"a tuple variant",
+            )),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.value {
+            Some(value) => ContentDeserializer::new(value).deserialize_any(visitor),
+            None => Err(E::invalid_type(
+                de::Unexpected::UnitVariant,
+                &"a struct variant",
+            )),
+        }
+    }
+}
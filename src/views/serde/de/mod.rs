@@ -1,3 +1,4 @@
+pub(crate) mod content;
 ///
 // #[allow(missing_docs)] for the module only
 pub mod entities_mut;
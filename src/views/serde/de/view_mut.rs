@@ -2,6 +2,7 @@ use crate::add_component::AddComponent;
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::tracking::Tracking;
+use crate::views::serde::de::content::{ElementSeed, ElementsSeed};
 use crate::views::ViewMut;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -11,7 +12,9 @@ use serde::{Deserialize, Deserializer};
 
 /// Builder to customize [`ViewMut`]'s deserialization format.
 ///
-/// Make sure to match the configuration used when serializing.
+/// Make sure to match the configuration used when serializing.\
+/// Components failing to deserialize fall back to the migrations registered with
+/// [`World::register_component_migrate`](crate::World::register_component_migrate).
 pub struct ViewMutDeserializer<'tmp, 'view, T: Component, Track> {
     #[allow(missing_docs)]
     pub view: &'tmp mut ViewMut<'view, T, Track>,
@@ -91,7 +94,13 @@ where
                             // Ignore the type name
                             map.next_value::<serde::de::IgnoredAny>()?;
                         } else if key == "data" {
-                            data_value = Some(map.next_value::<Vec<(EntityId, T)>>()?);
+                            let migrations = &self.place.view.sparse_set.migrations;
+
+                            data_value = Some(if migrations.is_empty() {
+                                map.next_value::<Vec<(EntityId, T)>>()?
+                            } else {
+                                map.next_value_seed(ElementsSeed { migrations })?
+                            });
                         } else {
                             // Skip unknown fields
                             map.next_value::<serde::de::IgnoredAny>()?;
@@ -140,7 +149,19 @@ where
                 {
                     let override_component = self.place.override_component;
 
-                    while let Some((eid, component)) = seq.next_element::<(EntityId, T)>()? {
+                    loop {
+                        let element = if self.place.view.sparse_set.migrations.is_empty() {
+                            seq.next_element::<(EntityId, T)>()?
+                        } else {
+                            seq.next_element_seed(ElementSeed {
+                                migrations: &self.place.view.sparse_set.migrations,
+                            })?
+                        };
+
+                        let Some((eid, component)) = element else {
+                            break;
+                        };
+
                         if !override_component && self.place.view.contains(eid) {
                             continue;
                         }
@@ -4,13 +4,16 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
-use crate::sparse_set::SparseSet;
+use crate::iter::{IntoIter, Shiperator};
+use crate::sparse_set::{FullRawWindow, SparseSet};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
     DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
-    Modified, RemovalTracking, Tracking, TrackingTimestamp,
+    Modified, ModifiedBitSet, RemovalTracking, Tracking, TrackingTimestamp,
 };
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
 use core::marker::PhantomData;
 use core::ops::Deref;
@@ -74,6 +77,491 @@ impl<'a, T: Component, Track: Tracking> View<'a, T, Track> {
     }
 }
 
+impl<T: Component, Track: Tracking> View<'_, T, Track> {
+    /// Returns `entity`'s component or `default` if it doesn't have one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let fast = world.add_entity(Speed(10.0));
+    /// let idle = world.add_entity(());
+    ///
+    /// let speeds = world.borrow::<View<Speed>>().unwrap();
+    /// let default = Speed(1.0);
+    ///
+    /// assert_eq!(speeds.get_or(fast, &default).0, 10.0);
+    /// assert_eq!(speeds.get_or(idle, &default).0, 1.0);
+    /// ```
+    #[inline]
+    pub fn get_or<'v>(&'v self, entity: EntityId, default: &'v T) -> &'v T {
+        self.sparse_set.private_get(entity).unwrap_or(default)
+    }
+    /// Iterates the ids of all entities with a component in this storage.
+    ///
+    /// The ids are read directly from the storage, in the same order as [`iter`](crate::IntoIter::iter).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Selected;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Selected);
+    /// world.add_entity(());
+    /// let entity2 = world.add_entity(Selected);
+    ///
+    /// let selected = world.borrow::<View<Selected>>().unwrap();
+    ///
+    /// assert_eq!(selected.ids().len(), selected.len());
+    /// assert_eq!(selected.ids().collect::<Vec<_>>(), [entity0, entity2]);
+    /// ```
+    #[inline]
+    pub fn ids(&self) -> impl ExactSizeIterator<Item = EntityId> + DoubleEndedIterator + '_ {
+        self.sparse_set.dense.iter().copied()
+    }
+    /// Returns the entities with a component in both this storage and `other`.
+    ///
+    /// The smaller storage is iterated and the ids are in its order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Component)]
+    /// struct Visible;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Enemy);
+    /// let target = world.add_entity((Enemy, Visible));
+    /// world.add_entity(Visible);
+    ///
+    /// let (enemies, visibles) = world.borrow::<(View<Enemy>, View<Visible>)>().unwrap();
+    ///
+    /// assert_eq!(enemies.intersection_ids(&visibles), [target]);
+    /// ```
+    pub fn intersection_ids<U: Component, OtherTrack>(
+        &self,
+        other: &View<'_, U, OtherTrack>,
+    ) -> Vec<EntityId> {
+        if self.sparse_set.len() <= other.sparse_set.len() {
+            self.ids()
+                .filter(|&entity| other.sparse_set.contains(entity))
+                .collect()
+        } else {
+            other
+                .ids()
+                .filter(|&entity| self.sparse_set.contains(entity))
+                .collect()
+        }
+    }
+    /// Returns the entities with a component in this storage but not in `other`.
+    ///
+    /// The ids are in this storage's order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Component)]
+    /// struct Visible;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let hidden = world.add_entity(Enemy);
+    /// world.add_entity((Enemy, Visible));
+    ///
+    /// let (enemies, visibles) = world.borrow::<(View<Enemy>, View<Visible>)>().unwrap();
+    ///
+    /// assert_eq!(enemies.difference_ids(&visibles), [hidden]);
+    /// ```
+    pub fn difference_ids<U: Component, OtherTrack>(
+        &self,
+        other: &View<'_, U, OtherTrack>,
+    ) -> Vec<EntityId> {
+        self.ids()
+            .filter(|&entity| !other.sparse_set.contains(entity))
+            .collect()
+    }
+    /// Returns the entity with the maximum component according to `compare` and its component.
+    ///
+    /// If several components are equally maximum, the last one is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Score(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Score(1.5));
+    /// let best = world.add_entity(Score(4.0));
+    /// world.add_entity(Score(2.5));
+    ///
+    /// let scores = world.borrow::<View<Score>>().unwrap();
+    ///
+    /// let (entity, score) = scores.max_by(|a, b| a.0.total_cmp(&b.0)).unwrap();
+    ///
+    /// assert_eq!(entity, best);
+    /// assert_eq!(score.0, 4.0);
+    /// ```
+    #[inline]
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> Option<(EntityId, &T)> {
+        self.ids()
+            .zip(&self.sparse_set.data)
+            .max_by(|(_, a), (_, b)| compare(a, b))
+    }
+    /// Returns the entity with the minimum component according to `compare` and its component.
+    ///
+    /// If several components are equally minimum, the first one is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Distance(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Distance(3.0));
+    /// let closest = world.add_entity(Distance(0.5));
+    ///
+    /// let distances = world.borrow::<View<Distance>>().unwrap();
+    ///
+    /// let (entity, _) = distances.min_by(|a, b| a.0.total_cmp(&b.0)).unwrap();
+    ///
+    /// assert_eq!(entity, closest);
+    /// ```
+    #[inline]
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> Option<(EntityId, &T)> {
+        self.ids()
+            .zip(&self.sparse_set.data)
+            .min_by(|(_, a), (_, b)| compare(a, b))
+    }
+    /// Returns the entity whose component gives the maximum value from `f` and its component.
+    ///
+    /// If several components are equally maximum, the last one is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let tank = world.add_entity(Health(300));
+    /// world.add_entity(Health(100));
+    ///
+    /// let healths = world.borrow::<View<Health>>().unwrap();
+    ///
+    /// assert_eq!(healths.max_by_key(|health| health.0).unwrap().0, tank);
+    /// ```
+    #[inline]
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<(EntityId, &T)> {
+        self.ids()
+            .zip(&self.sparse_set.data)
+            .max_by_key(|(_, component)| f(component))
+    }
+    /// Returns the entity whose component gives the minimum value from `f` and its component.
+    ///
+    /// If several components are equally minimum, the first one is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Health(300));
+    /// let weakest = world.add_entity(Health(100));
+    ///
+    /// let healths = world.borrow::<View<Health>>().unwrap();
+    ///
+    /// assert_eq!(healths.min_by_key(|health| health.0).unwrap().0, weakest);
+    /// ```
+    #[inline]
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<(EntityId, &T)> {
+        self.ids()
+            .zip(&self.sparse_set.data)
+            .min_by_key(|(_, component)| f(component))
+    }
+    /// Returns an iterator over all contiguous windows of `size` components, like [`slice::windows`].
+    ///
+    /// The windows follow the storage's internal order, which depends on insertion, deletion and sort order.\
+    /// Sort the storage first to get windows in a meaningful order.
+    ///
+    /// ### Panics
+    ///
+    /// - `size` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Sample(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Sample(1.0));
+    /// world.add_entity(Sample(3.0));
+    /// world.add_entity(Sample(5.0));
+    ///
+    /// let samples = world.borrow::<View<Sample>>().unwrap();
+    ///
+    /// let smoothed: Vec<f32> = samples
+    ///     .windows(2)
+    ///     .map(|window| (window[0].0 + window[1].0) / 2.0)
+    ///     .collect();
+    ///
+    /// assert_eq!(smoothed, [2.0, 4.0]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.sparse_set.data.windows(size)
+    }
+    /// Returns an iterator over the components in chunks of `size`, with the ids of their entities.\
+    /// The last chunk is shorter if the number of components isn't a multiple of `size`.
+    ///
+    /// Chunks follow the storage's internal order, which depends on insertion, deletion and sort order.
+    ///
+    /// ### Panics
+    ///
+    /// - `size` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Vertex(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let ids = (0..5)
+    ///     .map(|i| world.add_entity(Vertex(i as f32)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let vertices = world.borrow::<View<Vertex>>().unwrap();
+    ///
+    /// let mut chunks = vertices.chunks(2);
+    ///
+    /// let (chunk_ids, chunk) = chunks.next().unwrap();
+    /// assert_eq!(chunk_ids, &ids[..2]);
+    /// assert_eq!(chunk.len(), 2);
+    ///
+    /// assert_eq!(chunks.next().unwrap().1.len(), 2);
+    /// assert_eq!(chunks.next().unwrap().1.len(), 1);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn chunks(
+        &self,
+        size: usize,
+    ) -> core::iter::Zip<core::slice::Chunks<'_, EntityId>, core::slice::Chunks<'_, T>> {
+        self.sparse_set
+            .dense
+            .chunks(size)
+            .zip(self.sparse_set.data.chunks(size))
+    }
+    /// Returns an iterator over every `step`th component with the id of its entity, starting with the first one.\
+    /// Visits the components at positions `0`, `step`, `2 * step`, ... of the storage.
+    ///
+    /// Positions follow the storage's internal order, which depends on insertion, deletion and sort order.
+    ///
+    /// ### Panics
+    ///
+    /// - `step` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Particle(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let ids = (0..5)
+    ///     .map(|i| world.add_entity(Particle(i)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let particles = world.borrow::<View<Particle>>().unwrap();
+    ///
+    /// let sampled = particles
+    ///     .sample(2)
+    ///     .map(|(id, particle)| (id, particle.0))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(sampled, [(ids[0], 0), (ids[2], 2), (ids[4], 4)]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn sample(&self, step: usize) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        self.sparse_set
+            .dense
+            .iter()
+            .zip(&self.sparse_set.data)
+            .step_by(step)
+            .map(|(&entity, component)| (entity, component))
+    }
+}
+
+impl<T: Component + Clone, Track: Tracking> View<'_, T, Track> {
+    /// Returns a clone of `entity`'s component or `None` if it doesn't have one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Name(String);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Name("Ferris".to_string()));
+    ///
+    /// let name = world.borrow::<View<Name>>().unwrap().get_cloned(entity);
+    ///
+    /// assert_eq!(name, Some(Name("Ferris".to_string())));
+    /// ```
+    #[inline]
+    pub fn get_cloned(&self, entity: EntityId) -> Option<T> {
+        self.sparse_set.private_get(entity).cloned()
+    }
+}
+
+impl<T: Component + Copy, Track: Tracking> View<'_, T, Track> {
+    /// Returns a copy of `entity`'s component or `None` if it doesn't have one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Clone, Copy, Debug, PartialEq)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let fast = world.add_entity(Speed(10.0));
+    /// let idle = world.add_entity(());
+    ///
+    /// let speeds = world.borrow::<View<Speed>>().unwrap();
+    ///
+    /// assert_eq!(speeds.get_copied(fast), Some(Speed(10.0)));
+    /// assert_eq!(speeds.get_copied(idle), None);
+    /// ```
+    #[inline]
+    pub fn get_copied(&self, entity: EntityId) -> Option<T> {
+        self.sparse_set.private_get(entity).copied()
+    }
+    /// Returns a copy of `entity`'s component or `default` if it doesn't have one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Clone, Copy)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let fast = world.add_entity(Speed(10.0));
+    /// let idle = world.add_entity(());
+    ///
+    /// let speeds = world.borrow::<View<Speed>>().unwrap();
+    ///
+    /// assert_eq!(speeds.get_copied_or(fast, Speed(1.0)).0, 10.0);
+    /// assert_eq!(speeds.get_copied_or(idle, Speed(1.0)).0, 1.0);
+    /// ```
+    #[inline]
+    pub fn get_copied_or(&self, entity: EntityId, default: T) -> T {
+        self.sparse_set
+            .private_get(entity)
+            .copied()
+            .unwrap_or(default)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Component, Track: Tracking> View<'_, T, Track> {
+    /// Iterates all components with their id, except the ones of entities in `exclude`.
+    ///
+    /// The storage is iterated in place, checking `exclude` once per component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    /// use std::collections::HashSet;
+    ///
+    /// #[derive(Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let selected = world.add_entity(Name("selected"));
+    /// world.add_entity(Name("other"));
+    ///
+    /// let names = world.borrow::<View<Name>>().unwrap();
+    /// let exclude = HashSet::from([selected]);
+    ///
+    /// let mut iter = names.iter_except(&exclude);
+    /// assert_eq!(iter.next().unwrap().1 .0, "other");
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn iter_except<'e, H: core::hash::BuildHasher>(
+        &'e self,
+        exclude: &'e std::collections::HashSet<EntityId, H>,
+    ) -> impl Iterator<Item = (EntityId, &'e T)> + 'e {
+        self.sparse_set
+            .dense
+            .iter()
+            .zip(&self.sparse_set.data)
+            .filter(move |(entity, _)| !exclude.contains(*entity))
+            .map(|(&entity, component)| (entity, component))
+    }
+}
+
 impl<'a, T: Component, Track> View<'a, T, Track>
 where
     Track: Tracking,
@@ -236,6 +724,46 @@ where
             self.current,
         )
     }
+
+    /// Returns the *modification* flags of this storage as a read-only bitset.\
+    /// Bit indices correspond to dense positions, not to `EntityId`s.\
+    /// Dense positions change when components are added, removed or sorted.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{track, Component, View, ViewMut, World};
+    ///
+    /// struct U32(u32);
+    /// impl Component for U32 {
+    ///     type Tracking = track::Modification;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid0 = world.add_entity(U32(0));
+    /// let eid1 = world.add_entity(U32(1));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     u32s[eid1].0 += 1;
+    /// });
+    ///
+    /// world.run(|u32s: View<U32, track::Modification>| {
+    ///     let bitset = u32s.modified_bitset();
+    ///
+    ///     assert!(!bitset.contains(u32s.index_of(eid0).unwrap()));
+    ///     assert!(bitset.contains(u32s.index_of(eid1).unwrap()));
+    ///     assert_eq!(bitset.to_words(), vec![0b10]);
+    /// });
+    /// ```
+    #[inline]
+    pub fn modified_bitset(&self) -> ModifiedBitSet<'_> {
+        ModifiedBitSet {
+            data: &self.sparse_set.modification_data,
+            last: self.last_modification,
+            current: self.current,
+        }
+    }
 }
 
 impl<Track, T: Component> View<'_, T, Track>
@@ -388,3 +916,37 @@ impl<T: Component, Track: Tracking> core::ops::Index<EntityId> for View<'_, T, T
         self.get(entity).unwrap()
     }
 }
+
+impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoIterator for &'tmp View<'v, T, Track> {
+    type Item = &'tmp T;
+    type IntoIter = Shiperator<FullRawWindow<'tmp, T>>;
+
+    /// Iterates the components of this view, same as `(&view).iter()`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(1));
+    /// world.add_entity(U32(2));
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// let mut sum = 0;
+    /// for u32 in &u32s {
+    ///     sum += u32.0;
+    /// }
+    ///
+    /// assert_eq!(sum, 3);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::iter(self)
+    }
+}
@@ -4,14 +4,17 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
-use crate::r#mut::Mut;
-use crate::sparse_set::{SparseSet, SparseSetDrain};
+use crate::iter::{IntoIter, Shiperator, ShiperatorCaptain, ShiperatorOutput, ShiperatorSailor};
+use crate::r#mut::{Mut, SilentMut};
+use crate::remove::Remove;
+use crate::sparse_set::{FullRawWindowMut, InsertionResult, SparseSet, SparseSetDrain};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
     DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
     Modified, RemovalOrDeletionTracking, RemovalTracking, Tracking, TrackingTimestamp,
 };
+use crate::views::entry::{Entry, OccupiedEntry, VacantEntry};
 use crate::views::view::View;
 use core::fmt;
 use core::marker::PhantomData;
@@ -196,6 +199,117 @@ where
     pub fn drain(&mut self) -> SparseSetDrain<'_, T> {
         self.sparse_set.private_drain(self.current)
     }
+    /// Removes `entity`'s component and returns it, flagging it *removed*.\
+    /// Returns `None` if `entity` doesn't have a component in this storage, including when it's dead.
+    ///
+    /// Same as [`Remove::remove`] without having to import the trait.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// assert_eq!(u32s.take(entity), Some(U32(0)));
+    /// assert_eq!(u32s.take(entity), None);
+    /// ```
+    ///
+    /// [`Remove::remove`]: crate::Remove::remove
+    #[inline]
+    pub fn take(&mut self, entity: EntityId) -> Option<T> {
+        Remove::remove(self, entity)
+    }
+    /// Adds `component` to `entity` and returns the component it replaced, if any.\
+    /// The component is flagged as *modified* if `entity` already had one, *inserted* otherwise.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is dead, a more recent entity with the same index has a component in this storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// assert_eq!(u32s.replace(entity, U32(0)), Ok(None));
+    /// assert_eq!(u32s.replace(entity, U32(1)), Ok(Some(U32(0))));
+    /// assert_eq!(u32s[entity], U32(1));
+    /// ```
+    #[track_caller]
+    pub fn replace(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Result<Option<T>, error::AddComponent> {
+        match self.sparse_set.insert(entity, component, self.current) {
+            InsertionResult::Inserted | InsertionResult::OtherComponentOverride => Ok(None),
+            InsertionResult::ComponentOverride(old_component) => Ok(Some(old_component)),
+            InsertionResult::NotInserted => Err(error::AddComponent::EntityIsNotAlive),
+        }
+    }
+    /// Returns the [`Entry`] of `entity`, to inspect or insert its component in place.
+    ///
+    /// The entry is [`Entry::Dead`] when a more recent entity with the same index has a component in this storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{track, Component, ViewMut, World};
+    ///
+    /// struct Health(u32);
+    /// impl Component for Health {
+    ///     type Tracking = track::All;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    ///
+    /// let mut healths = world.borrow::<ViewMut<Health>>().unwrap();
+    ///
+    /// healths.entry(entity).or_insert(Health(10)).unwrap();
+    /// assert!(healths.is_inserted(entity));
+    ///
+    /// healths
+    ///     .entry(entity)
+    ///     .and_modify(|mut health| health.0 -= 1)
+    ///     .or_insert(Health(10))
+    ///     .unwrap();
+    /// assert_eq!(healths[entity].0, 9);
+    /// ```
+    pub fn entry(&mut self, entity: EntityId) -> Entry<'_, 'a, T, Track> {
+        if self.sparse_set.contains(entity) {
+            Entry::Occupied(OccupiedEntry { view: self, entity })
+        } else if self
+            .sparse_set
+            .sparse
+            .get(entity)
+            .is_some_and(|sparse_entity| {
+                !sparse_entity.is_dead() && sparse_entity.gen() > entity.gen()
+            })
+        {
+            Entry::Dead(entity)
+        } else {
+            Entry::Vacant(VacantEntry { view: self, entity })
+        }
+    }
     /// Applies the given function `f` to the entities `a` and `b`.\
     /// The two entities shouldn't point to the same component.  
     ///
@@ -233,6 +347,169 @@ where
     pub fn retain_mut<F: FnMut(EntityId, Mut<'_, T>) -> bool>(&mut self, f: F) {
         self.sparse_set.private_retain_mut(self.current, f);
     }
+
+    /// Returns a mutable reference to `entity`'s component without flagging it *modified*.\
+    /// Returns `None` if `entity` does not have a component in this storage.
+    ///
+    /// Modification tracking will miss any change made through this reference.\
+    /// Use [`mark_modified`](ViewMut::mark_modified) when the component is actually changed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{track, Component, ViewMut, World};
+    ///
+    /// struct U32(u32);
+    /// impl Component for U32 {
+    ///     type Tracking = track::Modification;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid = world.add_entity(U32(0));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     let component = u32s.get_mut_silent(eid).unwrap();
+    ///
+    ///     if component.0 != 0 {
+    ///         component.0 = 0;
+    ///         u32s.mark_modified(eid);
+    ///     }
+    ///
+    ///     assert!(!u32s.is_modified(eid));
+    /// });
+    /// ```
+    #[inline]
+    pub fn get_mut_silent(&mut self, entity: EntityId) -> Option<&mut T> {
+        let index = self.sparse_set.index_of(entity)?;
+
+        Some(unsafe { self.sparse_set.data.get_unchecked_mut(index) })
+    }
+    /// Returns an iterator over the components in chunks of `size`, with the ids of their entities.\
+    /// The last chunk is shorter if the number of components isn't a multiple of `size`.
+    ///
+    /// If the storage tracks modification, all components are flagged modified by this call.
+    ///
+    /// ### Panics
+    ///
+    /// - `size` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Vertex(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for i in 0..5 {
+    ///     world.add_entity(Vertex(i as f32));
+    /// }
+    ///
+    /// let mut vertices = world.borrow::<ViewMut<Vertex>>().unwrap();
+    ///
+    /// for (_, chunk) in vertices.chunks_mut(2) {
+    ///     for vertex in chunk {
+    ///         vertex.0 *= 2.0;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vertices.as_slice()[4].0, 8.0);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn chunks_mut(
+        &mut self,
+        size: usize,
+    ) -> core::iter::Zip<core::slice::Chunks<'_, EntityId>, core::slice::ChunksMut<'_, T>> {
+        let SparseSet {
+            dense,
+            data,
+            modification_data,
+            is_tracking_modification,
+            ..
+        } = &mut *self.sparse_set;
+
+        if *is_tracking_modification {
+            modification_data.fill(self.current);
+        }
+
+        dense.chunks(size).zip(data.chunks_mut(size))
+    }
+    /// Applies `f` to all components of this storage.
+    ///
+    /// If the storage tracks modification, all components are flagged modified at once,
+    /// instead of one by one like when iterating.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Timer(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Timer(5));
+    /// world.add_entity(Timer(3));
+    ///
+    /// let mut timers = world.borrow::<ViewMut<Timer>>().unwrap();
+    ///
+    /// timers.update_all(|timer| timer.0 = timer.0.saturating_sub(4));
+    ///
+    /// assert_eq!(timers.as_slice()[0].0, 1);
+    /// assert_eq!(timers.as_slice()[1].0, 0);
+    /// ```
+    #[inline]
+    pub fn update_all(&mut self, f: impl FnMut(&mut T)) {
+        self.sparse_set.data.iter_mut().for_each(f);
+
+        if self.sparse_set.is_tracking_modification {
+            self.sparse_set.modification_data.fill(self.current);
+        }
+    }
+    /// Applies `f` to all components of this storage in parallel.
+    ///
+    /// If the storage tracks modification, all components are flagged modified at once,
+    /// instead of one by one like when iterating.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Timer(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for _ in 0..100 {
+    ///     world.add_entity(Timer(5));
+    /// }
+    ///
+    /// let mut timers = world.borrow::<ViewMut<Timer>>().unwrap();
+    ///
+    /// timers.par_update_all(|timer| timer.0 -= 1);
+    ///
+    /// assert!(timers.as_slice().iter().all(|timer| timer.0 == 4));
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn par_update_all(&mut self, f: impl Fn(&mut T) + Send + Sync)
+    where
+        T: Send,
+    {
+        use rayon::prelude::*;
+
+        self.sparse_set.data.par_iter_mut().for_each(f);
+
+        if self.sparse_set.is_tracking_modification {
+            self.sparse_set.modification_data.fill(self.current);
+        }
+    }
 }
 
 impl<'v, Track, T: Component + Default> ViewMut<'v, T, Track>
@@ -303,6 +580,49 @@ where
 
         Some(component)
     }
+    /// Retrieve `entity` component from this storage and from `other`.\
+    /// A component missing from one storage is `None`, the component from the other storage is still returned.
+    ///
+    /// Components are flagged *modified* when they are mutably dereferenced, like with [`Get::get`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Pos(i32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Vel(i32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid = world.add_entity(Pos(0));
+    ///
+    /// let (mut positions, mut velocities) = world.borrow::<(ViewMut<Pos>, ViewMut<Vel>)>().unwrap();
+    ///
+    /// let (pos, vel) = positions.get_disjoint_mut(&mut velocities, eid);
+    ///
+    /// assert!(vel.is_none());
+    /// pos.unwrap().0 += 1;
+    ///
+    /// assert_eq!(positions[eid], Pos(1));
+    /// ```
+    #[inline]
+    pub fn get_disjoint_mut<'a, 'b, 'o, U: Component, TrackU>(
+        &'a mut self,
+        other: &'b mut ViewMut<'o, U, TrackU>,
+        entity: EntityId,
+    ) -> (
+        Option<<&'a mut ViewMut<'v, T, Track> as Get>::Out>,
+        Option<<&'b mut ViewMut<'o, U, TrackU> as Get>::Out>,
+    )
+    where
+        &'b mut ViewMut<'o, U, TrackU>: Get,
+    {
+        (Get::get(self, entity).ok(), Get::get(other, entity).ok())
+    }
 }
 
 impl<Track, T: Component> ViewMut<'_, T, Track>
@@ -395,6 +715,86 @@ where
     pub fn modified_mut(&mut self) -> Modified<&mut Self> {
         Modified(self)
     }
+    /// Flags `entity`'s component as *modified*.\
+    /// Returns `false` if `entity` does not have a component in this storage.
+    #[inline]
+    pub fn mark_modified(&mut self, entity: EntityId) -> bool {
+        if let Some(index) = self.sparse_set.index_of(entity) {
+            self.sparse_set.modification_data[index] = self.current;
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Flags all components of this storage as *modified*.\
+    /// Useful after mutating components with [`get_mut_silent`](ViewMut::get_mut_silent).
+    #[inline]
+    pub fn mark_all_modified(&mut self) {
+        self.sparse_set.modification_data.fill(self.current);
+    }
+    /// Iterates all components of this storage and their entity without flagging them *modified*.
+    ///
+    /// Iterating a `&mut ViewMut` flags a component as soon as it is mutably dereferenced.\
+    /// Components yielded by this iterator are only flagged when [`SilentMut::mark`] is called.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::advanced::SilentMut;
+    /// use shipyard::{track, Component, ViewMut, World};
+    ///
+    /// struct Health(u32);
+    /// impl Component for Health {
+    ///     type Tracking = track::Modification;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let full = world.add_entity(Health(100));
+    /// let hurt = world.add_entity(Health(40));
+    ///
+    /// world.run(|mut healths: ViewMut<Health>| {
+    ///     for (_, mut health) in healths.iter_silent() {
+    ///         let healed = (health.0 + 10).min(100);
+    ///
+    ///         if health.0 != healed {
+    ///             health.0 = healed;
+    ///             SilentMut::mark(&mut health);
+    ///         }
+    ///     }
+    ///
+    ///     assert!(!healths.is_modified(full));
+    ///     assert!(healths.is_modified(hurt));
+    /// });
+    /// ```
+    #[inline]
+    pub fn iter_silent(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (EntityId, SilentMut<'_, T>)> + DoubleEndedIterator {
+        let current = self.current;
+        let SparseSet {
+            dense,
+            data,
+            modification_data,
+            ..
+        } = &mut *self.sparse_set;
+
+        dense
+            .iter()
+            .copied()
+            .zip(data.iter_mut().zip(modification_data.iter_mut()))
+            .map(move |(entity, (data, flag))| {
+                (
+                    entity,
+                    SilentMut {
+                        flag,
+                        current,
+                        data,
+                    },
+                )
+            })
+    }
     /// Removes the *modified* flag on all components of this storage.
     #[inline]
     pub fn clear_all_modified(self) {
@@ -663,3 +1063,40 @@ impl<'a, T: Component, Track> core::ops::IndexMut<EntityId> for ViewMut<'a, T, T
         unsafe { data.get_unchecked_mut(index) }
     }
 }
+
+impl<'tmp, 'v: 'tmp, T: Component, Track> IntoIterator for &'tmp mut ViewMut<'v, T, Track>
+where
+    FullRawWindowMut<'tmp, T, Track>: ShiperatorCaptain + ShiperatorSailor,
+{
+    type Item = <FullRawWindowMut<'tmp, T, Track> as ShiperatorOutput>::Out;
+    type IntoIter = Shiperator<FullRawWindowMut<'tmp, T, Track>>;
+
+    /// Iterates the components of this view, same as `(&mut view).iter()`.
+    ///
+    /// Components are yielded as `&mut T`, or [`Mut<T>`](Mut) when modification is tracked.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(1));
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// for u32 in &mut u32s {
+    ///     u32.0 += 1;
+    /// }
+    ///
+    /// assert_eq!(u32s[entity].0, 2);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::iter(self)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::world::World;
+use core::ops::Deref;
+
+/// Access to the whole [`World`] from inside a system.
+///
+/// Nothing is borrowed up front, storages can be borrowed as needed with [`World::borrow`] or [`World::run`].\
+/// The system is scheduled as if it borrowed [`AllStorages`] exclusively, it never runs at the same time as a system borrowing storages.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Component, View, Workload, World, WorldView};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn dynamic(world: WorldView) {
+///     if let Ok(healths) = world.borrow::<View<Health>>() {
+///         assert_eq!(healths.len(), 1);
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// world.add_entity(Health(100));
+///
+/// Workload::new("Dynamic")
+///     .with_system(dynamic)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Dynamic").unwrap();
+/// ```
+///
+/// [`AllStorages`]: crate::all_storages::AllStorages
+#[derive(Clone, Copy)]
+pub struct WorldView<'a>(pub(crate) &'a World);
+
+impl Deref for WorldView<'_> {
+    type Target = World;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl AsRef<World> for WorldView<'_> {
+    #[inline]
+    fn as_ref(&self) -> &World {
+        self.0
+    }
+}
@@ -3,11 +3,15 @@ mod run_batches;
 
 pub use builder::WorldBuilder;
 
+pub(crate) use run_batches::RunOptions;
+
 use crate::all_storages::{
     AllStorages, CustomStorageAccess, TupleClone, TupleDeleteAny, TupleRetainStorage,
 };
+use crate::arena::ScratchArena;
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::WorldBorrow;
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
@@ -19,23 +23,26 @@ use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
 use crate::memory_usage::WorldMemoryUsage;
 use crate::r#mut::Mut;
 use crate::reserve::BulkEntityIter;
-use crate::scheduler::info::WorkloadsInfo;
-use crate::scheduler::{AsLabel, Batches, Label, Scheduler};
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::scheduler::info::{RunTrace, TraceEvent, WorkloadStats, WorkloadsInfo};
+use crate::scheduler::{AdvanceOnExit, AsLabel, Batches, Label, Scheduler, WorkloadRunner};
+use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TuplePack, TupleRemove};
 use crate::storage::{Storage, StorageId};
 use crate::system::System;
-use crate::tracking::{TrackingTimestamp, TupleTrack};
+use crate::tracking::{TrackingSnapshot, TrackingTimestamp, TupleTrack};
 use crate::views::EntitiesViewMut;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicU64;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
     pub(crate) all_storages: AtomicRefCell<AllStorages>,
     pub(crate) scheduler: AtomicRefCell<Scheduler>,
     counter: Arc<AtomicU64>,
+    tick: Arc<AtomicU64>,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
 }
@@ -55,6 +62,7 @@ impl Default for World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            tick: Arc::new(AtomicU64::new(0)),
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -84,6 +92,43 @@ impl World {
     pub fn new() -> World {
         Default::default()
     }
+    /// Creates an empty `World` holding at most `capacity` entities, and `capacity` components per storage.
+    ///
+    /// Entities are allocated upfront and each component storage is allocated for `capacity` components when it's created.\
+    /// Adding an entity or a component beyond the capacity returns an error with the `_checked` methods and panics otherwise,
+    /// memory is never reallocated.\
+    /// Use [`World::register_with_capacity`] to allocate storages at startup.
+    ///
+    /// The capacity is enforced by the storages themselves, views, bulk insertions and commands panic instead of reallocating.\
+    /// Only the `_checked` methods of [`World`] and [`AllStorages`] report an error.
+    ///
+    /// Use [`World::builder`] to configure a fixed capacity in `no_std` environments.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::with_fixed_capacity(2);
+    /// world.register_with_capacity::<Position>(2);
+    ///
+    /// world.add_entity(Position(0.0, 0.0));
+    /// world.add_entity(Position(1.0, 1.0));
+    ///
+    /// assert_eq!(
+    ///     world.add_entity_checked(Position(2.0, 2.0)),
+    ///     Err(error::AddEntity::EntitiesFull)
+    /// );
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::all_storages::AllStorages
+    #[cfg(feature = "std")]
+    pub fn with_fixed_capacity(capacity: usize) -> World {
+        World::builder().with_fixed_capacity(capacity).build()
+    }
     /// Removes the local [`ThreadPool`](rayon::ThreadPool).
     #[cfg(feature = "parallel")]
     pub fn remove_local_thread_pool(&mut self) -> Option<rayon::ThreadPool> {
@@ -123,6 +168,90 @@ impl World {
     pub fn add_unique<T: Send + Sync + Unique>(&self, component: T) {
         self.all_storages.borrow().unwrap().add_unique(component);
     }
+    /// Adds a new unique storage, unless one of the same type already exists.\
+    /// Unlike [`add_unique`](World::add_unique), an existing unique is never replaced.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// ### Errors
+    ///
+    /// - A `T` unique storage already exists, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Unique, UniqueView, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_distinct_unique(U32(0)).unwrap();
+    ///
+    /// let err = world.add_distinct_unique(U32(1)).unwrap_err();
+    /// assert!(err.name.ends_with("U32"));
+    ///
+    /// assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 0);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::all_storages::AllStorages
+    #[track_caller]
+    pub fn add_distinct_unique<T: Send + Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::UniqueAlreadyExists> {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .add_distinct_unique(component)
+    }
+    /// Adds a new unique storage whose value is created by `init` the first time it is borrowed.\
+    /// Following borrows use the same value. Replaces any previous `T` unique storage or initializer.
+    ///
+    /// Until then the unique storage exists but holds `init`, [`World::add_unique`] and [`World::remove_unique`] replace or remove it.\
+    /// `init` runs at most once and without any lock held, borrowing the storage from another thread while it runs fails with a borrow error.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Assets(Vec<u8>);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_unique_lazy(|| Assets(vec![0; 1024]));
+    ///
+    /// world.borrow::<UniqueViewMut<Assets>>().unwrap().0[0] = 1;
+    ///
+    /// let assets = world.borrow::<UniqueView<Assets>>().unwrap();
+    /// assert_eq!(assets.0[0], 1);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::all_storages::AllStorages
+    #[track_caller]
+    pub fn add_unique_lazy<T: Send + Sync + Unique>(
+        &self,
+        init: impl FnOnce() -> T + Send + Sync + 'static,
+    ) {
+        self.all_storages.borrow().unwrap().add_unique_lazy(init);
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a `!Send` unique storage value, use [`NonSend`] with [`UniqueView`] or [`UniqueViewMut`].
     /// Does nothing if the storage already exists.
@@ -358,7 +487,7 @@ You can use:
 ### Errors
 
 - [AllStorages] borrow failed.
-- Storage borrow failed.
+- Storage borrow failed, this includes borrowing the same storage twice in a tuple with at least one exclusive borrow.
 - Unique storage did not exist.
 
 ### Example
@@ -518,10 +647,14 @@ world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
-        system
+        let result = system
             .run((data,), self)
             .map_err(error::Run::GetStorage)
-            .unwrap()
+            .unwrap();
+
+        self.try_run_observers().unwrap();
+
+        result
     }
     #[doc = "Borrows the requested storages, runs the function and evaluates to the function's return value.
 
@@ -647,10 +780,64 @@ let i = world.run(sys1);
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
-        system
+        let result = system
             .run((), self)
             .map_err(error::Run::GetStorage)
-            .unwrap()
+            .unwrap();
+
+        self.try_run_observers().unwrap();
+
+        result
+    }
+    /// Same as [`World::run`] but returns an error instead of panicking when a storage can't be borrowed.\
+    /// The system's return value is passed through on success.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (exclusive) when requesting [`AllStoragesViewMut`]
+    /// - [`AllStorages`] (shared) + storage (exclusive or shared) for all other views
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    /// - Unique storage did not exist.
+    /// - Observers kept triggering each other, see [`error::Observe::DepthLimit`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// assert!(matches!(
+    ///     world.try_run(|_: ViewMut<U32>| {}),
+    ///     Err(error::Run::GetStorage(_))
+    /// ));
+    ///
+    /// drop(u32s);
+    ///
+    /// assert_eq!(world.try_run(|u32s: ViewMut<U32>| u32s.len()).unwrap(), 0);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::all_storages::AllStorages
+    /// [`AllStoragesViewMut`]: crate::AllStoragesViewMut
+    pub fn try_run<B, S: System<(), B>>(&self, system: S) -> Result<S::Return, error::Run> {
+        #[cfg(feature = "tracing")]
+        let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
+        #[cfg(feature = "tracing")]
+        let _system_span = system_span.enter();
+
+        let result = system.run((), self).map_err(error::Run::GetStorage)?;
+
+        self.try_run_observers()?;
+
+        Ok(result)
     }
     /// Modifies the current default workload to `name`.
     ///
@@ -692,6 +879,8 @@ let i = world.run(sys1);
     }
     /// Runs the `name` workload.
     ///
+    /// Commands recorded by its systems are applied at each [`Workload::flush`](crate::Workload::flush) and once all systems ran.
+    ///
     /// ### Borrows
     ///
     /// - Scheduler (shared)
@@ -701,6 +890,7 @@ let i = world.run(sys1);
     ///
     /// - Scheduler borrow failed.
     /// - Workload did not exist.
+    /// - Workload is already running.
     /// - Storage borrow failed.
     /// - User error returned by system.
     pub fn run_workload<T>(&self, label: impl AsLabel<T>) -> Result<(), error::RunWorkload> {
@@ -717,63 +907,69 @@ let i = world.run(sys1);
             &scheduler.system_names,
             batches,
             &*label,
+            RunOptions::default(),
         )
     }
-    /// Returns `true` if the world contains the `name` workload.
+    /// Runs the `a` workload if `condition` returns `true`, the `b` workload otherwise.
+    ///
+    /// `condition` is evaluated once, before running the workload.
     ///
     /// ### Borrows
     ///
     /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
     ///
-    /// ### Panics
+    /// ### Errors
     ///
     /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
     ///
     /// ### Example
     /// ```
-    /// use shipyard::{Workload, World};
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique, PartialEq, Eq)]
+    /// enum State {
+    ///     Menu,
+    ///     Playing,
+    /// }
+    ///
+    /// fn start(mut state: UniqueViewMut<State>) {
+    ///     *state = State::Playing;
+    /// }
+    /// fn play() {}
     ///
     /// let world = World::new();
     ///
-    /// Workload::new("foo").add_to_world(&world).unwrap();
+    /// world.add_unique(State::Menu);
     ///
-    /// assert!(world.contains_workload("foo"));
-    /// assert!(!world.contains_workload("bar"));
+    /// Workload::new("Menu").with_system(start).add_to_world(&world).unwrap();
+    /// Workload::new("Playing").with_system(play).add_to_world(&world).unwrap();
+    ///
+    /// let in_menu = |world: &World| *world.borrow::<UniqueView<State>>().unwrap() == State::Menu;
+    ///
+    /// world.run_workload_branch(in_menu, "Menu", "Playing").unwrap();
+    /// assert!(*world.borrow::<UniqueView<State>>().unwrap() == State::Playing);
     /// ```
-    #[track_caller]
-    pub fn contains_workload<T>(&self, name: impl AsLabel<T>) -> bool {
-        let label = name.as_label();
-
-        self.scheduler.borrow().unwrap().contains_workload(&*label)
-    }
-    #[allow(clippy::type_complexity)]
-    pub(crate) fn run_batches(
+    pub fn run_workload_branch<T, U>(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
-        system_names: &[Box<dyn Label>],
-        batches: &Batches,
-        workload_name: &dyn Label,
+        condition: impl FnOnce(&World) -> bool,
+        a: impl AsLabel<T>,
+        b: impl AsLabel<U>,
     ) -> Result<(), error::RunWorkload> {
-        if let Some(run_if) = &batches.workload_run_if {
-            if !run_if
-                .run(self)
-                .map_err(|err| error::RunWorkload::Run((workload_name.dyn_clone(), err)))?
-            {
-                return Ok(());
-            }
-        }
-
-        #[cfg(feature = "parallel")]
-        {
-            self.run_batches_parallel(systems, system_names, batches, workload_name)
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+        if condition(self) {
+            self.run_workload(a)
+        } else {
+            self.run_workload(b)
         }
     }
-    /// Run the default workload if there is one.
+    /// Runs the `name` workload and returns how parallel it is and how many systems ran.
+    ///
+    /// The batch structure is computed when the workload is added to the `World`,
+    /// systems skipped by their run if are not counted in [`WorkloadStats::systems_run`].
     ///
     /// ### Borrows
     ///
@@ -783,89 +979,855 @@ let i = world.run(sys1);
     /// ### Errors
     ///
     /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
     /// - Storage borrow failed.
     /// - User error returned by system.
-    pub fn run_default_workload(&self) -> Result<(), error::RunWorkload> {
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    /// #[derive(Component)]
+    /// struct Velocity;
+    ///
+    /// fn read1(_: View<Position>) {}
+    /// fn read2(_: View<Position>) {}
+    /// fn write(_: ViewMut<Position>, _: View<Velocity>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("")
+    ///     .with_system(read1)
+    ///     .with_system(read2)
+    ///     .with_system(write)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let stats = world.run_workload_stats("").unwrap();
+    ///
+    /// assert_eq!(stats.batches, 2);
+    /// assert_eq!(stats.max_batch_width, 2);
+    /// assert_eq!(stats.systems, 3);
+    /// assert_eq!(stats.critical_path, 2);
+    /// assert_eq!(stats.systems_run, 3);
+    /// ```
+    pub fn run_workload_stats<T>(
+        &self,
+        label: impl AsLabel<T>,
+    ) -> Result<WorkloadStats, error::RunWorkload> {
         let scheduler = self
             .scheduler
             .borrow()
             .map_err(|_| error::RunWorkload::Scheduler)?;
 
-        if !scheduler.is_empty() {
-            self.run_batches(
-                &scheduler.systems,
-                &scheduler.system_names,
-                scheduler.default_workload(),
-                &scheduler.default,
-            )?
-        }
-        Ok(())
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            RunOptions::default(),
+        )?;
+
+        Ok(batches.stats())
     }
-    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// Runs the `name` workload with reproducible ordering.
     ///
-    /// ### Errors
-    ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
-        self.all_storages.borrow()
-    }
-    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// Systems run one at a time on the current thread, always in the same order.\
+    /// This order respects the workload's batches and `before`/`after` constraints, borrows are checked the same way.\
+    /// Meant for debugging and tests relying on the order of side effects, use [`World::run_workload`] otherwise.
     ///
-    /// ### Errors
+    /// ### Borrows
     ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
-        self.all_storages.borrow_mut()
-    }
-    /// Inserts a custom storage to the `World`.
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
     ///
     /// ### Errors
     ///
-    /// - `AllStorages` is already borrowed exclusively.
-    pub fn add_custom_storage<S: 'static + Storage + Send + Sync>(
-        &self,
-        storage_id: StorageId,
-        storage: S,
-    ) -> Result<(), error::Borrow> {
-        let _ = self
-            .all_storages
-            .borrow()?
-            .custom_storage_or_insert_by_id(storage_id, || storage);
-
-        Ok(())
-    }
-
-    /// Increments the current tracking cycle and returns the previous value.
-    #[inline]
-    pub(crate) fn get_current(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(
-            self.counter
-                .fetch_add(1, core::sync::atomic::Ordering::Acquire),
-        )
-    }
-
-    /// Returns a timestamp used to clear tracking information.
-    pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(self.counter.load(core::sync::atomic::Ordering::Acquire))
-    }
-}
-
-impl World {
-    /// Creates a new entity with the components passed as argument and returns its `EntityId`.
-    /// `component` must always be a tuple, even for a single component.
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
     ///
     /// ### Example
-    ///
     /// ```
-    /// use shipyard::{Component, World};
+    /// use shipyard::{Workload, World};
+    /// use std::sync::Mutex;
     ///
-    /// #[derive(Component)]
-    /// struct U32(u32);
+    /// static LOG: Mutex<Vec<u32>> = Mutex::new(Vec::new());
     ///
-    /// #[derive(Component)]
-    /// struct USIZE(usize);
+    /// fn sys1() {
+    ///     LOG.lock().unwrap().push(1);
+    /// }
+    /// fn sys2() {
+    ///     LOG.lock().unwrap().push(2);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload_deterministic("").unwrap();
+    ///
+    /// assert_eq!(*LOG.lock().unwrap(), vec![1, 2]);
+    /// ```
+    pub fn run_workload_deterministic<T>(
+        &self,
+        label: impl AsLabel<T>,
+    ) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            RunOptions {
+                deterministic: true,
+                ..RunOptions::default()
+            },
+        )
+    }
+    /// Runs the `name` workload, stopping between batches once `cancel` is set.
+    ///
+    /// `cancel` is checked before each batch, systems already running are never interrupted.\
+    /// Keep a clone of `cancel` to set it from another thread or from a system.\
+    /// Commands pushed by the systems that ran are not applied when the workload is cancelled.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Workload was cancelled.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Workload, World};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// fn never_runs() {
+    ///     panic!("the workload was cancelled");
+    /// }
+    ///
+    /// let cancel = Arc::new(AtomicBool::new(false));
+    /// let system_cancel = cancel.clone();
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Background")
+    ///     .with_system(move || system_cancel.store(true, Ordering::Relaxed))
+    ///     .with_barrier()
+    ///     .with_system(never_runs)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     world.run_workload_cancelable("Background", cancel),
+    ///     Err(error::RunWorkload::Cancelled)
+    /// ));
+    /// ```
+    pub fn run_workload_cancelable<T>(
+        &self,
+        label: impl AsLabel<T>,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            RunOptions {
+                cancel: Some(&cancel),
+                ..RunOptions::default()
+            },
+        )
+    }
+    /// Runs the `name` workload with reproducible ordering and records which systems ran or were skipped.
+    ///
+    /// Systems always run sequentially, the same way as [`World::run_workload_deterministic`], even with the `parallel` feature.\
+    /// The trace reflects this sequential order, not the schedule [`World::run_workload`] would follow on multiple threads.\
+    /// Comparing the returned [`RunTrace`] with the one of a previous run detects scheduling or run if changes.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::info::TraceEvent;
+    /// use shipyard::scheduler::SystemModificator;
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn physics() {}
+    /// fn render() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(physics)
+    ///     .with_system(render.run_if(|| false))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let trace = world.run_workload_traced("Frame").unwrap();
+    ///
+    /// assert_eq!(trace.workload, "Frame");
+    /// assert!(matches!(trace.events[0], TraceEvent::Ran(_)));
+    /// assert!(matches!(trace.events[1], TraceEvent::SkippedByRunIf(_)));
+    ///
+    /// assert_eq!(world.run_workload_traced("Frame").unwrap(), trace);
+    /// ```
+    pub fn run_workload_traced<T>(
+        &self,
+        label: impl AsLabel<T>,
+    ) -> Result<RunTrace, error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        let mut trace = RunTrace {
+            workload: format!("{:?}", label),
+            events: Vec::new(),
+        };
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            RunOptions {
+                trace: Some(&mut trace),
+                ..RunOptions::default()
+            },
+        )?;
+
+        Ok(trace)
+    }
+    /// Returns a [`WorkloadRunner`] to run the `name` workload one system at a time.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let mut runner = world.workload_runner("foo").unwrap();
+    ///
+    /// let mut steps = 0;
+    /// while let Some(_system) = runner.step(&world).unwrap() {
+    ///     steps += 1;
+    /// }
+    ///
+    /// assert_eq!(steps, 2);
+    /// ```
+    pub fn workload_runner<T>(
+        &self,
+        name: impl AsLabel<T>,
+    ) -> Result<WorkloadRunner, error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = name.as_label();
+
+        if scheduler.contains_workload(&*label) {
+            Ok(WorkloadRunner::new(label))
+        } else {
+            Err(error::RunWorkload::MissingWorkload)
+        }
+    }
+    /// Runs the `name` workload one system at a time until all systems ran or `budget` is exceeded.\
+    /// Returns the systems that ran, in order.
+    ///
+    /// The budget is checked between systems, a system is never interrupted.\
+    /// Systems are run in the same order as [`World::run_workload_deterministic`].\
+    /// Commands recorded by the systems that ran are applied before returning.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Workload, World};
+    /// use std::time::Duration;
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let ran = world
+    ///     .run_workload_budgeted("foo", Duration::from_secs(60))
+    ///     .unwrap();
+    /// assert_eq!(ran.len(), 2);
+    ///
+    /// let ran = world.run_workload_budgeted("foo", Duration::ZERO).unwrap();
+    /// assert!(ran.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn run_workload_budgeted<T>(
+        &self,
+        name: impl AsLabel<T>,
+        budget: std::time::Duration,
+    ) -> Result<alloc::vec::Vec<Box<dyn Label>>, error::RunWorkload> {
+        let start = std::time::Instant::now();
+        let mut runner = self.workload_runner(name)?;
+        let mut ran = alloc::vec::Vec::new();
+
+        while start.elapsed() < budget {
+            match runner.step(self)? {
+                Some(system) => ran.push(system),
+                None => break,
+            }
+        }
+
+        self.try_apply_commands()?;
+
+        Ok(ran)
+    }
+    /// Runs the `name` workload one system at a time and returns the number of bytes each system allocated, in order.
+    ///
+    /// Systems are run in the same order as [`World::run_workload_deterministic`], on the current thread.\
+    /// A system's count includes its run if condition and the commands applied right after it.\
+    /// Systems skipped by their run if condition are not part of the result.
+    ///
+    /// Allocations are only counted when [`CountingAllocator`] is the global allocator, all counts are `0` otherwise.\
+    /// Allocations made by other threads while the workload runs are counted too, including rayon's worker threads.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::alloc_profile::CountingAllocator;
+    /// use shipyard::{Workload, World};
+    /// use std::alloc::System;
+    ///
+    /// #[global_allocator]
+    /// static ALLOCATOR: CountingAllocator<System> = CountingAllocator(System);
+    ///
+    /// fn no_alloc() {}
+    /// fn alloc() {
+    ///     let _ = std::hint::black_box(vec![0u8; 64]);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo")
+    ///     .with_system(no_alloc)
+    ///     .with_system(alloc)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let profile = world.run_workload_alloc_profile("foo").unwrap();
+    ///
+    /// assert_eq!(profile.len(), 2);
+    /// assert!(profile[1].1 >= 64);
+    /// ```
+    ///
+    /// [`CountingAllocator`]: crate::alloc_profile::CountingAllocator
+    #[cfg(feature = "alloc_profile")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc_profile")))]
+    pub fn run_workload_alloc_profile<T>(
+        &self,
+        name: impl AsLabel<T>,
+    ) -> Result<alloc::vec::Vec<(Box<dyn Label>, usize)>, error::RunWorkload> {
+        use crate::alloc_profile::ProfiledRun;
+
+        let mut runner = self.workload_runner(name)?;
+        let mut profile = alloc::vec::Vec::new();
+
+        let profiled_run = ProfiledRun::start();
+
+        loop {
+            let before = profiled_run.allocated_bytes();
+
+            // The system's name is cloned after measuring so it doesn't count towards the system
+            match runner.step_then(self, || profiled_run.allocated_bytes().wrapping_sub(before))? {
+                Some(system_profile) => profile.push(system_profile),
+                None => break,
+            }
+        }
+
+        Ok(profile)
+    }
+    /// Returns `true` if the world contains the `name` workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo").add_to_world(&world).unwrap();
+    ///
+    /// assert!(world.contains_workload("foo"));
+    /// assert!(!world.contains_workload("bar"));
+    /// ```
+    #[track_caller]
+    pub fn contains_workload<T>(&self, name: impl AsLabel<T>) -> bool {
+        let label = name.as_label();
+
+        self.scheduler.borrow().unwrap().contains_workload(&*label)
+    }
+    /// Returns the `World`'s tick, the number of workload runs that ended on this `World`.\
+    /// It goes up by one at the end of each run, whether it finished or stopped early, including nested runs.
+    ///
+    /// [`SystemModificator::every`](crate::scheduler::SystemModificator::every) uses it.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo").add_to_world(&world).unwrap();
+    ///
+    /// assert_eq!(world.tick(), 0);
+    ///
+    /// world.run_workload("foo").unwrap();
+    /// world.run_workload("foo").unwrap();
+    ///
+    /// assert_eq!(world.tick(), 2);
+    /// ```
+    pub fn tick(&self) -> u64 {
+        self.tick.load(Ordering::Relaxed)
+    }
+    /// Returns the system that stopped the last run of the `name` workload and its error.\
+    /// Returns `None` if the workload does not exist or its last run didn't fail.
+    ///
+    /// The error is cleared at the start of each run.\
+    /// Custom errors are stored as their message.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Workload, World};
+    ///
+    /// fn fail() -> Result<(), error::MissingComponent> {
+    ///     Err(error::MissingComponent {
+    ///         id: shipyard::EntityId::dead(),
+    ///         name: "U32",
+    ///     })
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo")
+    ///     .with_try_system(fail)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(world.last_workload_error("foo").is_none());
+    ///
+    /// assert!(world.run_workload("foo").is_err());
+    ///
+    /// let (_system, err) = world.last_workload_error("foo").unwrap();
+    /// assert!(matches!(err, error::Run::Custom(_)));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[track_caller]
+    pub fn last_workload_error<T>(
+        &self,
+        name: impl AsLabel<T>,
+    ) -> Option<(Box<dyn Label>, error::Run)> {
+        let label = name.as_label();
+
+        self.scheduler
+            .borrow()
+            .unwrap()
+            .workloads
+            .get(&*label)?
+            .last_error()
+    }
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn run_batches(
+        &self,
+        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        system_names: &[Box<dyn Label>],
+        batches: &Batches,
+        workload_name: &dyn Label,
+        options: RunOptions<'_>,
+    ) -> Result<(), error::RunWorkload> {
+        let _running = batches
+            .start_running()
+            .ok_or_else(|| error::RunWorkload::Reentrant(workload_name.dyn_clone()))?;
+
+        let _advance = self.start_run(batches);
+
+        let result =
+            self.run_started_batches(systems, system_names, batches, workload_name, options);
+
+        if let Err(error::RunWorkload::Run(run_system)) = &result {
+            batches.set_last_error(Some((
+                run_system.system.clone(),
+                run_system.error.duplicate(),
+            )));
+        }
+
+        result
+    }
+    /// Clears what the previous run of `batches` left: its error, its stats and the [`Arena`](crate::Arena).\
+    /// Called by every run path once the workload has been flagged as running,
+    /// the returned guard has to be kept until the end of the run.
+    pub(crate) fn start_run(&self, batches: &Batches) -> AdvanceOnExit {
+        batches.set_last_error(None);
+        batches.systems_run.store(0, Ordering::Relaxed);
+        self.reset_arena();
+
+        batches.advance_on_exit(self.tick.clone())
+    }
+    /// Makes the memory of the [`Arena`](crate::Arena) available again.\
+    /// Does nothing if the arena is borrowed.
+    fn reset_arena(&self) {
+        if let Ok(all_storages) = self.all_storages.borrow() {
+            if let Ok(mut arena) = all_storages.custom_storage_mut::<ScratchArena>() {
+                arena.reset();
+            }
+        }
+    }
+    /// Runs `batches` once they have been flagged as running.
+    #[allow(clippy::type_complexity)]
+    fn run_started_batches(
+        &self,
+        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        system_names: &[Box<dyn Label>],
+        batches: &Batches,
+        workload_name: &dyn Label,
+        mut options: RunOptions<'_>,
+    ) -> Result<(), error::RunWorkload> {
+        if let Some(run_if) = &batches.workload_run_if {
+            if !run_if.run(self).map_err(|err| {
+                error::RunWorkload::Run(error::RunSystem::new(
+                    workload_name,
+                    workload_name.dyn_clone(),
+                    err,
+                ))
+            })? {
+                if let Some(trace) = &mut options.trace {
+                    trace.events.push(TraceEvent::WorkloadSkipped);
+                }
+
+                return Ok(());
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            // Traces are only recorded sequentially, the order of a parallel run isn't reproducible
+            if options.deterministic || options.trace.is_some() {
+                self.run_batches_sequential(systems, system_names, batches, workload_name, options)
+            } else {
+                self.run_batches_parallel(systems, system_names, batches, workload_name, options)
+            }
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.run_batches_sequential(systems, system_names, batches, workload_name, options)
+        }
+    }
+    /// Run the default workload if there is one.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    pub fn run_default_workload(&self) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        if !scheduler.is_empty() {
+            self.run_batches(
+                &scheduler.systems,
+                &scheduler.system_names,
+                scheduler.default_workload(),
+                &scheduler.default,
+                RunOptions::default(),
+            )?
+        }
+        Ok(())
+    }
+    /// Runs the `name` workload on all `worlds`, each `World` on a different thread.\
+    /// Returns the result of each `World`, in the same order as `worlds`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared) of each `World`
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// Per `World`:
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload is already running.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// fn increment(mut u32s: ViewMut<U32>) {
+    ///     for mut i in (&mut u32s).iter() {
+    ///         i.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let worlds = [World::new(), World::new()];
+    ///
+    /// for world in &worlds {
+    ///     world.add_entity(U32(0));
+    ///     Workload::new("Tick")
+    ///         .with_system(increment)
+    ///         .add_to_world(world)
+    ///         .unwrap();
+    /// }
+    ///
+    /// for result in World::run_workloads_parallel(&worlds, "Tick") {
+    ///     result.unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn run_workloads_parallel<T>(
+        worlds: &[World],
+        name: impl AsLabel<T>,
+    ) -> alloc::vec::Vec<Result<(), error::RunWorkload>> {
+        use rayon::prelude::*;
+
+        let label = name.as_label();
+
+        worlds
+            .par_iter()
+            .map(|world| world.run_workload(label.clone()))
+            .collect()
+    }
+    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
+        self.all_storages.borrow()
+    }
+    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
+        self.all_storages.borrow_mut()
+    }
+    /// Runs `f` with exclusive access to `AllStorages` and returns its result.
+    ///
+    /// Useful for one-off bulk operations without defining a system.\
+    /// `f` can freely add or remove storages and entities.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let (kept, deleted) = world.edit(|all_storages| {
+    ///     let kept = all_storages.add_entity(Health(10));
+    ///     let deleted = all_storages.add_entity(Health(20));
+    ///     all_storages.delete_entity(deleted);
+    ///
+    ///     (kept, deleted)
+    /// });
+    ///
+    /// assert_eq!(world.get::<&Health>(kept).unwrap().0, 10);
+    /// assert!(!world.is_entity_alive(deleted));
+    /// ```
+    pub fn edit<R>(&mut self, f: impl FnOnce(&mut AllStorages) -> R) -> R {
+        let all_storages = self.all_storages.get_mut();
+        let result = f(all_storages);
+
+        all_storages.dispatch_observers();
+
+        result
+    }
+    /// Inserts a custom storage to the `World`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed exclusively.
+    pub fn add_custom_storage<S: 'static + Storage + Send + Sync>(
+        &self,
+        storage_id: StorageId,
+        storage: S,
+    ) -> Result<(), error::Borrow> {
+        let _ = self
+            .all_storages
+            .borrow()?
+            .custom_storage_or_insert_by_id(storage_id, || storage);
+
+        Ok(())
+    }
+
+    /// Increments the current tracking cycle and returns the previous value.
+    #[inline]
+    pub(crate) fn get_current(&self) -> TrackingTimestamp {
+        TrackingTimestamp::new(
+            self.counter
+                .fetch_add(1, core::sync::atomic::Ordering::Acquire),
+        )
+    }
+
+    /// Returns a timestamp used to clear tracking information.
+    pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
+        TrackingTimestamp::new(self.counter.load(core::sync::atomic::Ordering::Acquire))
+    }
+}
+
+impl World {
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and is full, use [`add_entity_checked`](World::add_entity_checked) to get an error instead.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
     ///
     /// let mut world = World::new();
     ///
@@ -877,9 +1839,51 @@ impl World {
     pub fn add_entity<C: TupleAddComponent>(&mut self, component: C) -> EntityId {
         self.all_storages.get_mut().add_entity(component)
     }
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Same as [`add_entity`](World::add_entity) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - The `World` has a fixed capacity and all entity indices are in use, `component` is dropped.
+    /// - The `World` has a fixed capacity and one of the storages is full, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::with_fixed_capacity(1);
+    ///
+    /// let entity = world.add_entity_checked(U32(0)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.add_entity_checked(U32(1)),
+    ///     Err(error::AddEntity::EntitiesFull)
+    /// );
+    ///
+    /// world.delete_entity(entity);
+    /// assert!(world.add_entity_checked(U32(1)).is_ok());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn add_entity_checked<C: TupleAddComponent>(
+        &mut self,
+        component: C,
+    ) -> Result<EntityId, error::AddEntity> {
+        self.all_storages.get_mut().add_entity_checked(component)
+    }
     /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.
     /// `source` must always yield a tuple, even for a single component.
     ///
+    /// ### Panics
+    ///
+    /// - The `World` has a fixed capacity and there isn't enough room for all the new entities or their components.
+    ///
     /// ### Example
     ///
     /// ```
@@ -906,7 +1910,8 @@ impl World {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive, use [`add_component_checked`](World::add_component_checked) to get an error instead.
+    /// - The `World` has a fixed capacity and one of the storages is full.
     ///
     /// ### Example
     ///
@@ -933,6 +1938,46 @@ impl World {
     pub fn add_component<C: TupleAddComponent>(&mut self, entity: EntityId, component: C) {
         self.all_storages.get_mut().add_component(entity, component)
     }
+    /// Adds components to an existing entity.\
+    /// If the entity already owned a component it will be replaced.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Same as [`add_component`](World::add_component) but returns an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive, `component` is dropped.
+    /// - The `World` has a fixed capacity and one of the storages is full, `component` is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(
+    ///     world.add_component_checked(entity, (U32(0),)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn add_component_checked<C: TupleAddComponent>(
+        &mut self,
+        entity: EntityId,
+        component: C,
+    ) -> Result<(), error::AddComponent> {
+        self.all_storages
+            .get_mut()
+            .add_component_checked(entity, component)
+    }
     /// Deletes components from an entity. As opposed to `remove`, `delete` doesn't return anything.
     /// `C` must always be a tuple, even for a single component.
     ///
@@ -958,8 +2003,9 @@ impl World {
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
         self.all_storages.get_mut().delete_component::<C>(entity)
     }
-    /// Removes components from an entity.
-    /// `C` must always be a tuple, even for a single component.
+    /// Removes components from an entity.\
+    /// `C` can be a single component or a tuple of components.\
+    /// Components the entity doesn't have are returned as `None`.
     ///
     /// ### Example
     ///
@@ -969,7 +2015,7 @@ impl World {
     /// #[derive(Component, Debug, PartialEq, Eq)]
     /// struct U32(u32);
     ///
-    /// #[derive(Component)]
+    /// #[derive(Component, Debug, PartialEq, Eq)]
     /// struct USIZE(usize);
     ///
     /// let mut world = World::new();
@@ -978,12 +2024,62 @@ impl World {
     ///
     /// let (i,) = world.remove::<(U32,)>(entity);
     /// assert_eq!(i, Some(U32(0)));
+    ///
+    /// let (i, j) = world.remove::<(U32, USIZE)>(entity);
+    /// assert_eq!(i, None);
+    /// assert_eq!(j, Some(USIZE(1)));
+    ///
+    /// assert_eq!(world.remove::<USIZE>(entity), None);
     /// ```
     #[inline]
     #[track_caller]
     pub fn remove<C: TupleRemove>(&mut self, entity: EntityId) -> C::Out {
         self.all_storages.get_mut().remove::<C>(entity)
     }
+    /// Reorders the storages in `C` so components of entities present in all of them are at the start, in the same order.\
+    /// See [`AllStorages::tight_pack`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0), USIZE(0)));
+    ///
+    /// world.tight_pack::<(U32, USIZE)>();
+    /// assert!(world.is_packed::<(U32, USIZE)>());
+    ///
+    /// world.remove::<USIZE>(entity);
+    /// assert!(!world.is_packed::<(U32, USIZE)>());
+    /// ```
+    #[inline]
+    pub fn tight_pack<C: TuplePack>(&mut self) {
+        self.all_storages.get_mut().tight_pack::<C>();
+    }
+    /// Returns `true` if the storages in `C` are still packed together.\
+    /// Returns `false` if one of the storages doesn't exist or is mutably borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `C` storages (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    #[track_caller]
+    #[inline]
+    pub fn is_packed<C: TuplePack>(&self) -> bool {
+        self.all_storages.borrow().unwrap().is_packed::<C>()
+    }
     /// Deletes an entity with all its components. Returns true if the entity were alive.
     ///
     /// ### Example
@@ -1008,6 +2104,39 @@ impl World {
     pub fn delete_entity(&mut self, entity: EntityId) -> bool {
         self.all_storages.get_mut().delete_entity(entity)
     }
+    /// Deletes `entity` and detaches it from its parent.\
+    /// If `cascade` is `true`, all of `entity`'s descendants are deleted too, otherwise its children become roots.
+    ///
+    /// Returns `true` if `entity` was alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{hierarchy::HierarchyViewMut, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let root = world.add_entity(());
+    /// let child = world.add_entity(());
+    ///
+    /// world
+    ///     .borrow::<HierarchyViewMut>()
+    ///     .unwrap()
+    ///     .attach_child(root, child);
+    ///
+    /// world.delete_in_hierarchy(root, false);
+    ///
+    /// assert!(world.is_entity_alive(child));
+    /// assert_eq!(world.borrow::<HierarchyViewMut>().unwrap().parent(child), None);
+    /// ```
+    #[cfg(feature = "hierarchy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hierarchy")))]
+    #[track_caller]
+    pub fn delete_in_hierarchy(&mut self, entity: EntityId, cascade: bool) -> bool {
+        self.all_storages
+            .get_mut()
+            .delete_in_hierarchy(entity, cascade)
+    }
     /// Deletes all components of an entity without deleting the entity.
     ///
     /// ### Example
@@ -1187,6 +2316,70 @@ impl World {
     pub fn clear(&mut self) {
         self.all_storages.get_mut().clear();
     }
+    /// Deletes all entities and components in the `World` and resets all tracking information.
+    ///
+    /// Storages stay registered and keep their allocated memory.\
+    /// Entity generations are preserved, ids from before the call are considered dead.\
+    /// See [`AllStorages::clear_keep_capacity`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// world.clear_keep_capacity();
+    ///
+    /// assert!(!world.is_entity_alive(entity));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn clear_keep_capacity(&mut self) {
+        self.all_storages.get_mut().clear_keep_capacity();
+    }
+    /// Returns a copy of the tracking metadata of all storages, components are not copied.\
+    /// See [`AllStorages::tracking_snapshot`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Modification)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    /// world.get::<&mut U32>(entity).unwrap().0 += 1;
+    ///
+    /// let snapshot = world.tracking_snapshot();
+    ///
+    /// world.clear_all_modified();
+    /// assert!(!world.borrow::<View<U32>>().unwrap().is_modified(entity));
+    ///
+    /// world.restore_tracking_snapshot(&snapshot);
+    /// assert!(world.borrow::<View<U32>>().unwrap().is_modified(entity));
+    /// ```
+    #[inline]
+    pub fn tracking_snapshot(&mut self) -> TrackingSnapshot {
+        self.all_storages.get_mut().tracking_snapshot()
+    }
+    /// Restores the tracking metadata of all storages present in `snapshot`.\
+    /// See [`AllStorages::restore_tracking_snapshot`] for more details.
+    #[inline]
+    pub fn restore_tracking_snapshot(&mut self, snapshot: &TrackingSnapshot) {
+        self.all_storages
+            .get_mut()
+            .restore_tracking_snapshot(snapshot);
+    }
     /// Clear all deletion and removal tracking data.
     #[track_caller]
     pub fn clear_all_removed_and_deleted(&mut self) {
@@ -1222,6 +2415,8 @@ impl World {
     /// Make the given entity alive.
     /// Does nothing if an entity with a greater generation is already at this index.
     /// Returns `true` if the entity is successfully spawned.
+    ///
+    /// When the `World` has a fixed capacity, `entity`'s index has to be lower than the capacity.
     #[inline]
     #[track_caller]
     pub fn spawn(&mut self, entity: EntityId) -> bool {
@@ -1251,6 +2446,96 @@ impl World {
         self.all_storages.get_mut().retain_mut(f);
     }
 
+    /// Deletes all entities for which `keep(id)` returns `false`, and all their components.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid0 = world.add_entity(U32(0));
+    /// let eid1 = world.add_entity(U32(1));
+    ///
+    /// world.retain_entities(|eid| eid != eid0);
+    ///
+    /// assert!(!world.is_entity_alive(eid0));
+    /// assert!(world.is_entity_alive(eid1));
+    /// ```
+    #[track_caller]
+    pub fn retain_entities(&mut self, keep: impl Fn(EntityId) -> bool) {
+        self.all_storages.get_mut().retain_entities(keep);
+    }
+
+    /// Applies all operations recorded by [`Commands`](crate::Commands), in the order they were recorded.
+    ///
+    /// Workloads apply commands automatically, this is only needed for commands recorded outside workloads.
+    #[track_caller]
+    pub fn apply_commands(&mut self) {
+        self.all_storages.get_mut().apply_commands();
+    }
+
+    /// Applies the operations recorded by [`Commands`](crate::Commands) if there are any, then runs insertion observers.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed, the commands stay queued.
+    /// - Observers kept inserting observed components for 64 rounds.
+    pub(crate) fn try_apply_commands(&self) -> Result<(), error::RunWorkload> {
+        let has_commands = {
+            let all_storages = self
+                .all_storages
+                .borrow()
+                .map_err(|_| error::RunWorkload::ApplyCommands)?;
+            let queue = all_storages.custom_storage::<CommandQueue>();
+
+            queue.is_ok_and(|queue| !queue.is_empty())
+        };
+
+        if has_commands {
+            self.all_storages
+                .borrow_mut()
+                .map_err(|_| error::RunWorkload::ApplyCommands)?
+                .apply_commands();
+        }
+
+        self.try_run_observers()
+            .map_err(error::RunWorkload::Observe)
+    }
+
+    /// Calls the observers registered with [`World::observe_insert`] for all insertions not dispatched yet.
+    ///
+    /// `World` methods inserting components already dispatch them before returning,
+    /// this is only needed after [`World::bulk_add_entity`] or inserting through a view borrowed outside of a system.
+    ///
+    /// ### Errors
+    ///
+    /// - Observers kept inserting observed components for 64 rounds, the remaining insertions are dropped.
+    pub fn run_observers(&mut self) -> Result<(), error::Observe> {
+        self.all_storages.get_mut().run_observers()
+    }
+
+    /// Same as [`World::run_observers`] but leaves the insertions queued if `AllStorages` is already borrowed.
+    pub(crate) fn try_run_observers(&self) -> Result<(), error::Observe> {
+        // A shared borrow doesn't get in the way of other threads borrowing storages
+        let is_pending = self
+            .all_storages
+            .borrow()
+            .is_ok_and(|all_storages| all_storages.has_pending_observers());
+
+        if is_pending {
+            if let Ok(mut all_storages) = self.all_storages.borrow_mut() {
+                return all_storages.run_observers();
+            }
+        }
+
+        Ok(())
+    }
+
     /// Displays storages memory information.
     pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
         WorldMemoryUsage(self)
@@ -1559,6 +2844,57 @@ for (i, j) in &mut iter {
         entities.on_deletion(f);
     }
 
+    /// Registers `f` to be called each time a `T` component is inserted, including when it replaces an existing one.
+    ///
+    /// Observers are called after the insertion completed, [`SparseSet::on_insertion`] callbacks don't replace them.
+    ///
+    /// Observers need exclusive access to `AllStorages`, insertions are dispatched before returning from:
+    /// - `World` and [`AllStorages`] methods inserting components, like [`World::add_entity`] or [`AllStorages::add_component`]
+    /// - [`World::run`], when `AllStorages` isn't borrowed elsewhere
+    /// - each system of a sequential workload and each batch of a parallel one, [`Workload::flush`] points included
+    ///
+    /// Insertions made through a borrowed view or [`World::bulk_add_entity`] can't be dispatched while the borrow is alive,
+    /// they are dispatched by the next of these points or by [`World::run_observers`].
+    ///
+    /// Insertions are dispatched storage by storage, in the order observers were first registered for them.\
+    /// Components inserted by an observer are dispatched after all pending insertions, until none is left.
+    ///
+    /// ### Panics
+    ///
+    /// - `World` methods dispatching insertions panic when observers keep inserting observed components for 64 rounds.
+    ///   [`World::run_observers`] and workloads return an error instead.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.observe_insert::<Player>(|all_storages, entity| {
+    ///     all_storages.add_component(entity, Health(100));
+    /// });
+    ///
+    /// let player = world.add_entity(Player);
+    ///
+    /// assert_eq!(world.get::<&Health>(player).unwrap().0, 100);
+    /// ```
+    ///
+    /// [`Workload::flush`]: crate::Workload::flush
+    /// [`SparseSet::on_insertion`]: crate::sparse_set::SparseSet::on_insertion
+    pub fn observe_insert<T: Component + Send + Sync>(
+        &mut self,
+        f: impl Fn(&mut AllStorages, EntityId) + Send + Sync + 'static,
+    ) {
+        self.all_storages.get_mut().observe_insert::<T>(f);
+    }
+
     /// Returns true if entity matches a living entity.
     pub fn is_entity_alive(&mut self, entity: EntityId) -> bool {
         self.all_storages
@@ -1568,6 +2904,106 @@ for (i, j) in &mut iter {
             .is_alive(entity)
     }
 
+    /// Returns the number of alive entities.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn entity_count(&self) -> usize {
+        self.all_storages.borrow().unwrap().entity_count()
+    }
+
+    /// Returns the number of component storages.\
+    /// `Entities` and unique storages are not counted, borrowed storages are.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(world.storage_count(), 0);
+    ///
+    /// world.add_entity(U32(0));
+    /// world.add_entity(U32(1));
+    ///
+    /// assert_eq!(world.entity_count(), 2);
+    /// assert_eq!(world.storage_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn storage_count(&self) -> usize {
+        self.all_storages.borrow().unwrap().storage_count()
+    }
+
+    /// Returns the [`StorageId`] of all component storages, sorted.\
+    /// `Entities` and unique storages are not included, borrowed storages are.\
+    /// Returns an empty `Vec` if `AllStorages` is borrowed exclusively.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((U32(0), USIZE(0)));
+    ///
+    /// let names = world
+    ///     .component_storage_ids()
+    ///     .into_iter()
+    ///     .map(|storage_id| world.storage_name(storage_id).unwrap())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names.len(), 2);
+    /// assert!(names.iter().any(|name| name.contains("U32")));
+    /// assert!(names.iter().any(|name| name.contains("USIZE")));
+    /// ```
+    pub fn component_storage_ids(&self) -> Vec<StorageId> {
+        self.all_storages
+            .borrow()
+            .map(|all_storages| all_storages.component_storage_ids())
+            .unwrap_or_default()
+    }
+
+    /// Returns the name of the storage identified by `storage_id`.\
+    /// Returns `None` if there is no such storage or if it or `AllStorages` can't be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `storage_id`'s storage (shared)
+    pub fn storage_name(&self, storage_id: StorageId) -> Option<Cow<'static, str>> {
+        self.all_storages.borrow().ok()?.storage_name(storage_id)
+    }
+
     /// Moves an entity from a `World` to another.
     ///
     /// ### Panics
@@ -1638,6 +3074,132 @@ for (i, j) in &mut iter {
             .move_components(other_all_storages, from, to);
     }
 
+    /// Moves an entity and the components of `storages` to another `World`.\
+    /// The entity gets a new id in `other`, it is returned.\
+    /// Components in other storages are deleted alongside the entity.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `other` has a fixed capacity and all entity indices are in use.
+    /// - `other` has a fixed capacity and the storage of one of the moved components is full.
+    ///
+    /// Nothing is moved or deleted when an error is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{advanced::StorageId, Component, sparse_set::SparseSet, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// let entity = world1.add_entity((U32(0), USIZE(1)));
+    ///
+    /// let new_entity = world1
+    ///     .transfer_entity(&mut world2, entity, &[StorageId::of::<SparseSet<USIZE>>()])
+    ///     .unwrap();
+    ///
+    /// assert!(!world1.is_entity_alive(entity));
+    /// assert!(world2.get::<&U32>(new_entity).is_err());
+    /// assert_eq!(world2.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    pub fn transfer_entity(
+        &mut self,
+        other: &mut World,
+        entity: EntityId,
+        storages: &[StorageId],
+    ) -> Result<EntityId, error::TransferEntity> {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .transfer_entity(other_all_storages, entity, storages)
+    }
+
+    /// Moves entities and the components of `storages` to another `World`.\
+    /// Entities get a new id in `other`, each `(old_id, new_id)` pair is added to `remap`.\
+    /// Components in other storages are deleted alongside the entities.
+    ///
+    /// Components storing `EntityId`s can then be fixed up using `remap`.
+    ///
+    /// ### Errors
+    ///
+    /// - One of the entities can't be transferred, see [`transfer_entity`](World::transfer_entity).\
+    ///   The entities before it are transferred and added to `remap`, the ones after it are left untouched.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{advanced::StorageId, Component, sparse_set::SparseSet, World};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// let entity1 = world1.add_entity(USIZE(0));
+    /// let entity2 = world1.add_entity(USIZE(1));
+    ///
+    /// let mut remap = HashMap::new();
+    /// world1.transfer_entities(
+    ///     &mut world2,
+    ///     [entity1, entity2],
+    ///     &[StorageId::of::<SparseSet<USIZE>>()],
+    ///     &mut remap,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(remap.len(), 2);
+    /// assert_eq!(world2.get::<&USIZE>(remap[&entity2]).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    pub fn transfer_entities(
+        &mut self,
+        other: &mut World,
+        entities: impl IntoIterator<Item = EntityId>,
+        storages: &[StorageId],
+        remap: &mut impl Extend<(EntityId, EntityId)>,
+    ) -> Result<(), error::TransferEntity> {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .transfer_entities(other_all_storages, entities, storages, remap)
+    }
+
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for `capacity` components.\
+    /// Adding components will not reallocate until `capacity` is reached.\
+    /// A `capacity` of 0 only creates the storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_with_capacity::<U32>(1000);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn register_with_capacity<T: Component + Send + Sync>(&mut self, capacity: usize) {
+        self.all_storages
+            .get_mut()
+            .register_with_capacity::<T>(capacity);
+    }
+
     /// Registers the function to clone these components.
     ///
     /// The type of the storage is used and not the component itself.\
@@ -1666,6 +3228,106 @@ for (i, j) in &mut iter {
         self.all_storages.get_mut().register_clone::<T>();
     }
 
+    /// Registers `f` to upgrade `Old`, a previous serialization format of `New`, when deserializing.
+    ///
+    /// When deserializing a `ViewMut<New>`, each component is read in the current format first.\
+    /// If that fails, migrations are tried in registration order.\
+    /// When no migration matches, the error of the current format is returned.
+    ///
+    /// Migrations need a self-describing format like JSON or RON, each component is buffered
+    /// to be read multiple times.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, EntityId, ViewMut, World};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct HealthV1(u32);
+    ///
+    /// #[derive(Component, serde::Deserialize)]
+    /// struct Health {
+    ///     current: u32,
+    ///     max: u32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_component_migrate(|old: HealthV1| Health {
+    ///     current: old.0,
+    ///     max: old.0,
+    /// });
+    ///
+    /// let save = r#"[[{"index":0,"gen":0},10]]"#;
+    ///
+    /// world.run(|mut healths: ViewMut<Health>| {
+    ///     let mut deserializer = serde_json::Deserializer::from_str(save);
+    ///     serde::Deserialize::deserialize_in_place(&mut deserializer, &mut healths).unwrap();
+    /// });
+    ///
+    /// let health = world.get::<&Health>(EntityId::new_from_index_and_gen(0, 0)).unwrap();
+    /// assert_eq!((health.current, health.max), (10, 10));
+    /// ```
+    #[cfg(feature = "serde1")]
+    #[track_caller]
+    pub fn register_component_migrate<Old, New>(
+        &mut self,
+        f: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        Old: serde::de::DeserializeOwned,
+        New: Component + Send + Sync,
+    {
+        self.all_storages
+            .get_mut()
+            .register_component_migrate::<Old, New>(f);
+    }
+
+    /// Includes `T` components in [`World::serialize_registered`] and [`World::deserialize_registered`] under `name`.\
+    /// Registering the same component multiple times with the same name has no effect.
+    ///
+    /// Storages that are not registered are skipped, this controls which components are persisted.\
+    /// `name` identifies the storage in the serialized data, it has to stay the same between versions.
+    ///
+    /// ### Panics
+    ///
+    /// - `T` is already registered under a different name.
+    /// - `name` is already used by another component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, serde::Serialize, serde::Deserialize)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Component)]
+    /// struct Cache(Vec<u8>);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_serde::<Name>("name");
+    /// world.register_serde::<Name>("name");
+    ///
+    /// world.add_entity((Name("Alice".to_string()), Cache(Vec::new())));
+    ///
+    /// let mut serialized = Vec::new();
+    /// world
+    ///     .serialize_registered(&mut serde_json::ser::Serializer::new(&mut serialized))
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// let serialized_str = String::from_utf8(serialized).unwrap();
+    /// assert_eq!(serialized_str, r#"{"name":[[{"index":0,"gen":0},"Alice"]]}"#);
+    /// ```
+    #[cfg(feature = "serde1")]
+    #[track_caller]
+    pub fn register_serde<T>(&mut self, name: &'static str)
+    where
+        T: Component + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.all_storages.get_mut().register_serde::<T>(name);
+    }
+
     /// Clones `entity` from this `World` to `other` alongside all its with a registered clone function.
     ///
     /// ### Borrows
@@ -1872,4 +3534,56 @@ impl World {
             Err(err) => Err(error::Deserialize::Borrow(err)),
         }
     }
+
+    /// Serializes all storages registered with [`World::register_serde`], other storages are skipped.
+    ///
+    /// See [`AllStorages::serialize_registered`].
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Registered storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed.
+    /// - Serialization error.
+    pub fn serialize_registered<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, error::Serialize<S>> {
+        match self.all_storages.borrow() {
+            Ok(all_storages) => all_storages.serialize_registered(serializer),
+            Err(err) => Err(error::Serialize::Borrow(
+                error::GetStorage::AllStoragesBorrow(err),
+            )),
+        }
+    }
+
+    /// Deserializes storages serialized with [`World::serialize_registered`].
+    ///
+    /// See [`AllStorages::deserialize_registered`].
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Registered storages present in `deserializer` (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed.
+    /// - Deserialization error.
+    pub fn deserialize_registered<'de, D: serde::Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<(), error::Deserialize<'de, D>> {
+        match self.all_storages.borrow() {
+            Ok(all_storages) => all_storages.deserialize_registered(deserializer),
+            Err(err) => Err(error::Deserialize::Borrow(
+                error::GetStorage::AllStoragesBorrow(err),
+            )),
+        }
+    }
 }
@@ -86,11 +86,30 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
         }
     }
 
+    /// Limits the `World` to `capacity` entities and `capacity` components per storage.
+    ///
+    /// See [`World::with_fixed_capacity`].
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::builder().with_fixed_capacity(1024).build();
+    /// ```
+    pub fn with_fixed_capacity(self, capacity: usize) -> WorldBuilder<Lock, ThreadId> {
+        WorldBuilder {
+            all_storages_builder: self.all_storages_builder.with_fixed_capacity(capacity),
+            #[cfg(feature = "parallel")]
+            thread_pool: self.thread_pool,
+        }
+    }
+
     /// Use a local [`ThreadPool`](rayon::ThreadPool).
     ///
     /// This is useful when you have multiple [`Worlds`](World) or something else using [`rayon`] and want them to stay isolated.\
     /// For example with a single [`ThreadPool`](rayon::ThreadPool), a panic would take down all [`Worlds`](World).\
     /// With a [`ThreadPool`](rayon::ThreadPool) per [`World`] we can keep the panic confined to a single [`World`].
+    ///
+    /// Systems borrowing `!Send` or `!Sync` storages still run on the thread calling `run_workload`.
     #[cfg(feature = "parallel")]
     pub fn with_local_thread_pool(
         mut self,
@@ -113,6 +132,7 @@ impl WorldBuilder<LockPresent, ThreadIdPresent> {
             all_storages,
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            tick: Arc::new(AtomicU64::new(0)),
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
         }
@@ -1,7 +1,23 @@
 use crate::error;
+use crate::scheduler::info::{RunTrace, TraceEvent};
 use crate::scheduler::{Batches, Label};
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::format;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// How a workload run behaves, on top of running its systems.
+#[derive(Default)]
+pub(crate) struct RunOptions<'a> {
+    /// Runs the systems one after the other, even with the `parallel` feature.
+    #[cfg_attr(not(feature = "parallel"), allow(unused))]
+    pub(crate) deterministic: bool,
+    /// Stops the run between batches once set.
+    pub(crate) cancel: Option<&'a AtomicBool>,
+    /// Records which systems ran or were skipped.\
+    /// Forces a sequential run, like `deterministic`.
+    pub(crate) trace: Option<&'a mut RunTrace>,
+}
 
 impl World {
     #[cfg(feature = "parallel")]
@@ -11,130 +27,209 @@ impl World {
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        options: RunOptions<'_>,
     ) -> Result<(), error::RunWorkload> {
+        let cancel = options.cancel;
+
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
 
-        let run_batch = || -> Result<(), error::RunWorkload> {
-            for (batch, batch_run_if) in batches.parallel.iter().zip(&batches.parallel_run_if) {
-                let mut result = Ok(());
+        for (batch_index, (batch, batch_run_if)) in batches
+            .parallel
+            .iter()
+            .zip(&batches.parallel_run_if)
+            .enumerate()
+        {
+            if is_cancelled(cancel) {
+                return Err(error::RunWorkload::Cancelled);
+            }
+
+            let mut result = Ok(());
 
-                let run_if = (
-                    if batch_run_if.0 == usize::MAX {
-                        // There is no run_if for this system
+            let run_if = (
+                if batch_run_if.0 == usize::MAX {
+                    // There is no run_if for this system
 
-                        true
-                    } else {
-                        match (batches.systems_run_if[batch_run_if.0])(self) {
-                            Ok(should_run) => should_run,
-                            Err(err) => {
-                                return Err(error::RunWorkload::Run((
-                                    system_names[batch.0.unwrap()].clone(),
-                                    err,
-                                )));
-                            }
+                    true
+                } else {
+                    match (batches.systems_run_if[batch_run_if.0])(self) {
+                        Ok(should_run) => should_run,
+                        Err(err) => {
+                            return Err(error::RunWorkload::Run(error::RunSystem::new(
+                                workload_name,
+                                system_names[batch.0.unwrap()].clone(),
+                                err,
+                            )));
                         }
-                    },
-                    batch
-                        .1
-                        .iter()
-                        .zip(&batch_run_if.1)
-                        .map(|(&index, &run_if_index)| {
-                            if run_if_index == usize::MAX {
-                                // There is no run_if for this system
-
-                                return Ok(true);
-                            }
-
-                            match (batches.systems_run_if[run_if_index])(self) {
-                                Ok(should_run) => Ok(should_run),
-                                Err(err) => {
-                                    Err(error::RunWorkload::Run((system_names[index].clone(), err)))
-                                }
-                            }
-                        })
-                        .collect::<Result<alloc::vec::Vec<_>, error::RunWorkload>>()?,
-                );
+                    }
+                },
+                batch
+                    .1
+                    .iter()
+                    .zip(&batch_run_if.1)
+                    .map(|(&index, &run_if_index)| {
+                        if run_if_index == usize::MAX {
+                            // There is no run_if for this system
 
-                let mut start = 0;
-                let single_system = batch.0.filter(|_| run_if.0).or_else(|| {
-                    let system = batch.1.first().copied().filter(|_| run_if.1[0]);
+                            return Ok(true);
+                        }
 
-                    if system.is_some() {
-                        start = 1;
-                    }
+                        match (batches.systems_run_if[run_if_index])(self) {
+                            Ok(should_run) => Ok(should_run),
+                            Err(err) => Err(error::RunWorkload::Run(error::RunSystem::new(
+                                workload_name,
+                                system_names[index].clone(),
+                                err,
+                            ))),
+                        }
+                    })
+                    .collect::<Result<alloc::vec::Vec<_>, error::RunWorkload>>()?,
+            );
 
-                    system
-                });
+            let mut start = 0;
+            let single_system = batch.0.filter(|_| run_if.0).or_else(|| {
+                let system = batch.1.first().copied().filter(|_| run_if.1[0]);
+
+                if system.is_some() {
+                    start = 1;
+                }
 
-                rayon::in_place_scope(|scope| {
-                    // This check exists to avoid spawning a parallel job when possible.
-                    // On wasm it causes a "condvar wait not supported" error.
-                    if start < batch.1.len() {
-                        scope.spawn(|_| {
-                            use rayon::prelude::*;
+                system
+            });
 
-                            result = batch.1[start..]
-                                .par_iter()
-                                .zip(&run_if.1[start..])
-                                .try_for_each(|(&index, should_run)| {
+            // Number of threads available to the parallel systems, the calling thread runs `single_system`
+            let workers = batches.max_threads.map_or(usize::MAX, |max_threads| {
+                max_threads.saturating_sub(usize::from(single_system.is_some()))
+            });
+            // With a single thread left and nothing else to run, the calling thread does the work
+            let run_on_caller = workers == 0 || (workers == 1 && single_system.is_none());
+
+            // Workers don't know which workloads the calling thread is running
+            #[cfg(feature = "std")]
+            let running = crate::scheduler::running_workloads();
+
+            // The scope's body runs on the calling thread so systems borrowing
+            // `!Send` or `!Sync` storages never reach a worker thread.
+            in_place_scope(self.thread_pool.as_ref(), |scope| {
+                // This check exists to avoid spawning a parallel job when possible.
+                // On wasm it causes a "condvar wait not supported" error.
+                if start < batch.1.len() && !run_on_caller {
+                    scope.spawn(|_| {
+                        use rayon::prelude::*;
+
+                        // Each chunk runs its systems one after the other,
+                        // at most `workers` systems run at the same time
+                        let chunk_len = (batch.1.len() - start).div_ceil(workers);
+
+                        result = batch.1[start..]
+                            .par_chunks(chunk_len)
+                            .zip(run_if.1[start..].par_chunks(chunk_len))
+                            .try_for_each(|(chunk, chunk_run_if)| {
+                                #[cfg(feature = "std")]
+                                let _running = crate::scheduler::inherit_running(&running);
+
+                                for (&index, &should_run) in chunk.iter().zip(chunk_run_if) {
                                     if !should_run {
-                                        return Ok(());
+                                        continue;
                                     }
 
                                     #[cfg(feature = "tracing")]
-                                    {
-                                        self.run_single_system(
-                                            systems,
-                                            system_names,
-                                            &parent_span,
-                                            index,
-                                        )
-                                    }
+                                    self.run_single_system(
+                                        systems,
+                                        system_names,
+                                        batches,
+                                        workload_name,
+                                        &parent_span,
+                                        index,
+                                    )?;
                                     #[cfg(not(feature = "tracing"))]
-                                    {
-                                        self.run_single_system(systems, system_names, index)
-                                    }
-                                });
-                        });
-                    }
+                                    self.run_single_system(
+                                        systems,
+                                        system_names,
+                                        batches,
+                                        workload_name,
+                                        index,
+                                    )?;
+                                }
+
+                                Ok(())
+                            });
+                    });
+                }
+
+                if let Some(index) = single_system {
+                    #[cfg(feature = "tracing")]
+                    self.run_single_system(
+                        systems,
+                        system_names,
+                        batches,
+                        workload_name,
+                        &parent_span,
+                        index,
+                    )?;
+                    #[cfg(not(feature = "tracing"))]
+                    self.run_single_system(systems, system_names, batches, workload_name, index)?;
+                }
+
+                if run_on_caller {
+                    for (&index, &should_run) in batch.1[start..].iter().zip(&run_if.1[start..]) {
+                        if !should_run {
+                            continue;
+                        }
 
-                    if let Some(index) = single_system {
                         #[cfg(feature = "tracing")]
-                        self.run_single_system(systems, system_names, &parent_span, index)?;
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            batches,
+                            workload_name,
+                            &parent_span,
+                            index,
+                        )?;
                         #[cfg(not(feature = "tracing"))]
-                        self.run_single_system(systems, system_names, index)?;
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            batches,
+                            workload_name,
+                            index,
+                        )?;
                     }
+                }
 
-                    Ok(())
-                })?;
-
-                result?;
-            }
+                Ok(())
+            })?;
 
-            Ok(())
-        };
+            result?;
 
-        if let Some(thread_pool) = &self.thread_pool {
-            thread_pool.scope(|_| run_batch())
-        } else {
-            // Use non local ThreadPool
-            run_batch()
+            // Systems of the same batch can't be interrupted, observers run once they all finished
+            if batches.parallel_flushes.contains(&batch_index) {
+                self.try_apply_commands()?;
+            } else {
+                self.try_run_observers()
+                    .map_err(error::RunWorkload::Observe)?;
+            }
         }
+
+        self.try_apply_commands()
     }
 
-    #[cfg(not(feature = "parallel"))]
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_sequential(
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        options: RunOptions<'_>,
     ) -> Result<(), error::RunWorkload> {
+        let RunOptions {
+            cancel, mut trace, ..
+        } = options;
+
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
         #[cfg(feature = "tracing")]
@@ -144,7 +239,12 @@ impl World {
             .sequential
             .iter()
             .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, &run_if_index)| {
+            .enumerate()
+            .try_for_each(|(position, (&index, &run_if_index))| {
+                if is_cancelled(cancel) {
+                    return Err(error::RunWorkload::Cancelled);
+                }
+
                 let should_run = if run_if_index == usize::MAX {
                     // There is no run_if for this system
 
@@ -153,7 +253,8 @@ impl World {
                     match (batches.systems_run_if[run_if_index])(self) {
                         Ok(should_run) => should_run,
                         Err(err) => {
-                            return Err(error::RunWorkload::Run((
+                            return Err(error::RunWorkload::Run(error::RunSystem::new(
+                                workload_name,
                                 system_names[index].clone(),
                                 err,
                             )));
@@ -161,19 +262,42 @@ impl World {
                     }
                 };
 
-                if !should_run {
-                    return Ok(());
+                if let Some(trace) = &mut trace {
+                    let system_name = format!("{:?}", system_names[index]);
+
+                    trace.events.push(if should_run {
+                        TraceEvent::Ran(system_name)
+                    } else {
+                        TraceEvent::SkippedByRunIf(system_name)
+                    });
                 }
 
-                #[cfg(feature = "tracing")]
-                {
-                    self.run_single_system(systems, system_names, &parent_span, index)
+                if should_run {
+                    #[cfg(feature = "tracing")]
+                    self.run_single_system(
+                        systems,
+                        system_names,
+                        batches,
+                        workload_name,
+                        &parent_span,
+                        index,
+                    )?;
+                    #[cfg(not(feature = "tracing"))]
+                    self.run_single_system(systems, system_names, batches, workload_name, index)?;
+
+                    // The next system sees what observers inserted
+                    self.try_run_observers()
+                        .map_err(error::RunWorkload::Observe)?;
                 }
-                #[cfg(not(feature = "tracing"))]
-                {
-                    self.run_single_system(systems, system_names, index)
+
+                if batches.sequential_flushes.contains(&position) {
+                    self.try_apply_commands()?;
                 }
-            })
+
+                Ok(())
+            })?;
+
+        self.try_apply_commands()
     }
 
     #[allow(clippy::type_complexity)]
@@ -181,6 +305,8 @@ impl World {
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync>],
         system_names: &[Box<dyn Label>],
+        batches: &Batches,
+        workload_name: &dyn Label,
         #[cfg(feature = "tracing")] parent_span: &tracing::Span,
         index: usize,
     ) -> Result<(), error::RunWorkload> {
@@ -190,7 +316,35 @@ impl World {
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
-        (systems[index])(self)
-            .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+        (systems[index])(self).map_err(|err| {
+            error::RunWorkload::Run(error::RunSystem::new(
+                workload_name,
+                system_names[index].clone(),
+                err,
+            ))
+        })?;
+
+        // Only systems that completed are counted
+        batches.systems_run.fetch_add(1, Ordering::Relaxed);
+
+        Ok(())
+    }
+}
+
+fn is_cancelled(cancel: Option<&AtomicBool>) -> bool {
+    cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+}
+
+/// Creates a scope whose body runs on the current thread.\
+/// Jobs spawned in the scope run on `thread_pool` or on the global `ThreadPool` if it's `None`.
+#[cfg(feature = "parallel")]
+fn in_place_scope<'scope, R>(
+    thread_pool: Option<&rayon::ThreadPool>,
+    op: impl FnOnce(&rayon::Scope<'scope>) -> R,
+) -> R {
+    match thread_pool {
+        Some(thread_pool) => thread_pool.in_place_scope(op),
+        // Use non local ThreadPool
+        None => rayon::in_place_scope(op),
     }
 }
@@ -162,3 +162,120 @@ fn move_between_worlds() {
     assert_eq!(*world2.get::<&USIZE>(entity1).unwrap(), &USIZE(1));
     assert_eq!(*world2.get::<&U32>(entity1).unwrap(), &U32(2));
 }
+
+#[test]
+#[should_panic(expected = "Entity has to be alive to add component to it.")]
+fn dead_entity() {
+    let world = World::new();
+
+    let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+
+    let entity = entities.add_entity((), ());
+    entities.delete_unchecked(entity);
+
+    entities.add_component(entity, &mut u32s, U32(0));
+}
+
+#[test]
+fn dead_entity_checked() {
+    let world = World::new();
+
+    let (mut entities, mut u32s, mut usizes) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<USIZE>)>()
+        .unwrap();
+
+    let entity = entities.add_entity((), ());
+    entities.delete_unchecked(entity);
+
+    assert_eq!(
+        entities.add_component_checked(entity, &mut u32s, U32(0)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+    assert_eq!(
+        entities.add_distinct_component_checked(entity, &mut usizes, USIZE(0)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+    assert!(u32s.is_empty());
+    assert!(usizes.is_empty());
+
+    let entity = entities.add_entity((), ());
+    assert_eq!(
+        entities.add_distinct_component_checked(entity, &mut usizes, USIZE(0)),
+        Ok(true)
+    );
+    assert_eq!(
+        entities.add_distinct_component_checked(entity, &mut usizes, USIZE(1)),
+        Ok(false)
+    );
+}
+
+#[test]
+fn try_add_component() {
+    let mut world = World::new();
+    let entity = world.add_entity(());
+
+    let all_storages = world.all_storages().unwrap();
+    all_storages
+        .try_add_component(entity, (U32(0), USIZE(1)))
+        .unwrap();
+
+    assert_eq!(*all_storages.get::<&U32>(entity).unwrap(), &U32(0));
+    assert_eq!(*all_storages.get::<&USIZE>(entity).unwrap(), &USIZE(1));
+}
+
+#[test]
+fn try_add_component_dead_entity() {
+    let mut world = World::new();
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    let all_storages = world.all_storages().unwrap();
+
+    assert_eq!(
+        all_storages.try_add_component(entity, (U32(0),)),
+        Err(error::TryAddComponent::AddComponent(
+            error::AddComponent::EntityIsNotAlive
+        ))
+    );
+    assert!(all_storages.get::<&U32>(entity).is_err());
+}
+
+#[test]
+fn try_add_component_entities_borrow() {
+    let mut world = World::new();
+    let entity = world.add_entity(());
+
+    let all_storages = world.all_storages().unwrap();
+    let _entities = all_storages.borrow::<EntitiesViewMut>().unwrap();
+
+    assert_eq!(
+        all_storages.try_add_component(entity, (U32(0),)),
+        Err(error::TryAddComponent::StorageBorrow(
+            error::GetStorage::Entities(error::Borrow::Shared)
+        ))
+    );
+}
+
+#[test]
+fn try_add_component_storage_borrow() {
+    let mut world = World::new();
+    let entity = world.add_entity(());
+
+    let all_storages = world.all_storages().unwrap();
+    let usizes = all_storages.borrow::<View<USIZE>>().unwrap();
+
+    assert!(matches!(
+        all_storages.try_add_component(entity, (U32(0), USIZE(1))),
+        Err(error::TryAddComponent::StorageBorrow(
+            error::GetStorage::StorageBorrow {
+                borrow: error::Borrow::Unique,
+                ..
+            }
+        ))
+    ));
+
+    drop(usizes);
+
+    assert!(all_storages.get::<&U32>(entity).is_err());
+    assert!(all_storages.get::<&USIZE>(entity).is_err());
+}
@@ -171,3 +171,19 @@ fn workload() {
     world.run_default_workload().unwrap();
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn spawn_batch_with() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let entities = all_storages.spawn_batch_with(3, |i| (U32(i as u32),));
+
+    assert_eq!(entities.len(), 3);
+
+    let u32s = all_storages.borrow::<View<U32>>().unwrap();
+    for (i, &entity) in entities.iter().enumerate() {
+        assert_eq!(u32s[entity], U32(i as u32));
+    }
+    assert_eq!(u32s.iter().ids().collect::<Vec<_>>(), entities);
+}
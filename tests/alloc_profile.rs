@@ -0,0 +1,75 @@
+#![cfg(feature = "alloc_profile")]
+
+use shipyard::alloc_profile::CountingAllocator;
+use shipyard::scheduler::SystemModificator;
+use shipyard::*;
+use std::alloc::System;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator<System> = CountingAllocator(System);
+
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+fn no_alloc() {}
+
+fn alloc_vec() {
+    std::hint::black_box(vec![0u8; 1024]);
+}
+
+fn add_entities(mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>) {
+    for i in 0..100 {
+        entities.add_entity(&mut u32s, U32(i));
+    }
+}
+
+// Allocations from all threads are counted, a single test keeps the harness' other threads idle
+#[test]
+fn alloc_profile() {
+    let world = World::new();
+
+    assert!(matches!(
+        world.run_workload_alloc_profile("Missing"),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+
+    Workload::new("Profile")
+        .with_system(no_alloc)
+        .with_system(alloc_vec)
+        .with_system(add_entities)
+        .with_system(alloc_vec.run_if(|| false))
+        .add_to_world(&world)
+        .unwrap();
+
+    let profile = world.run_workload_alloc_profile("Profile").unwrap();
+
+    assert_eq!(profile.len(), 3);
+    assert_eq!(profile[0].0, no_alloc.as_label());
+    assert!(profile[0].1 < 1024);
+    assert_eq!(profile[1].0, alloc_vec.as_label());
+    assert!(profile[1].1 >= 1024);
+    assert_eq!(profile[2].0, add_entities.as_label());
+    assert!(profile[2].1 >= 100 * core::mem::size_of::<U32>());
+
+    #[cfg(feature = "parallel")]
+    {
+        fn par_alloc_vec(u32s: View<U32>) {
+            use rayon::prelude::*;
+
+            u32s.par_iter().for_each(|_| {
+                std::hint::black_box(vec![0u8; 1024]);
+            });
+        }
+
+        Workload::new("Parallel")
+            .with_system(par_alloc_vec)
+            .add_to_world(&world)
+            .unwrap();
+
+        let profile = world.run_workload_alloc_profile("Parallel").unwrap();
+
+        assert!(profile[0].1 >= 100 * 1024);
+    }
+}
@@ -271,3 +271,37 @@ fn unique_exhaustive_list() {
         )>();
     });
 }
+
+#[test]
+fn multiple_uniques() {
+    let world = World::new();
+
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(1));
+
+    {
+        let (u32, mut usize) = world
+            .borrow::<(UniqueView<U32>, UniqueViewMut<USIZE>)>()
+            .unwrap();
+
+        usize.0 += u32.0 as usize;
+    }
+
+    assert!(matches!(
+        world.borrow::<(UniqueViewMut<U32>, UniqueViewMut<U32>)>(),
+        Err(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Unique,
+            ..
+        })
+    ));
+    assert!(matches!(
+        world.borrow::<(UniqueView<U32>, UniqueViewMut<U32>)>(),
+        Err(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Unique,
+            ..
+        })
+    ));
+
+    // The first guard of a failed tuple borrow is released
+    world.borrow::<UniqueViewMut<U32>>().unwrap();
+}
@@ -124,3 +124,41 @@ fn inserted() {
     world.run_default_workload().unwrap();
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn keep_capacity() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(USIZE(0));
+    let entity1 = world.add_entity(USIZE(1));
+    world.delete_entity(entity1);
+    let entity2 = world.add_entity(USIZE(2));
+
+    world.clear_keep_capacity();
+
+    assert!(!world.is_entity_alive(entity0));
+    assert!(!world.is_entity_alive(entity2));
+    assert_eq!(world.entity_count(), 0);
+
+    let usizes = world.borrow::<View<USIZE, track::All>>().unwrap();
+    assert_eq!(usizes.len(), 0);
+    assert_eq!(usizes.deleted().count(), 0);
+    assert_eq!(usizes.removed().count(), 0);
+    drop(usizes);
+
+    let entity3 = world.add_entity(USIZE(3));
+    assert_ne!(entity3, entity0);
+    assert_ne!(entity3, entity2);
+
+    let usizes = world.borrow::<View<USIZE, track::All>>().unwrap();
+    assert_eq!(
+        usizes.inserted().iter().ids().collect::<Vec<_>>(),
+        vec![entity3]
+    );
+}
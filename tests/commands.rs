@@ -0,0 +1,182 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn applied_between_batches() {
+    fn spawn(u32s: View<U32>, mut commands: Commands) {
+        for (id, &U32(i)) in u32s.iter().with_id() {
+            commands.delete_entity(id);
+            commands.add_entity(U32(i + 10));
+        }
+
+        assert_eq!(commands.len(), 2);
+    }
+
+    fn check(mut u32s: ViewMut<U32>) {
+        assert_eq!(u32s.len(), 1);
+
+        for mut i in (&mut u32s).iter() {
+            assert_eq!(*i, U32(10));
+            i.0 += 1;
+        }
+    }
+
+    let world = World::new();
+
+    world.add_entity(U32(0));
+
+    Workload::new("")
+        .with_system(spawn)
+        .flush()
+        .with_system(check)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.iter().collect::<Vec<_>>(), vec![&U32(11)]);
+    });
+}
+
+#[test]
+fn applied_at_workload_end() {
+    fn spawn(mut commands: Commands) {
+        commands.add_entity(U32(0));
+    }
+
+    fn check(u32s: View<U32>) {
+        assert_eq!(u32s.len(), 0);
+    }
+
+    let world = World::new();
+
+    // Ordering alone doesn't apply commands, only flushes do
+    Workload::new("")
+        .with_system(spawn)
+        .with_barrier()
+        .with_system(check)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
+
+#[test]
+fn outside_workload() {
+    let mut world = World::new();
+
+    let eid = world.add_entity(());
+
+    world.run(|mut commands: Commands| {
+        commands.add_component(eid, U32(0));
+        commands.delete_component::<U32>(eid);
+        commands.add_component(eid, U32(1));
+    });
+
+    assert!(world.get::<&U32>(eid).is_err());
+
+    world.apply_commands();
+
+    assert_eq!(*world.get::<&U32>(eid).unwrap(), &U32(1));
+    assert!(world.borrow::<Commands>().unwrap().is_empty());
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+#[test]
+fn par_commands() {
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    for i in 0..1000 {
+        world.add_entity(U32(i));
+    }
+
+    world.run(|u32s: View<U32>, mut commands: Commands| {
+        let par_commands = commands.par_commands();
+
+        u32s.par_iter().with_id().for_each(|(id, &U32(i))| {
+            if i % 2 == 0 {
+                par_commands.delete_entity(id);
+            } else {
+                par_commands.add_entity(U32(i + 1000));
+            }
+        });
+
+        drop(par_commands);
+
+        assert_eq!(commands.len(), 1000);
+    });
+
+    world.apply_commands();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1000);
+    assert_eq!(
+        world
+            .borrow::<View<U32>>()
+            .unwrap()
+            .iter()
+            .filter(|u| u.0 >= 1000)
+            .count(),
+        500
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn systems_with_commands_share_a_batch() {
+    fn spawn_one(mut commands: Commands) {
+        commands.add_entity(U32(1));
+    }
+
+    fn spawn_two(mut commands: Commands) {
+        commands.add_entity(U32(2));
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(spawn_one)
+        .with_system(spawn_two)
+        .add_to_world(&world)
+        .unwrap();
+
+    let stats = world.run_workload_stats("").unwrap();
+
+    assert_eq!(stats.batches, 1);
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 2);
+}
+
+#[test]
+fn apply_fails_while_all_storages_borrowed() {
+    fn spawn(mut commands: Commands) {
+        commands.add_entity(U32(0));
+    }
+
+    let mut world = World::new();
+
+    Workload::new("")
+        .with_system(spawn)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run(|u32s: View<U32>| {
+        assert!(matches!(
+            world.run_default_workload(),
+            Err(error::RunWorkload::ApplyCommands)
+        ));
+        assert_eq!(u32s.len(), 0);
+    });
+
+    world.apply_commands();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
@@ -0,0 +1,63 @@
+use shipyard::*;
+
+#[test]
+fn compact() {
+    #[derive(Component)]
+    struct U64(u64);
+
+    #[derive(Component)]
+    struct U32(u32);
+
+    let mut world = World::new();
+
+    let entities = world
+        .bulk_add_entity((0..1000).map(|i| (U64(i),)))
+        .collect::<Vec<_>>();
+    world.add_entity((U32(0),));
+
+    world.run(|mut u64s: ViewMut<U64>| {
+        for entity in &entities[1..] {
+            u64s.delete(*entity);
+        }
+    });
+
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let report = all_storages.compact(0.5);
+
+    let u64s = report
+        .iter()
+        .find(|compaction| compaction.storage_name.contains("U64"))
+        .unwrap();
+    assert!(u64s.reclaimed_memory_bytes >= 999 * core::mem::size_of::<u64>());
+    assert!(!report
+        .iter()
+        .any(|compaction| compaction.storage_name.contains("U32")));
+
+    assert!(!all_storages
+        .compact(0.5)
+        .iter()
+        .any(|compaction| compaction.storage_name.contains("U64")));
+}
+
+#[test]
+fn compact_fixed_capacity() {
+    #[derive(Component)]
+    struct U64(u64);
+
+    let mut world = World::with_fixed_capacity(1000);
+
+    let entities = world
+        .bulk_add_entity((0..1000).map(|i| (U64(i),)))
+        .collect::<Vec<_>>();
+
+    world.run(|mut u64s: ViewMut<U64>| {
+        for entity in &entities[1..] {
+            u64s.delete(*entity);
+        }
+    });
+
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    assert!(all_storages.compact(0.5).is_empty());
+}
@@ -111,3 +111,71 @@ fn on_deletion() {
         EntityId::new_from_index_and_gen(0, 0).inner()
     );
 }
+
+#[test]
+fn slot_count() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(());
+    let e1 = world.add_entity(());
+    let e2 = world.add_entity(());
+    world.delete_entity(e0);
+    world.delete_entity(e2);
+    world.delete_entity(e1);
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert_eq!(entities.slot_count(), 3);
+    assert_eq!(entities.recycled_count(), 3);
+    drop(entities);
+
+    world.add_entity(());
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert_eq!(entities.slot_count(), 3);
+    assert_eq!(entities.recycled_count(), 2);
+}
+
+#[test]
+fn delete_entity_collect() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Tracked(u32);
+    impl Component for Tracked {
+        type Tracking = track::Deletion;
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Dropped(u32);
+    impl Component for Dropped {
+        type Tracking = track::Untracked;
+    }
+
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.register_take::<U32>();
+    all_storages.register_take::<Tracked>();
+
+    let entity = all_storages.add_entity((U32(0), Tracked(1), Dropped(2)));
+    let other = all_storages.add_entity((U32(3),));
+
+    let mut components = all_storages.delete_entity_collect(entity);
+
+    assert_eq!(components.len(), 1);
+    assert_eq!(components.get::<U32>(), Some(&U32(0)));
+    assert_eq!(components.take::<U32>(), Some(U32(0)));
+    assert!(components.is_empty());
+
+    assert!(!all_storages.is_entity_alive(entity));
+    assert_eq!(*all_storages.get::<&U32>(other).unwrap(), &U32(3));
+    assert_eq!(
+        all_storages
+            .borrow::<View<Tracked>>()
+            .unwrap()
+            .deleted()
+            .collect::<Vec<_>>(),
+        [(entity, &Tracked(1))]
+    );
+    assert!(all_storages.get::<&Dropped>(entity).is_err());
+
+    assert!(all_storages.delete_entity_collect(entity).is_empty());
+}
@@ -54,10 +54,20 @@ fn default() {
     world.borrow::<CustomView>().unwrap();
 }
 
-#[allow(unused)]
 #[derive(Hash, Debug, PartialEq, Clone, Label)]
 struct MyLabel;
 
+#[test]
+fn label() {
+    let world = World::new();
+
+    Workload::new(MyLabel).add_to_world(&world).unwrap();
+
+    assert!(world.contains_workload(MyLabel));
+    assert!(!world.contains_workload("MyLabel"));
+    world.run_workload(MyLabel).unwrap();
+}
+
 #[test]
 fn into_iter_rename() {
     #[derive(Component)]
@@ -128,3 +138,55 @@ fn into_iter_field_visibility() {
     // Should not be accessible
     // view.v_comp_bb;
 }
+
+#[test]
+fn grouped_uniques() {
+    use shipyard::borrow::Mutability;
+
+    #[derive(Unique)]
+    struct Time(f32);
+
+    #[derive(Unique)]
+    struct Score(u32);
+
+    #[derive(Component)]
+    struct Pos(f32);
+
+    #[derive(Borrow, BorrowInfo)]
+    struct Resources<'v> {
+        time: UniqueView<'v, Time>,
+        score: UniqueViewMut<'v, Score>,
+        positions: View<'v, Pos>,
+    }
+
+    fn system(mut resources: Resources) {
+        resources.score.0 += resources.positions.len() as u32 * resources.time.0 as u32;
+    }
+
+    let world = World::new();
+    world.add_unique(Time(2.0));
+    world.add_unique(Score(0));
+    world.add_entity(Pos(0.0));
+
+    Workload::new("")
+        .with_system(system)
+        .add_to_world(&world)
+        .unwrap();
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Score>>().unwrap().0, 2);
+
+    let info = world.workloads_info();
+    let borrows = &info.0[""].batches_info[0].systems().next().unwrap().borrow;
+
+    assert_eq!(borrows.len(), 3);
+    assert!(borrows
+        .iter()
+        .any(|info| info.name.contains("Score") && info.mutability == Mutability::Exclusive));
+    assert!(borrows
+        .iter()
+        .any(|info| info.name.contains("Time") && info.mutability == Mutability::Shared));
+    assert!(borrows
+        .iter()
+        .any(|info| info.name.contains("Pos") && info.mutability == Mutability::Shared));
+}
@@ -0,0 +1,250 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn entities_full() {
+    let mut world = World::with_fixed_capacity(2);
+
+    let entity0 = world.add_entity_checked((U32(0),)).unwrap();
+    world.add_entity_checked((U32(1), USIZE(1))).unwrap();
+
+    assert_eq!(
+        world.add_entity_checked((U32(2),)),
+        Err(error::AddEntity::EntitiesFull)
+    );
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 2);
+
+    world.delete_entity(entity0);
+
+    let entity2 = world.add_entity_checked((U32(2),)).unwrap();
+    assert_eq!(entity2.index(), entity0.index());
+}
+
+#[test]
+#[should_panic(expected = "Cannot add an entity, the World has a fixed capacity and is full.")]
+fn add_entity_full() {
+    let mut world = World::with_fixed_capacity(1);
+
+    world.add_entity(U32(0));
+    world.add_entity(U32(1));
+}
+
+#[test]
+fn storage_full() {
+    let mut world = World::with_fixed_capacity(2);
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1),));
+
+    // Deleting without touching the components keeps the storage full
+    world
+        .borrow::<EntitiesViewMut>()
+        .unwrap()
+        .delete_unchecked(entity1);
+
+    // No component is added when one of the storages is full
+    assert_eq!(
+        world.add_entity_checked((USIZE(2), U32(2))),
+        Err(error::AddEntity::StorageFull(core::any::type_name::<U32>()))
+    );
+    assert!(world.borrow::<View<USIZE>>().unwrap().is_empty());
+
+    // Replacing a component doesn't need more room
+    world.add_component_checked(entity0, (U32(10),)).unwrap();
+    assert_eq!(*world.get::<&U32>(entity0).unwrap(), &U32(10));
+
+    let entity2 = world.add_entity(());
+    assert_eq!(
+        world.add_component_checked(entity2, (USIZE(2), U32(2))),
+        Err(error::AddComponent::StorageFull(
+            core::any::type_name::<U32>()
+        ))
+    );
+    assert!(world.borrow::<View<USIZE>>().unwrap().is_empty());
+
+    assert_eq!(
+        world
+            .all_storages()
+            .unwrap()
+            .try_add_component(entity2, (USIZE(2), U32(2))),
+        Err(error::TryAddComponent::AddComponent(
+            error::AddComponent::StorageFull(core::any::type_name::<U32>())
+        ))
+    );
+    assert!(world.borrow::<View<USIZE>>().unwrap().is_empty());
+}
+
+#[test]
+fn no_reallocation() {
+    let mut world = World::with_fixed_capacity(64);
+    world.register_with_capacity::<U32>(64);
+
+    let first = world.add_entity(U32(0));
+    let address: *const U32 = *world.get::<&U32>(first).unwrap();
+
+    for i in 1..64 {
+        world.add_entity(U32(i));
+    }
+
+    let new_address: *const U32 = *world.get::<&U32>(first).unwrap();
+    assert_eq!(new_address, address);
+}
+
+#[test]
+fn storages_reserved_on_creation() {
+    let mut world = World::with_fixed_capacity(64);
+
+    let first = world.add_entity((U32(0), USIZE(0)));
+    let address: *const USIZE = *world.get::<&USIZE>(first).unwrap();
+
+    world.bulk_add_entity((1..32).map(|i| (U32(i), USIZE(i as usize))));
+    world.run(
+        |mut entities: EntitiesViewMut, mut usizes: ViewMut<USIZE>| {
+            for i in 32..64 {
+                entities.add_entity(&mut usizes, USIZE(i));
+            }
+        },
+    );
+
+    let new_address: *const USIZE = *world.get::<&USIZE>(first).unwrap();
+    assert_eq!(new_address, address);
+}
+
+#[test]
+#[should_panic(expected = "storage is full, the World has a fixed capacity.")]
+fn view_insert_full() {
+    let mut world = World::with_fixed_capacity(2);
+
+    let entity0 = world.add_entity(U32(0));
+    world.add_entity(U32(1));
+
+    // Deleting without touching the components keeps the storage full
+    world
+        .borrow::<EntitiesViewMut>()
+        .unwrap()
+        .delete_unchecked(entity0);
+
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>| {
+        let entity = entities.add_entity((), ());
+        u32s.add_component_unchecked(entity, U32(2));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Cannot add an entity, the World has a fixed capacity and is full.")]
+fn bulk_add_entity_full() {
+    let mut world = World::with_fixed_capacity(2);
+
+    world.bulk_add_entity((0..3).map(|i| (U32(i),)));
+}
+
+#[test]
+fn bulk_add_entity_full_keeps_storages() {
+    let mut world = World::with_fixed_capacity(2);
+
+    world.add_entity((U32(0), USIZE(0)));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.bulk_add_entity((1..3).map(|i| (U32(i), USIZE(i as usize))));
+    }));
+    assert!(result.is_err());
+
+    world.add_entity((U32(1), USIZE(1)));
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 2);
+    assert_eq!(world.borrow::<View<USIZE>>().unwrap().len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Cannot add an entity, the World has a fixed capacity and is full.")]
+fn spawn_batch_with_full() {
+    let world = World::with_fixed_capacity(2);
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.spawn_batch_with(3, |i| -> (U32,) { panic!("called for {i}") });
+}
+
+#[test]
+fn spawn_beyond_capacity() {
+    let mut world = World::with_fixed_capacity(2);
+
+    assert!(!world.spawn(EntityId::new_from_index_and_gen(2, 0)));
+    assert!(world.spawn(EntityId::new_from_index_and_gen(1, 0)));
+}
+
+#[test]
+fn builder() {
+    let mut world = World::builder().with_fixed_capacity(1).build();
+
+    world.add_entity(());
+
+    assert_eq!(
+        world.add_entity_checked(()),
+        Err(error::AddEntity::EntitiesFull)
+    );
+}
+
+#[test]
+fn transfer_entity_full() {
+    let mut world1 = World::new();
+    let mut world2 = World::with_fixed_capacity(1);
+
+    let entity = world1.add_entity((U32(0), USIZE(0)));
+    world2.add_entity((U32(1),));
+
+    assert_eq!(
+        world1.transfer_entity(
+            &mut world2,
+            entity,
+            &[advanced::StorageId::of::<sparse_set::SparseSet<U32>>()]
+        ),
+        Err(error::TransferEntity::EntitiesFull)
+    );
+
+    // The source is left untouched
+    assert!(world1.is_entity_alive(entity));
+    assert_eq!(world1.get::<&U32>(entity).as_deref(), Ok(&&U32(0)));
+    assert_eq!(world1.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(0)));
+}
+
+#[test]
+fn transfer_entity_storage_full() {
+    let mut world1 = World::new();
+    let mut world2 = World::with_fixed_capacity(2);
+
+    let entity = world1.add_entity((U32(0), USIZE(0)));
+    world2.add_entity((U32(1),));
+    let deleted = world2.add_entity((U32(2),));
+
+    // Deleting without touching the components keeps the storage full
+    world2
+        .borrow::<EntitiesViewMut>()
+        .unwrap()
+        .delete_unchecked(deleted);
+
+    let storages = [
+        advanced::StorageId::of::<sparse_set::SparseSet<USIZE>>(),
+        advanced::StorageId::of::<sparse_set::SparseSet<U32>>(),
+    ];
+    let u32_name = core::any::type_name::<U32>();
+
+    assert_eq!(
+        world1.transfer_entity(&mut world2, entity, &storages),
+        Err(error::TransferEntity::StorageFull(u32_name))
+    );
+
+    // The source is left untouched
+    assert!(world1.is_entity_alive(entity));
+    assert_eq!(world1.get::<&U32>(entity).as_deref(), Ok(&&U32(0)));
+    assert_eq!(world1.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(0)));
+}
@@ -140,3 +140,236 @@ fn old_id() {
         assert!(u32s.get(entity1).is_err());
     });
 }
+
+#[test]
+fn world_get() {
+    #[derive(Debug, PartialEq)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Modification;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    world.borrow::<ViewMut<U32>>().unwrap().clear_all_modified();
+
+    {
+        let u32 = world.get::<&U32>(entity).unwrap();
+        assert_eq!(**u32, U32(0));
+
+        // the guard keeps the storage borrowed
+        assert!(world.borrow::<ViewMut<U32>>().is_err());
+    }
+
+    {
+        let mut u32 = world.get::<&mut U32>(entity).unwrap();
+        u32.0 += 1;
+    }
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(u32s[entity], U32(1));
+    assert!(u32s.is_modified(entity));
+}
+
+#[test]
+fn get_with_id() {
+    #[derive(Debug, PartialEq)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Untracked;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity1 = world.add_entity((U32(0), USIZE(1)));
+    let entity2 = world.add_entity(U32(2));
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+
+    assert_eq!(
+        (&u32s, &usizes).get_with_id(entity1),
+        Ok((entity1, (&U32(0), &USIZE(1))))
+    );
+    assert_eq!(u32s.get_with_id(entity2), Ok((entity2, &U32(2))));
+    assert!((&u32s, &usizes).get_with_id(entity2).is_err());
+}
+
+#[test]
+fn missing_component_name() {
+    #[derive(Component)]
+    struct U32(u32);
+    #[derive(Component)]
+    struct I16(i16);
+    #[derive(Component)]
+    struct U8(u8);
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), U8(0)));
+
+    let (u32s, i16s, u8s) = world.borrow::<(View<U32>, View<I16>, View<U8>)>().unwrap();
+
+    let err = (&u32s, &i16s, &u8s).get(entity).err().unwrap();
+
+    assert_eq!(
+        err,
+        error::MissingComponent {
+            id: entity,
+            name: core::any::type_name::<I16>(),
+        }
+    );
+    assert!(format!("{}", err).contains("I16"));
+}
+
+#[test]
+fn get_or() {
+    #[derive(Component, Clone, Copy, Debug, PartialEq)]
+    struct U32(u32);
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    world.delete_entity(entity);
+    // Same index, newer generation
+    let new_entity = world.add_entity(U32(1));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    assert_eq!(u32s.get_or(entity, &U32(2)), &U32(2));
+    assert_eq!(u32s.get_or(new_entity, &U32(2)), &U32(1));
+    assert_eq!(u32s.get_copied_or(entity, U32(2)), U32(2));
+    assert_eq!(u32s.get_copied_or(new_entity, U32(2)), U32(1));
+}
+
+#[test]
+fn get_cloned() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Modification;
+    }
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Name(String);
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), Name("0".to_string())));
+    let missing = world.add_entity(U32(1));
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_modified());
+
+    let (mut u32s, names) = world.borrow::<(ViewMut<U32>, View<Name>)>().unwrap();
+
+    assert_eq!(
+        (&mut u32s, &names).get_cloned(entity),
+        Some((U32(0), Name("0".to_string())))
+    );
+    assert_eq!((&mut u32s, &names).get_cloned(missing), None);
+    assert!(!u32s.is_modified(entity));
+
+    assert_eq!(names.get_cloned(entity), Some(Name("0".to_string())));
+    assert_eq!(names.get_cloned(missing), None);
+}
+
+#[test]
+fn get_optional() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Modification;
+    }
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Name(String);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Health(u32);
+
+    let mut world = World::new();
+
+    let full = world.add_entity((U32(0), Name("0".to_string()), Health(10)));
+    let partial = world.add_entity(U32(1));
+    let missing = world.add_entity(Name("2".to_string()));
+
+    let (mut u32s, names, healths) = world
+        .borrow::<(ViewMut<U32>, View<Name>, View<Health>)>()
+        .unwrap();
+
+    assert_eq!(
+        (&u32s, names.as_optional(), healths.as_optional()).get(full),
+        Ok((&U32(0), Some(&Name("0".to_string())), Some(&Health(10))))
+    );
+    assert_eq!(
+        (&u32s, names.as_optional(), healths.as_optional()).get(partial),
+        Ok((&U32(1), None, None))
+    );
+    assert_eq!(
+        (&u32s, names.as_optional()).get(missing),
+        Err(error::MissingComponent {
+            id: missing,
+            name: core::any::type_name::<U32>(),
+        })
+    );
+
+    let (number, name) = (u32s.as_optional(), &names).get(missing).unwrap();
+    assert!(number.is_none());
+    assert_eq!(name, &Name("2".to_string()));
+
+    let (number, _) = (u32s.as_optional(), names.as_optional())
+        .get(partial)
+        .unwrap();
+    number.unwrap().0 += 1;
+    assert!(u32s.is_modified(partial));
+    assert_eq!(u32s[partial], U32(2));
+
+    assert_eq!(
+        (&u32s, names.as_optional()).get_cloned(partial),
+        Some((U32(2), None))
+    );
+}
+
+#[test]
+fn get_disjoint_mut() {
+    #[derive(Debug, PartialEq)]
+    struct Pos(u32);
+    impl Component for Pos {
+        type Tracking = track::Modification;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Vel(u32);
+    impl Component for Vel {
+        type Tracking = track::Modification;
+    }
+
+    let mut world = World::new();
+
+    let both = world.add_entity((Pos(0), Vel(1)));
+    let partial = world.add_entity(Vel(2));
+
+    world.clear_all_inserted_and_modified();
+
+    let (mut positions, mut velocities) = world.borrow::<(ViewMut<Pos>, ViewMut<Vel>)>().unwrap();
+
+    let (pos, vel) = positions.get_disjoint_mut(&mut velocities, both);
+    pos.unwrap().0 += vel.unwrap().0;
+
+    assert_eq!(positions[both], Pos(1));
+    assert!(positions.is_modified(both));
+    assert!(!velocities.is_modified(both));
+
+    let (pos, vel) = positions.get_disjoint_mut(&mut velocities, partial);
+    assert!(pos.is_none());
+    vel.unwrap().0 = 3;
+
+    assert_eq!(velocities[partial], Vel(3));
+    assert!(velocities.is_modified(partial));
+}
@@ -0,0 +1,212 @@
+#![cfg(feature = "hierarchy")]
+
+use shipyard::hierarchy::{Children, HierarchyView, HierarchyViewMut, Parent};
+use shipyard::*;
+
+#[test]
+fn attach_detach() {
+    let mut world = World::new();
+
+    let root1 = world.add_entity(());
+    let root2 = world.add_entity(());
+    let e1 = world.add_entity(());
+    let e2 = world.add_entity(());
+    let e3 = world.add_entity(());
+    let e4 = world.add_entity(());
+    let e5 = world.add_entity(());
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+    hierarchy.attach_child(root1, e1);
+    hierarchy.attach_child(e1, e2);
+    hierarchy.attach_child(e1, e3);
+    hierarchy.attach_child(e3, e4);
+    hierarchy.attach_child(root2, e3);
+    hierarchy.attach_child(e3, e5);
+
+    assert_eq!(
+        hierarchy.iter_children(e3).collect::<Vec<_>>(),
+        vec![e4, e5]
+    );
+    assert_eq!(hierarchy.iter_children(e3).len(), 2);
+    assert_eq!(
+        hierarchy.iter_ancestors(e4).collect::<Vec<_>>(),
+        vec![e3, root2]
+    );
+    assert_eq!(
+        hierarchy
+            .iter_descendants_depth_first(root1)
+            .collect::<Vec<_>>(),
+        vec![e1, e2]
+    );
+
+    assert!(hierarchy.detach(e1));
+    assert!(!hierarchy.detach(e1));
+    assert_eq!(hierarchy.parent(e1), None);
+    assert_eq!(hierarchy.iter_children(root1).count(), 0);
+    assert_eq!(hierarchy.iter_children(e1).collect::<Vec<_>>(), vec![e2]);
+
+    hierarchy.detach_children(e3);
+    assert_eq!(hierarchy.iter_children(e3).count(), 0);
+    assert_eq!(hierarchy.parent(e4), None);
+    assert_eq!(hierarchy.parent(e5), None);
+    drop(hierarchy);
+
+    assert!(!world.borrow::<View<Children>>().unwrap().contains(root1));
+    assert!(!world.borrow::<View<Parent>>().unwrap().contains(e4));
+}
+
+#[test]
+fn descendants_order() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let a = world.add_entity(());
+    let b = world.add_entity(());
+    let a1 = world.add_entity(());
+    let a2 = world.add_entity(());
+    let b1 = world.add_entity(());
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+        hierarchy.attach_child(root, a);
+        hierarchy.attach_child(root, b);
+        hierarchy.attach_child(a, a1);
+        hierarchy.attach_child(a, a2);
+        hierarchy.attach_child(b, b1);
+    }
+
+    world.run(|hierarchy: HierarchyView| {
+        assert_eq!(
+            hierarchy
+                .iter_descendants_depth_first(root)
+                .collect::<Vec<_>>(),
+            vec![a, a1, a2, b, b1]
+        );
+        assert_eq!(
+            hierarchy
+                .iter_descendants_breadth_first(root)
+                .collect::<Vec<_>>(),
+            vec![a, b, a1, a2, b1]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Cannot attach an entity to itself or to one of its descendants.")]
+fn cycle() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let child = world.add_entity(());
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+    hierarchy.attach_child(root, child);
+    hierarchy.attach_child(child, root);
+}
+
+#[test]
+fn delete() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let child = world.add_entity(());
+    let grand_child = world.add_entity(());
+    let other = world.add_entity(());
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+        hierarchy.attach_child(root, child);
+        hierarchy.attach_child(root, other);
+        hierarchy.attach_child(child, grand_child);
+    }
+
+    assert!(world.delete_in_hierarchy(child, true));
+    assert!(!world.is_entity_alive(child));
+    assert!(!world.is_entity_alive(grand_child));
+    assert_eq!(
+        world
+            .borrow::<HierarchyView>()
+            .unwrap()
+            .iter_children(root)
+            .collect::<Vec<_>>(),
+        vec![other]
+    );
+
+    assert!(world.delete_in_hierarchy(root, false));
+    assert!(world.is_entity_alive(other));
+    assert_eq!(world.borrow::<HierarchyView>().unwrap().parent(other), None);
+}
+
+#[test]
+fn dangling_child() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let first = world.add_entity(());
+    let second = world.add_entity(());
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+        hierarchy.attach_child(root, first);
+        hierarchy.attach_child(root, second);
+    }
+
+    // Deleting the entity directly doesn't detach it
+    world.delete_entity(first);
+
+    let hierarchy = world.borrow::<HierarchyView>().unwrap();
+
+    assert_eq!(hierarchy.iter_children(root).count(), 0);
+    assert_eq!(hierarchy.iter_descendants_depth_first(root).count(), 0);
+    assert_eq!(hierarchy.iter_descendants_breadth_first(root).count(), 0);
+}
+
+#[test]
+fn dangling_child_detach_attach() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let first = world.add_entity(());
+    let second = world.add_entity(());
+    let third = world.add_entity(());
+    let fourth = world.add_entity(());
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+        hierarchy.attach_child(root, first);
+        hierarchy.attach_child(root, second);
+        hierarchy.attach_child(root, third);
+    }
+
+    // Deleting the entities directly doesn't detach them
+    world.delete_entity(first);
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+        hierarchy.attach_child(root, fourth);
+        assert_eq!(
+            hierarchy.iter_children(root).collect::<Vec<_>>(),
+            [second, third, fourth]
+        );
+    }
+
+    world.delete_entity(third);
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+    assert!(hierarchy.detach(second));
+    assert_eq!(hierarchy.iter_children(root).collect::<Vec<_>>(), [fourth]);
+
+    assert!(hierarchy.detach(fourth));
+    assert!(hierarchy.iter_children(root).next().is_none());
+
+    hierarchy.attach_child(root, second);
+    assert_eq!(hierarchy.iter_children(root).collect::<Vec<_>>(), [second]);
+}
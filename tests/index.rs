@@ -0,0 +1,40 @@
+use shipyard::index::Index;
+use shipyard::*;
+
+#[derive(Clone, Copy)]
+struct Team(u32);
+impl Component for Team {
+    type Tracking = track::All;
+}
+
+#[test]
+fn index() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Team(0));
+    let e1 = world.add_entity(Team(1));
+    let e2 = world.add_entity(Team(0));
+
+    let mut index = Index::new(|team: &Team| team.0);
+
+    index.update(&world.borrow::<View<Team>>().unwrap());
+    assert_eq!(index.lookup(&0), &[e0, e2]);
+    assert_eq!(index.lookup(&1), &[e1]);
+    assert!(index.lookup(&2).is_empty());
+
+    world.run(|mut teams: ViewMut<Team>| {
+        (&mut teams).get(e0).unwrap().0 = 1;
+    });
+    world.remove::<Team>(e2);
+
+    index.update(&world.borrow::<View<Team>>().unwrap());
+    assert!(index.lookup(&0).is_empty());
+    assert_eq!(index.lookup(&1), &[e1, e0]);
+    assert_eq!(index.key_of(e0), Some(&1));
+    assert_eq!(index.key_of(e2), None);
+
+    world.add_component(e2, Team(1));
+
+    index.update(&world.borrow::<View<Team>>().unwrap());
+    assert_eq!(index.lookup(&1), &[e1, e0, e2]);
+}
@@ -339,3 +339,103 @@ fn vec() {
         assert_eq!(eids, other_eids);
     });
 }
+
+#[test]
+fn rev() {
+    let world = World::new();
+
+    let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+
+    let id0 = entities.add_entity(&mut u32s, U32(0));
+    let id1 = entities.add_entity(&mut u32s, U32(1));
+    let id2 = entities.add_entity(&mut u32s, U32(2));
+
+    assert_eq!(
+        (&u32s).iter().rev().collect::<Vec<_>>(),
+        vec![&U32(2), &U32(1), &U32(0)]
+    );
+
+    let mut iter = (&u32s).iter().with_id();
+    assert_eq!(iter.next_back(), Some((id2, &U32(2))));
+    assert_eq!(iter.next(), Some((id0, &U32(0))));
+    assert_eq!(iter.next_back(), Some((id1, &U32(1))));
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(
+        (&u32s).iter().with_id().rev().collect::<Vec<_>>(),
+        vec![(id2, &U32(2)), (id1, &U32(1)), (id0, &U32(0))]
+    );
+}
+
+#[test]
+fn iter_except() {
+    use std::collections::HashSet;
+
+    let mut world = World::new();
+
+    let entities = (0..10)
+        .map(|i| world.add_entity(U32(i)))
+        .collect::<Vec<_>>();
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    let exclude: HashSet<EntityId> = entities.iter().copied().step_by(2).collect();
+    let remaining = u32s
+        .iter_except(&exclude)
+        .map(|(id, u)| (id, u.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        remaining,
+        entities
+            .iter()
+            .copied()
+            .zip(0..)
+            .filter(|(_, i)| i % 2 == 1)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(u32s.iter_except(&HashSet::new()).count(), 10);
+}
+
+#[test]
+fn windows() {
+    let mut world = World::new();
+
+    for i in [3, 1, 2, 0] {
+        world.add_entity(U32(i));
+    }
+
+    world
+        .borrow::<ViewMut<U32>>()
+        .unwrap()
+        .sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    let windows = u32s
+        .windows(3)
+        .map(|window| [window[0].0, window[1].0, window[2].0])
+        .collect::<Vec<_>>();
+
+    assert_eq!(windows, [[0, 1, 2], [1, 2, 3]]);
+    assert_eq!(u32s.windows(5).count(), 0);
+}
+
+#[test]
+fn sample() {
+    let mut world = World::new();
+
+    let ids = (0..7).map(|i| world.add_entity(U32(i))).collect::<Vec<_>>();
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    let sampled = u32s
+        .sample(3)
+        .map(|(id, u32)| (id, u32.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(sampled, [(ids[0], 0), (ids[3], 3), (ids[6], 6)]);
+    assert_eq!(u32s.sample(1).count(), 7);
+    assert_eq!(u32s.sample(10).count(), 1);
+}
@@ -191,6 +191,79 @@ fn parallel_iterator() {
     });
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn parallel_iterator_with_id() {
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    let eid0 = world.add_entity((USIZE(0), U32(1)));
+    world.add_entity(USIZE(2));
+    let eid2 = world.add_entity((USIZE(4), U32(5)));
+
+    world.run(|usizes: View<USIZE>, u32s: View<U32>| {
+        let mut ids = (&usizes, &u32s)
+            .par_iter()
+            .with_id()
+            .map(|(id, (x, y))| (id, x.0 + y.0 as usize))
+            .collect::<Vec<_>>();
+        ids.sort_unstable_by_key(|(_, sum)| *sum);
+
+        assert_eq!(ids, vec![(eid0, 1), (eid2, 9)]);
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn parallel_collect_into_vec() {
+    use rayon::prelude::*;
+    use shipyard::iter::ParCollectIntoVec;
+
+    let mut world = World::new();
+
+    world.bulk_add_entity((0..1000).map(|i| (USIZE(i),)));
+
+    let mut buffer = Vec::new();
+
+    world.run(|usizes: View<USIZE>| {
+        usizes
+            .par_iter()
+            .with_id()
+            .map(|(id, x)| (id, x.0 * 2))
+            .collect_into_buffer(&mut buffer);
+
+        let sequential = usizes
+            .iter()
+            .with_id()
+            .map(|(id, x)| (id, x.0 * 2))
+            .collect::<Vec<_>>();
+
+        assert_eq!(buffer, sequential);
+    });
+
+    let capacity = buffer.capacity();
+
+    world.run(|usizes: View<USIZE>| {
+        usizes
+            .par_iter()
+            .map(|x| (EntityId::dead(), x.0))
+            .collect_into_buffer(&mut buffer);
+    });
+
+    assert_eq!(buffer.len(), 1000);
+    assert_eq!(buffer[999], (EntityId::dead(), 999));
+    assert_eq!(buffer.capacity(), capacity);
+
+    // rayon's indexed iterators have their own `collect_into_vec`
+    let mut numbers = Vec::new();
+    (0..10).into_par_iter().collect_into_buffer(&mut numbers);
+    (0..10).into_par_iter().collect_into_vec(&mut numbers);
+    assert_eq!(numbers, (0..10).collect::<Vec<_>>());
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
 #[test]
@@ -443,6 +516,36 @@ fn contains() {
     );
 }
 
+#[test]
+fn dyn_storage() {
+    use shipyard::advanced::StorageId;
+    use shipyard::all_storages::CustomStorageAccess;
+    use shipyard::sparse_set::SparseSet;
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((USIZE(0), U32(1)));
+    world.add_entity(USIZE(2));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let storage_id = StorageId::of::<SparseSet<USIZE>>();
+
+    let storage = all_storages.custom_storage_by_id(storage_id).unwrap();
+    assert_eq!(storage.len(), Some(2));
+    assert_eq!(storage.contains(entity), Some(true));
+    drop(storage);
+
+    let current = all_storages.get_tracking_timestamp();
+    let mut storage = all_storages.custom_storage_mut_by_id(storage_id).unwrap();
+    storage.delete(entity, current);
+    assert_eq!(storage.len(), Some(1));
+    assert_eq!(storage.contains(entity), Some(false));
+    drop(storage);
+    drop(all_storages);
+
+    assert!(world.get::<&U32>(entity).is_ok());
+}
+
 #[test]
 fn debug() {
     let mut world = World::new();
@@ -458,3 +561,33 @@ fn debug() {
         );
     });
 }
+
+#[test]
+fn debug_dump() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.register_debug::<USIZE>();
+
+    all_storages.add_entity((USIZE(0), U32(0)));
+    all_storages.add_entity((USIZE(1), U32(1)));
+    all_storages.add_entity((USIZE(2),));
+
+    let dump = all_storages.debug_dump(2);
+    let usizes = format!(
+        "{}: 3 components\n  EId(0.0): USIZE(0)\n  EId(1.0): USIZE(1)\n  ... 1 more",
+        core::any::type_name::<sparse_set::SparseSet<USIZE>>()
+    );
+    let u32s = format!(
+        "{}: 2 components",
+        core::any::type_name::<sparse_set::SparseSet<U32>>()
+    );
+
+    assert!(dump.contains(&usizes));
+    assert!(dump.lines().any(|line| line == u32s));
+    assert!(!dump.contains("U32(0)"));
+
+    let _u32s = all_storages.borrow::<ViewMut<U32>>().unwrap();
+
+    assert!(all_storages.debug_dump(2).contains("borrowed"));
+}
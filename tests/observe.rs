@@ -0,0 +1,227 @@
+use shipyard::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Shield;
+
+#[test]
+fn direct_insert() {
+    let mut world = World::new();
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    let player = world.add_entity(Player);
+    assert_eq!(world.get::<&Health>(player).unwrap().0, 100);
+
+    let other = world.add_entity(());
+    world.add_component(other, Player);
+    assert_eq!(world.get::<&Health>(other).unwrap().0, 100);
+}
+
+#[test]
+fn reentrant_ordering() {
+    let mut world = World::new();
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let player_order = order.clone();
+    world.observe_insert::<Player>(move |all_storages, entity| {
+        player_order.lock().unwrap().push("player");
+        all_storages.add_component(entity, Health(100));
+    });
+    let health_order = order.clone();
+    world.observe_insert::<Health>(move |all_storages, entity| {
+        health_order.lock().unwrap().push("health");
+        all_storages.add_component(entity, Shield);
+    });
+    let shield_order = order.clone();
+    world.observe_insert::<Shield>(move |_, _| {
+        shield_order.lock().unwrap().push("shield");
+    });
+
+    let player = world.add_entity(Player);
+
+    assert!(world.get::<&Shield>(player).is_ok());
+    assert_eq!(*order.lock().unwrap(), ["player", "health", "shield"]);
+}
+
+#[test]
+fn system_insert() {
+    let mut world = World::new();
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    let player = world.run(
+        |mut entities: EntitiesViewMut, mut players: ViewMut<Player>| {
+            entities.add_entity(&mut players, Player)
+        },
+    );
+    assert_eq!(world.get::<&Health>(player).unwrap().0, 100);
+
+    Workload::new("")
+        .with_system(
+            |mut entities: EntitiesViewMut, mut players: ViewMut<Player>, healths: View<Health>| {
+                assert_eq!(healths.len(), 1);
+
+                entities.add_entity(&mut players, Player);
+            },
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("").unwrap();
+
+    assert_eq!(world.borrow::<View<Health>>().unwrap().len(), 2);
+}
+
+#[test]
+fn bulk_insert() {
+    let mut world = World::new();
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    let players = world
+        .bulk_add_entity((0..3).map(|_| Player))
+        .collect::<Vec<_>>();
+    world.run_observers().unwrap();
+
+    for player in players {
+        assert_eq!(world.get::<&Health>(player).unwrap().0, 100);
+    }
+}
+
+#[test]
+fn bulk_insert_tuple() {
+    let mut world = World::new();
+    let inserted = Arc::new(Mutex::new(Vec::new()));
+
+    world.add_entity(Health(0));
+
+    let observed = inserted.clone();
+    world.observe_insert::<Health>(move |_, entity| {
+        observed.lock().unwrap().push(entity);
+    });
+
+    let players = world
+        .bulk_add_entity((0..3).map(|_| (Player, Health(100))))
+        .collect::<Vec<_>>();
+    world.run_observers().unwrap();
+
+    assert_eq!(*inserted.lock().unwrap(), players);
+}
+
+#[test]
+fn depth_limit() {
+    let mut world = World::new();
+
+    world.observe_insert::<Health>(|all_storages, entity| {
+        all_storages.add_component(entity, Shield);
+    });
+    world.observe_insert::<Shield>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(0));
+    });
+
+    world.bulk_add_entity([Health(0)]).for_each(drop);
+
+    assert_eq!(world.run_observers(), Err(error::Observe::DepthLimit));
+    assert_eq!(world.run_observers(), Ok(()));
+}
+
+#[test]
+fn try_run_depth_limit() {
+    let mut world = World::new();
+
+    world.observe_insert::<Health>(|all_storages, entity| {
+        all_storages.add_component(entity, Shield);
+    });
+    world.observe_insert::<Shield>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(0));
+    });
+
+    assert!(matches!(
+        world.try_run(
+            |mut entities: EntitiesViewMut, mut healths: ViewMut<Health>| {
+                entities.add_entity(&mut healths, Health(0));
+            }
+        ),
+        Err(error::Run::Observe(error::Observe::DepthLimit))
+    ));
+}
+
+#[test]
+fn all_storages_insert() {
+    let mut world = World::new();
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        let player = all_storages.add_entity(Player);
+        assert_eq!(all_storages.get::<&Health>(player).unwrap().0, 100);
+
+        let other = all_storages.add_entity(());
+        all_storages.add_component(other, Player);
+        assert_eq!(all_storages.get::<&Health>(other).unwrap().0, 100);
+    });
+}
+
+#[test]
+fn sequential_systems() {
+    let mut world = World::new();
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    Workload::new("")
+        .with_system(
+            |mut entities: EntitiesViewMut, mut players: ViewMut<Player>| {
+                entities.add_entity(&mut players, Player);
+            },
+        )
+        .with_system(|healths: View<Health>| {
+            assert_eq!(healths.len(), 1);
+        })
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("").unwrap();
+}
+
+#[test]
+fn on_insertion_keeps_observers() {
+    let mut world = World::new();
+    let inserted = Arc::new(Mutex::new(Vec::new()));
+
+    world.observe_insert::<Player>(|all_storages, entity| {
+        all_storages.add_component(entity, Health(100));
+    });
+
+    let callback = inserted.clone();
+    world
+        .borrow::<ViewMut<Player>>()
+        .unwrap()
+        .on_insertion(move |entity, _| callback.lock().unwrap().push(entity));
+    let callback = inserted.clone();
+    world
+        .borrow::<ViewMut<Player>>()
+        .unwrap()
+        .on_insertion(move |entity, _| callback.lock().unwrap().push(entity));
+
+    let player = world.add_entity(Player);
+
+    assert_eq!(*inserted.lock().unwrap(), [player]);
+    assert_eq!(world.get::<&Health>(player).unwrap().0, 100);
+}
@@ -0,0 +1,104 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn tight_pack() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(U32(0));
+    let e1 = world.add_entity(USIZE(1));
+    let e2 = world.add_entity((U32(2), USIZE(2)));
+    let e3 = world.add_entity((USIZE(3), U32(3)));
+
+    assert!(!world.is_packed::<(U32, USIZE)>());
+
+    world.tight_pack::<(U32, USIZE)>();
+    assert!(world.is_packed::<(U32, USIZE)>());
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    assert_eq!(u32s.packed_len(), Some(2));
+    assert_eq!(usizes.packed_len(), Some(2));
+    assert_eq!(u32s.id_at(0), usizes.id_at(0));
+    assert_eq!(u32s.id_at(1), usizes.id_at(1));
+    assert_eq!(u32s.index_of(e0), Some(2));
+    assert_eq!(usizes.index_of(e1), Some(2));
+    drop((u32s, usizes));
+
+    // Adding components and removing unpacked ones keeps the pack
+    let e4 = world.add_entity((U32(4), USIZE(4)));
+    world.remove::<U32>(e0);
+    world.delete_entity(e4);
+    assert!(world.is_packed::<(U32, USIZE)>());
+
+    world.remove::<USIZE>(e2);
+    assert!(!world.is_packed::<(U32, USIZE)>());
+    assert_eq!(world.borrow::<View<USIZE>>().unwrap().packed_len(), None);
+    assert_eq!(world.borrow::<View<U32>>().unwrap().packed_len(), None);
+
+    world.tight_pack::<(U32, USIZE)>();
+    assert!(world.is_packed::<(U32, USIZE)>());
+    assert_eq!(world.borrow::<View<U32>>().unwrap().packed_len(), Some(1));
+
+    world.run(|mut u32s: ViewMut<U32>| u32s.sort_unstable_by(|a, b| a.0.cmp(&b.0)));
+    assert!(!world.is_packed::<(U32, USIZE)>());
+
+    world.tight_pack::<(U32, USIZE)>();
+    world.delete_entity(e3);
+    assert!(!world.is_packed::<(U32, USIZE)>());
+}
+
+#[test]
+fn is_packed_missing_storage() {
+    let world = World::new();
+    let storage_count = world.storage_count();
+
+    assert!(!world.is_packed::<(U32, USIZE)>());
+    assert_eq!(world.storage_count(), storage_count);
+}
+
+#[test]
+fn packed_iter() {
+    let mut world = World::new();
+
+    world.add_entity(U32(0));
+    let e1 = world.add_entity((U32(1), USIZE(1)));
+    world.add_entity((USIZE(2), U32(2)));
+
+    world.tight_pack::<(U32, USIZE)>();
+
+    world.run(|u32s: View<U32>, mut usizes: ViewMut<USIZE>| {
+        let iter = (&u32s, &mut usizes).iter();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        for (i, j) in iter {
+            assert_eq!(i.0 as usize, j.0);
+        }
+    });
+
+    // A new entity with both components is outside the packed components
+    world.add_entity((U32(4), USIZE(4)));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        let iter = (&u32s, &usizes).iter();
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_eq!(iter.count(), 3);
+    });
+
+    world.tight_pack::<(U32, USIZE)>();
+    world.delete_entity(e1);
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!((&u32s, &usizes).iter().count(), 2);
+    });
+}
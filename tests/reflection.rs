@@ -0,0 +1,75 @@
+use shipyard::advanced::StorageId;
+use shipyard::sparse_set::SparseSet;
+use shipyard::*;
+
+#[derive(Component)]
+struct U32(u32);
+
+#[derive(Component)]
+struct USIZE(usize);
+
+#[test]
+fn borrowed_storages() {
+    let world = World::new();
+
+    world.add_entity((U32(0), USIZE(0)));
+
+    let u32s = world.borrow::<ViewMut<U32>>().unwrap();
+
+    // Borrowed storages are listed but can't be named
+    assert_eq!(world.component_storage_ids().len(), 2);
+    assert!(world
+        .storage_name(StorageId::of::<SparseSet<U32>>())
+        .is_none());
+
+    drop(u32s);
+
+    assert_eq!(world.component_storage_ids().len(), 2);
+    assert!(world
+        .storage_name(StorageId::of::<SparseSet<U32>>())
+        .unwrap()
+        .contains("U32"));
+
+    let _all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    assert!(world.component_storage_ids().is_empty());
+    assert!(world
+        .storage_name(StorageId::of::<SparseSet<U32>>())
+        .is_none());
+}
+
+#[test]
+fn counts() {
+    #[derive(Unique)]
+    struct Time(f32);
+
+    let mut world = World::new();
+    world.add_unique(Time(0.0));
+
+    let eid0 = world.add_entity((U32(0),));
+    let eid1 = world.add_entity((U32(1), USIZE(1)));
+    world.bulk_add_entity((2..5).map(|i| (U32(i),)));
+
+    assert_eq!(world.entity_count(), 5);
+    assert_eq!(world.storage_count(), 2);
+
+    // Borrowed storages are still counted
+    let u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    assert_eq!(world.storage_count(), 2);
+    drop(u32s);
+
+    world.delete_entity(eid0);
+    world.delete_entity(eid0);
+    world.delete_entity(eid1);
+
+    assert_eq!(world.entity_count(), 3);
+
+    world.add_entity(());
+    assert!(world.spawn(eid1));
+
+    assert_eq!(world.entity_count(), 5);
+
+    world.clear();
+
+    assert_eq!(world.entity_count(), 0);
+}
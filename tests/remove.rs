@@ -167,3 +167,51 @@ fn track_reset_with_timestamp() {
     let usizes = world.borrow::<View<USIZE, track::All>>().unwrap();
     assert_eq!(usizes.removed().collect::<Vec<_>>(), vec![]);
 }
+
+#[test]
+fn world_remove_absent() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    let dead = world.add_entity(U32(1));
+    world.delete_entity(dead);
+
+    assert_eq!(world.remove::<USIZE>(entity), None);
+    assert_eq!(world.remove::<(U32, USIZE)>(entity), (Some(U32(0)), None));
+    assert_eq!(world.remove::<(U32, USIZE)>(entity), (None, None));
+    assert_eq!(world.remove::<(U32, USIZE)>(dead), (None, None));
+}
+
+#[test]
+fn take() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct Tracked(u32);
+    impl Component for Tracked {
+        type Tracking = track::Removal;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(Tracked(0));
+    world.delete_entity(entity);
+    let new_entity = world.add_entity(Tracked(1));
+    let other = world.add_entity(Tracked(2));
+
+    let mut trackeds = world.borrow::<ViewMut<Tracked>>().unwrap();
+
+    assert_eq!(entity.index(), new_entity.index());
+    assert_eq!(trackeds.take(entity), None);
+    assert!(!trackeds.is_removed(new_entity));
+
+    assert_eq!(trackeds.take(new_entity), Some(Tracked(1)));
+    assert_eq!(trackeds.take(new_entity), None);
+    assert!(trackeds.is_removed(new_entity));
+    assert_eq!(trackeds.len(), 1);
+    assert_eq!(trackeds[other], Tracked(2));
+}
@@ -47,3 +47,29 @@ fn track() {
         assert!(v_u32.is_modified(eid1));
     });
 }
+
+#[test]
+fn entities() {
+    let mut world = World::new();
+    world.track_all::<U32>();
+
+    let eid0 = world.add_entity(U32(0));
+    let eid1 = world.add_entity(U32(1));
+    let eid2 = world.add_entity(());
+
+    world.retain_entities(|eid| eid == eid1);
+
+    assert!(!world.is_entity_alive(eid0));
+    assert!(world.is_entity_alive(eid1));
+    assert!(!world.is_entity_alive(eid2));
+    world.run(|v_u32: View<U32, track::All>| {
+        let mut deleted = v_u32.deleted();
+        assert_eq!(deleted.next(), Some((eid0, &U32(0))));
+        assert!(deleted.next().is_none());
+
+        assert_eq!(v_u32.len(), 1);
+    });
+
+    let eid3 = world.add_entity(U32(3));
+    assert!(eid3 != eid0 && eid3 != eid2);
+}
@@ -0,0 +1,139 @@
+use shipyard::views::serde::de::view_mut::ViewMutDeserializer;
+use shipyard::{Component, EntityId, ViewMut, World};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PositionV1 {
+    position: (f32, f32),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PositionV2 {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Position {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+fn load(world: &World, save: &str) -> Result<(), serde_json::Error> {
+    world.run(|mut positions: ViewMut<Position>| {
+        let mut deserializer = serde_json::Deserializer::from_str(save);
+        serde::Deserialize::deserialize_in_place(&mut deserializer, &mut positions)
+    })
+}
+
+fn register_migrations(world: &mut World) {
+    world.register_component_migrate(|old: PositionV2| Position {
+        x: old.x,
+        y: old.y,
+        z: 0.0,
+    });
+    world.register_component_migrate(|old: PositionV1| Position {
+        x: old.position.0,
+        y: old.position.1,
+        z: 0.0,
+    });
+}
+
+#[test]
+fn migrate_old_formats() {
+    let mut world = World::new();
+    register_migrations(&mut world);
+
+    let entity0 = EntityId::new_from_index_and_gen(0, 0);
+    let entity1 = EntityId::new_from_index_and_gen(1, 0);
+    let entity2 = EntityId::new_from_index_and_gen(2, 0);
+
+    let save = serde_json::to_string(&(
+        (
+            entity0,
+            PositionV1 {
+                position: (1.0, 2.0),
+            },
+        ),
+        (entity1, PositionV2 { x: 3.0, y: 4.0 }),
+        (
+            entity2,
+            Position {
+                x: 5.0,
+                y: 6.0,
+                z: 7.0,
+            },
+        ),
+    ))
+    .unwrap();
+
+    load(&world, &save).unwrap();
+
+    world.run(|positions: ViewMut<Position>| {
+        assert_eq!(positions.len(), 3);
+        assert_eq!(
+            positions[entity0],
+            Position {
+                x: 1.0,
+                y: 2.0,
+                z: 0.0
+            }
+        );
+        assert_eq!(
+            positions[entity1],
+            Position {
+                x: 3.0,
+                y: 4.0,
+                z: 0.0
+            }
+        );
+        assert_eq!(
+            positions[entity2],
+            Position {
+                x: 5.0,
+                y: 6.0,
+                z: 7.0
+            }
+        );
+    });
+}
+
+#[test]
+fn migrate_with_type_names() {
+    let mut world = World::new();
+    register_migrations(&mut world);
+
+    let save = r#"{"type_name":"Position","data":[[{"index":0,"gen":0},{"x":1.0,"y":2.0}]]}"#;
+
+    world.run(|mut positions: ViewMut<Position>| {
+        let mut deserializer = serde_json::Deserializer::from_str(save);
+        serde::de::DeserializeSeed::deserialize(
+            ViewMutDeserializer::new(&mut positions).type_names(true),
+            &mut deserializer,
+        )
+        .unwrap();
+
+        assert_eq!(
+            positions[EntityId::new_from_index_and_gen(0, 0)],
+            Position {
+                x: 1.0,
+                y: 2.0,
+                z: 0.0
+            }
+        );
+    });
+}
+
+#[test]
+fn no_migration_matches() {
+    let mut world = World::new();
+    register_migrations(&mut world);
+
+    let save = r#"[[{"index":0,"gen":0},{"x":1.0}]]"#;
+
+    let err = load(&world, save).unwrap_err();
+
+    // The error of the current format is reported
+    assert!(err.to_string().contains("missing field `y`"), "{}", err);
+    world.run(|positions: ViewMut<Position>| assert!(positions.is_empty()));
+}
@@ -0,0 +1,168 @@
+use shipyard::{error, Component, EntityId, View, ViewMut, World};
+
+#[derive(Component, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+enum State {
+    Idle,
+    Walking(f32),
+    Attacking {
+        target: EntityId,
+        damage: Option<u32>,
+    },
+}
+
+#[derive(Component, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Cache(Vec<u8>);
+
+fn register(world: &mut World) {
+    world.register_serde::<Position>("position");
+    world.register_serde::<State>("state");
+}
+
+fn save(world: &World) -> String {
+    let mut serialized = Vec::new();
+    world
+        .serialize_registered(&mut serde_json::Serializer::new(&mut serialized))
+        .unwrap_or_else(|err| panic!("{:?}", err));
+
+    String::from_utf8(serialized).unwrap()
+}
+
+#[test]
+fn registered_roundtrip() {
+    let mut world = World::new();
+    register(&mut world);
+
+    let entity0 = world.add_entity((Position { x: 1.0, y: 2.0 }, State::Idle));
+    let entity1 = world.add_entity((Position { x: 3.0, y: 4.0 }, State::Walking(0.5)));
+    let entity2 = world.add_entity((
+        State::Attacking {
+            target: entity0,
+            damage: Some(5),
+        },
+        Cache(vec![1, 2, 3]),
+    ));
+
+    let serialized = save(&world);
+
+    let mut new_world = World::new();
+    register(&mut new_world);
+
+    new_world
+        .deserialize_registered(&mut serde_json::Deserializer::from_str(&serialized))
+        .unwrap_or_else(|err| panic!("{:?}", err));
+
+    assert_eq!(
+        *new_world.get::<&Position>(entity0).unwrap(),
+        &Position { x: 1.0, y: 2.0 }
+    );
+    assert_eq!(
+        *new_world.get::<&Position>(entity1).unwrap(),
+        &Position { x: 3.0, y: 4.0 }
+    );
+    assert_eq!(*new_world.get::<&State>(entity0).unwrap(), &State::Idle);
+    assert_eq!(
+        *new_world.get::<&State>(entity1).unwrap(),
+        &State::Walking(0.5)
+    );
+    assert_eq!(
+        *new_world.get::<&State>(entity2).unwrap(),
+        &State::Attacking {
+            target: entity0,
+            damage: Some(5),
+        }
+    );
+    assert!(new_world.get::<&Cache>(entity2).is_err());
+}
+
+#[test]
+fn unregistered_storages_are_skipped() {
+    let mut world = World::new();
+    world.register_serde::<Position>("position");
+
+    let entity = world.add_entity((Position { x: 1.0, y: 2.0 }, Cache(vec![1])));
+
+    assert_eq!(
+        save(&world),
+        r#"{"position":[[{"index":0,"gen":0},{"x":1.0,"y":2.0}]]}"#
+    );
+
+    // Cache is registered when loading but wasn't saved, State is saved but not registered
+    let save = r#"{"position":[[{"index":0,"gen":0},{"x":1.0,"y":2.0}]],"state":[[{"index":0,"gen":0},"Idle"]]}"#;
+
+    let mut new_world = World::new();
+    new_world.register_serde::<Position>("position");
+    new_world.register_serde::<Cache>("cache");
+
+    new_world
+        .deserialize_registered(&mut serde_json::Deserializer::from_str(save))
+        .unwrap_or_else(|err| panic!("{:?}", err));
+
+    assert_eq!(
+        *new_world.get::<&Position>(entity).unwrap(),
+        &Position { x: 1.0, y: 2.0 }
+    );
+    assert!(new_world.get::<&State>(entity).is_err());
+    assert!(new_world.get::<&Cache>(entity).is_err());
+}
+
+#[test]
+fn double_registration() {
+    let mut world = World::new();
+    world.register_serde::<Position>("position");
+    world.register_serde::<Position>("position");
+
+    world.add_entity(Position { x: 1.0, y: 2.0 });
+
+    assert_eq!(
+        save(&world),
+        r#"{"position":[[{"index":0,"gen":0},{"x":1.0,"y":2.0}]]}"#
+    );
+}
+
+#[test]
+#[should_panic]
+fn registration_name_conflict() {
+    let mut world = World::new();
+    world.register_serde::<Position>("position");
+    world.register_serde::<State>("position");
+}
+
+#[test]
+#[should_panic]
+fn registration_rename() {
+    let mut world = World::new();
+    world.register_serde::<Position>("position");
+    world.register_serde::<Position>("pos");
+}
+
+#[test]
+fn registered_borrow_error() {
+    let mut world = World::new();
+    register(&mut world);
+
+    world.add_entity(Position { x: 1.0, y: 2.0 });
+
+    let positions = world.borrow::<ViewMut<Position>>().unwrap();
+
+    let mut serialized = Vec::new();
+    assert!(matches!(
+        world.serialize_registered(&mut serde_json::Serializer::new(&mut serialized)),
+        Err(error::Serialize::Borrow(_))
+    ));
+
+    drop(positions);
+
+    let _positions = world.borrow::<View<Position>>().unwrap();
+    let save = r#"{"position":[]}"#;
+
+    assert!(matches!(
+        world.deserialize_registered(&mut serde_json::Deserializer::from_str(save)),
+        Err(error::Deserialize::Borrow(_))
+    ));
+}
@@ -63,3 +63,60 @@ fn update() {
         },
     );
 }
+
+#[test]
+fn swap_entities() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::All;
+    }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity((U32(1),));
+    let dead = world.add_entity(());
+    world.delete_entity(dead);
+
+    world.run(|u32s: ViewMut<U32>, usizes: ViewMut<USIZE>| {
+        u32s.clear_all_inserted();
+        usizes.clear_all_inserted();
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert!(!all_storages.swap_entities(entity0, dead));
+        assert!(all_storages.swap_entities(entity0, entity1));
+    });
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s[entity0], U32(1));
+        assert_eq!(u32s[entity1], U32(0));
+        assert!(u32s.is_modified(entity0));
+        assert!(u32s.is_modified(entity1));
+
+        assert!(!usizes.contains(entity0));
+        assert_eq!(usizes[entity1], USIZE(0));
+        assert!(usizes.is_removed(entity0));
+        assert!(usizes.is_inserted(entity1));
+    });
+
+    // the insertion timestamp follows the component
+    let entity2 = world.add_entity((U32(2),));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert!(all_storages.swap_entities(entity0, entity2));
+    });
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s[entity0], U32(2));
+        assert!(u32s.is_inserted(entity0));
+        assert!(!u32s.is_inserted(entity2));
+    });
+}
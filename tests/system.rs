@@ -14,20 +14,272 @@ fn all_storages(_: AllStoragesViewMut, _: EntitiesView) {}
 
 #[test]
 fn bad_systems() {
-    assert_eq!(
+    assert!(matches!(
         two_views.into_workload_system().err(),
-        Some(error::InvalidSystem::MultipleViews)
-    );
-    assert_eq!(
+        Some(error::InvalidSystem::MultipleViews(_, _))
+    ));
+    assert!(matches!(
         two_views_mut.into_workload_system().err(),
-        Some(error::InvalidSystem::MultipleViewsMut)
-    );
-    assert_eq!(
+        Some(error::InvalidSystem::MultipleViewsMut(_, _))
+    ));
+    assert!(matches!(
         two_views_mut_mid.into_workload_system().err(),
-        Some(error::InvalidSystem::MultipleViewsMut)
-    );
+        Some(error::InvalidSystem::MultipleViewsMut(_, _))
+    ));
     assert_eq!(
         all_storages.into_workload_system().err(),
         Some(error::InvalidSystem::AllStorages)
     );
 }
+
+#[test]
+fn shared_all_storages() {
+    fn sys(all_storages: AllStoragesView, u32s: View<U32>, _: EntitiesView) {
+        assert_eq!(
+            all_storages.borrow::<View<U32>>().unwrap().len(),
+            u32s.len()
+        );
+    }
+
+    assert!(sys.into_workload_system().is_ok());
+
+    let world = World::new();
+    world.add_entity(U32(0));
+
+    Workload::new("")
+        .with_system(sys)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+}
+
+#[test]
+fn world_view() {
+    fn sys(world: WorldView) {
+        world.run(|mut all_storages: AllStoragesViewMut| {
+            all_storages.add_entity((U32(0),));
+        });
+    }
+    fn with_storage(_: WorldView, _: View<U32>) {}
+
+    assert_eq!(
+        with_storage.into_workload_system().err(),
+        Some(error::InvalidSystem::AllStorages)
+    );
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys)
+        .with_system(|_: View<U32>| {})
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
+
+#[test]
+fn bad_systems_names() {
+    use shipyard::borrow::Mutability;
+
+    let err = two_views.into_workload_system().err().unwrap();
+    match &err {
+        error::InvalidSystem::MultipleViews(a, b) => {
+            assert!(a.name.unwrap().contains("U32"));
+            assert!(b.name.unwrap().contains("U32"));
+            assert_eq!(a.mutability, Mutability::Shared);
+            assert_eq!(b.mutability, Mutability::Exclusive);
+        }
+        _ => panic!(),
+    }
+    assert!(err.to_string().contains("U32"));
+
+    let err = two_views_mut.into_workload_system().err().unwrap();
+    match &err {
+        error::InvalidSystem::MultipleViewsMut(a, b) => {
+            assert!(a.name.unwrap().contains("U32"));
+            assert!(b.name.unwrap().contains("U32"));
+        }
+        _ => panic!(),
+    }
+    assert!(err.to_string().contains("mutably twice"));
+}
+
+#[cfg(feature = "test_utils")]
+#[test]
+fn assert_valid_system() {
+    assert_valid_system!(|_: View<U32>, _: EntitiesView| {});
+}
+
+#[cfg(feature = "test_utils")]
+#[test]
+#[should_panic(expected = "`two_views_mut` is not a valid system: Multiple exclusive views")]
+fn assert_valid_system_panic() {
+    assert_valid_system!(two_views_mut);
+}
+
+struct Nth<const N: usize>(u32);
+impl<const N: usize> Component for Nth<N> {
+    type Tracking = track::Untracked;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sixteen_views(
+    mut v0: ViewMut<Nth<0>>,
+    v1: View<Nth<1>>,
+    v2: View<Nth<2>>,
+    v3: View<Nth<3>>,
+    v4: View<Nth<4>>,
+    v5: View<Nth<5>>,
+    v6: View<Nth<6>>,
+    v7: View<Nth<7>>,
+    v8: View<Nth<8>>,
+    v9: View<Nth<9>>,
+    v10: View<Nth<10>>,
+    v11: View<Nth<11>>,
+    v12: View<Nth<12>>,
+    v13: View<Nth<13>>,
+    v14: View<Nth<14>>,
+    v15: View<Nth<15>>,
+) {
+    for (n0, n1, n15) in (&mut v0, &v1, &v15).iter() {
+        n0.0 += n1.0 + n15.0;
+    }
+
+    let others = [
+        v2.len(),
+        v3.len(),
+        v4.len(),
+        v5.len(),
+        v6.len(),
+        v7.len(),
+        v8.len(),
+        v9.len(),
+        v10.len(),
+        v11.len(),
+        v12.len(),
+        v13.len(),
+        v14.len(),
+    ];
+    assert!(others.iter().all(|&len| len == 0));
+}
+
+#[test]
+fn sixteen_views_system() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Nth::<0>(1), Nth::<1>(2), Nth::<15>(3)));
+
+    world.add_workload(|| sixteen_views);
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.get::<&Nth<0>>(entity).unwrap().0, 6);
+
+    world.run(sixteen_views);
+
+    assert_eq!(world.get::<&Nth<0>>(entity).unwrap().0, 11);
+}
+
+#[test]
+fn async_system() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use core::task::{Context, Wake};
+    use shipyard::scheduler::{AsyncExecutor, Executor, IntoWorkloadAsyncSystem};
+    use std::sync::Arc;
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    struct BlockOn;
+    impl Executor for BlockOn {
+        fn block_on(&self, mut future: Pin<&mut dyn Future<Output = ()>>) {
+            let waker = Arc::new(NoopWaker).into();
+            let mut cx = Context::from_waker(&waker);
+
+            while future.as_mut().poll(&mut cx).is_pending() {}
+        }
+    }
+
+    async fn increment(mut u32s: ViewMut<'_, U32>) {
+        for mut i in (&mut u32s).iter() {
+            i.0 += 1;
+        }
+    }
+
+    async fn invalid(_: View<'_, U32>, _: ViewMut<'_, U32>) {}
+
+    static NO_VIEW_RAN: AtomicBool = AtomicBool::new(false);
+
+    async fn no_view() {
+        NO_VIEW_RAN.store(true, Ordering::Relaxed);
+    }
+
+    assert!(matches!(
+        invalid.into_workload_async_system().err(),
+        Some(error::InvalidSystem::MultipleViews(_, _))
+    ));
+
+    let world = World::new();
+    let entity = world.add_entity(U32(0));
+
+    Workload::new("")
+        .with_system(increment.into_workload_async_system().unwrap())
+        .with_system(no_view.into_workload_async_system().unwrap())
+        .add_to_world(&world)
+        .unwrap();
+
+    // The executor is looked up when the system runs
+    assert!(world.run_default_workload().is_err());
+
+    world.add_unique(AsyncExecutor::new(BlockOn));
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.get::<&U32>(entity).unwrap().0, 1);
+    assert!(NO_VIEW_RAN.load(Ordering::Relaxed));
+}
+
+#[test]
+fn try_run() {
+    #[derive(Unique)]
+    struct Counter(u32);
+    #[derive(Unique)]
+    struct Missing;
+
+    let world = World::new();
+
+    world.add_unique(Counter(0));
+
+    {
+        let _counter = world.borrow::<UniqueViewMut<Counter>>().unwrap();
+
+        assert!(matches!(
+            world.try_run(|_: UniqueView<Counter>| {}),
+            Err(error::Run::GetStorage(
+                error::GetStorage::StorageBorrow { .. }
+            ))
+        ));
+    }
+
+    assert!(matches!(
+        world.try_run(|_: UniqueView<Missing>| {}),
+        Err(error::Run::GetStorage(
+            error::GetStorage::MissingStorage { .. }
+        ))
+    ));
+    assert_eq!(
+        world
+            .try_run(|mut counter: UniqueViewMut<Counter>| {
+                counter.0 += 1;
+                counter.0
+            })
+            .unwrap(),
+        1
+    );
+}
@@ -202,3 +202,177 @@ fn clear_all_modified() {
         assert!(!unit.is_modified(eid));
     });
 }
+
+#[test]
+fn get_mut_silent() {
+    let mut world = World::new();
+
+    let eid = world.add_entity(UnitInsertAndModification);
+
+    world.clear_all_inserted_and_modified();
+
+    world.run(|mut unit: ViewMut<UnitInsertAndModification>| {
+        *unit.get_mut_silent(eid).unwrap() = UnitInsertAndModification;
+
+        assert!(!unit.is_modified(eid));
+
+        assert!(unit.mark_modified(eid));
+        assert!(unit.is_modified(eid));
+    });
+}
+
+#[test]
+fn mark_all_modified() {
+    let mut world = World::new();
+
+    let eid = world.add_entity(UnitInsertAndModification);
+    let eid2 = world.add_entity(UnitInsertAndModification);
+
+    world.clear_all_inserted_and_modified();
+
+    world.run(|mut unit: ViewMut<UnitInsertAndModification>| {
+        assert!(!unit.is_modified(eid));
+        assert!(!unit.is_modified(eid2));
+
+        unit.mark_all_modified();
+
+        assert!(unit.is_modified(eid));
+        assert!(unit.is_modified(eid2));
+        assert_eq!(unit.modified().iter().count(), 2);
+    });
+}
+
+#[test]
+fn update_all() {
+    let mut world = World::new();
+
+    let eid = world.add_entity(UnitInsertAndModification);
+    let eid2 = world.add_entity(UnitInsertAndModification);
+
+    world.clear_all_inserted_and_modified();
+
+    world.run(|mut unit: ViewMut<UnitInsertAndModification>| {
+        let mut count = 0;
+        unit.update_all(|_| count += 1);
+
+        assert_eq!(count, 2);
+        assert!(unit.is_modified(eid));
+        assert!(unit.is_modified(eid2));
+    });
+}
+
+#[test]
+fn replace() {
+    let mut world = World::new();
+
+    let eid = world.add_entity(UnitInsertAndModification);
+    let eid2 = world.add_entity(());
+
+    world.clear_all_inserted_and_modified();
+
+    world.run(|mut unit: ViewMut<UnitInsertAndModification>| {
+        assert!(unit
+            .replace(eid, UnitInsertAndModification)
+            .unwrap()
+            .is_some());
+        assert!(unit.is_modified(eid));
+        assert!(!unit.is_inserted(eid));
+
+        assert!(unit
+            .replace(eid2, UnitInsertAndModification)
+            .unwrap()
+            .is_none());
+        assert!(unit.is_inserted(eid2));
+        assert!(!unit.is_modified(eid2));
+    });
+
+    world.delete_entity(eid);
+    let eid3 = world.add_entity(UnitInsertAndModification);
+    assert_eq!(eid3.index(), eid.index());
+
+    world.run(|mut unit: ViewMut<UnitInsertAndModification>| {
+        assert!(unit.replace(eid, UnitInsertAndModification).is_err());
+    });
+}
+
+#[test]
+fn tracking_snapshot() {
+    struct UnitAll;
+    impl Component for UnitAll {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    let eid = world.add_entity(UnitAll);
+    let eid2 = world.add_entity(UnitAll);
+    world.delete_entity(eid2);
+
+    let snapshot = world.tracking_snapshot();
+    assert_eq!(snapshot.len(), 1);
+
+    world.clear_all_inserted();
+    world.clear_all_removed_and_deleted();
+
+    world.run(|unit: View<UnitAll>| {
+        assert!(!unit.is_inserted(eid));
+        assert_eq!(unit.deleted().count(), 0);
+    });
+
+    world.restore_tracking_snapshot(&snapshot);
+
+    world.run(|unit: View<UnitAll>| {
+        assert!(unit.is_inserted(eid));
+        // Deleted components can't be brought back
+        assert_eq!(unit.deleted().count(), 0);
+    });
+}
+
+#[test]
+fn entry() {
+    use shipyard::error::AddComponent;
+    use shipyard::views::Entry;
+
+    #[derive(Default)]
+    struct UnitAll(u32);
+    impl Component for UnitAll {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    let eid = world.add_entity(());
+    let eid2 = world.add_entity(UnitAll(5));
+
+    world.clear_all_inserted_and_modified();
+
+    world.run(|mut units: ViewMut<UnitAll>| {
+        assert!(matches!(units.entry(eid), Entry::Vacant(_)));
+        assert_eq!(units.entry(eid).or_default().unwrap().0, 0);
+        assert!(units.is_inserted(eid));
+        assert!(!units.is_modified(eid));
+
+        assert_eq!(units.entry(eid2).or_insert(UnitAll(0)).unwrap().0, 5);
+        assert!(!units.is_inserted(eid2));
+        assert!(!units.is_modified(eid2));
+
+        let _ = units
+            .entry(eid2)
+            .and_modify(|mut unit| unit.0 += 1)
+            .or_insert_with(|| panic!());
+        assert_eq!(units[eid2].0, 6);
+        assert!(units.is_modified(eid2));
+    });
+
+    world.delete_entity(eid);
+    let eid3 = world.add_entity(UnitAll(1));
+    assert_eq!(eid3.index(), eid.index());
+
+    world.run(|mut units: ViewMut<UnitAll>| {
+        assert!(matches!(units.entry(eid), Entry::Dead(_)));
+        assert_eq!(
+            units.entry(eid).or_insert(UnitAll(0)).err(),
+            Some(AddComponent::EntityIsNotAlive)
+        );
+    });
+}
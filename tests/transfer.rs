@@ -0,0 +1,82 @@
+use shipyard::advanced::StorageId;
+use shipyard::sparse_set::SparseSet;
+use shipyard::*;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn transfer() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity = world1.add_entity((U32(0), USIZE(1)));
+    let other = world1.add_entity((U32(2),));
+
+    let new_entity = world1
+        .transfer_entity(&mut world2, entity, &[StorageId::of::<SparseSet<U32>>()])
+        .unwrap();
+
+    assert!(!world1.is_entity_alive(entity));
+    assert!(world2.is_entity_alive(new_entity));
+    assert_eq!(world2.get::<&U32>(new_entity).as_deref(), Ok(&&U32(0)));
+    assert!(world2.get::<&USIZE>(new_entity).is_err());
+
+    // Components that weren't moved are deleted
+    assert_eq!(world1.borrow::<View<USIZE>>().unwrap().len(), 0);
+    assert_eq!(world1.get::<&U32>(other).as_deref(), Ok(&&U32(2)));
+}
+
+#[test]
+fn transfer_dead_entity() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity = world1.add_entity((U32(0),));
+    world1.delete_entity(entity);
+
+    assert_eq!(
+        world1.transfer_entity(&mut world2, entity, &[StorageId::of::<SparseSet<U32>>()]),
+        Err(error::TransferEntity::EntityIsNotAlive)
+    );
+    assert_eq!(world2.borrow::<EntitiesView>().unwrap().iter().count(), 0);
+}
+
+#[test]
+fn transfer_entities() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity0 = world1.add_entity((U32(0),));
+    let entity1 = world1.add_entity((U32(1),));
+    let dead = world1.add_entity((U32(2),));
+    let entity3 = world1.add_entity((U32(3),));
+    world1.delete_entity(dead);
+
+    let mut remap = HashMap::new();
+    assert_eq!(
+        world1.transfer_entities(
+            &mut world2,
+            [entity0, entity1, dead, entity3],
+            &[StorageId::of::<SparseSet<U32>>()],
+            &mut remap,
+        ),
+        Err(error::TransferEntity::EntityIsNotAlive)
+    );
+
+    // Entities before the failing one are transferred, the ones after are left untouched
+    assert_eq!(remap.len(), 2);
+    assert_eq!(world2.get::<&U32>(remap[&entity0]).as_deref(), Ok(&&U32(0)));
+    assert_eq!(world2.get::<&U32>(remap[&entity1]).as_deref(), Ok(&&U32(1)));
+    assert_eq!(world1.get::<&U32>(entity3).as_deref(), Ok(&&U32(3)));
+}
@@ -217,3 +217,120 @@ fn unique_or_init_mut() {
 
     world.run(|u: UniqueOrInitViewMut<USIZE>| assert_eq!(**u.get().unwrap(), USIZE(11)));
 }
+
+#[test]
+fn add_unique_lazy() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static INIT: AtomicUsize = AtomicUsize::new(0);
+
+    let world = World::new();
+
+    world.add_unique_lazy(|| {
+        INIT.fetch_add(1, Ordering::Relaxed);
+
+        USIZE(0)
+    });
+
+    assert_eq!(INIT.load(Ordering::Relaxed), 0);
+
+    world.borrow::<UniqueViewMut<USIZE>>().unwrap().0 += 1;
+    world.run(|unique: UniqueView<USIZE>| assert_eq!(unique.0, 1));
+
+    assert_eq!(INIT.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn lazy_unique_add_remove() {
+    use shipyard::scheduler::SystemModificator;
+
+    fn never_runs() {
+        panic!("USIZE is present");
+    }
+
+    let world = World::new();
+
+    world.add_unique_lazy(|| USIZE(0));
+    assert_eq!(world.remove_unique::<USIZE>(), Ok(USIZE(0)));
+    assert!(world.borrow::<UniqueView<USIZE>>().is_err());
+
+    // Adding a value clears the initializer
+    world.add_unique_lazy(|| USIZE(0));
+    world.add_unique(USIZE(1));
+    assert_eq!(world.remove_unique::<USIZE>(), Ok(USIZE(1)));
+    assert!(world.borrow::<UniqueView<USIZE>>().is_err());
+
+    // A lazy unique is present before its first borrow
+    world.add_unique_lazy(|| USIZE(2));
+    Workload::new("")
+        .with_system(never_runs.run_if_missing_unique::<USIZE>())
+        .add_to_world(&world)
+        .unwrap();
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 2);
+}
+
+#[test]
+fn add_distinct_unique() {
+    let world = World::new();
+
+    world.add_distinct_unique(USIZE(0)).unwrap();
+    assert_eq!(
+        world.add_distinct_unique(USIZE(1)),
+        Err(error::UniqueAlreadyExists {
+            name: type_name::<USIZE>()
+        })
+    );
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 0);
+
+    let world = World::new();
+
+    world.add_unique_lazy(|| USIZE(0));
+    assert!(world.add_distinct_unique(USIZE(1)).is_err());
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 0);
+}
+
+#[test]
+fn lazy_unique_borrowed_during_init() {
+    use std::sync::Arc;
+
+    let world = Arc::new(World::new());
+    let weak = Arc::downgrade(&world);
+
+    world.add_unique_lazy(move || {
+        let world = weak.upgrade().unwrap();
+
+        assert!(matches!(
+            world.borrow::<UniqueView<USIZE>>(),
+            Err(error::GetStorage::StorageBorrow { .. })
+        ));
+        assert!(world.remove_unique::<USIZE>().is_err());
+
+        USIZE(0)
+    });
+
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 0);
+}
+
+#[test]
+fn lazy_unique_init_panics() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let world = World::new();
+
+    world.add_unique_lazy(|| -> USIZE { panic!("USIZE initializer failed") });
+
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        let _ = world.borrow::<UniqueView<USIZE>>();
+    }))
+    .is_err());
+
+    // The unique is removed instead of staying borrowed
+    assert!(matches!(
+        world.borrow::<UniqueView<USIZE>>(),
+        Err(error::GetStorage::MissingStorage { .. })
+    ));
+
+    world.add_unique(USIZE(1));
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 1);
+}
@@ -195,13 +195,41 @@ fn check_run_if_error() {
     world.add_workload(|| (|| {}, sys.run_if(|_: UniqueView<USIZE>| true)).into_workload());
 
     match world.run_default_workload() {
-        Err(error::RunWorkload::Run((label, _))) => {
-            assert!(label == sys.as_label());
+        Err(error::RunWorkload::Run(run_system)) => {
+            assert!(run_system.system == sys.as_label());
         }
         _ => panic!(),
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn run_error_context() {
+    use std::error::Error;
+
+    fn sys(_: UniqueView<USIZE>) {}
+
+    let world = World::new();
+
+    Workload::new("Failing")
+        .with_system(sys)
+        .add_to_world(&world)
+        .unwrap();
+
+    let err = world.run_workload("Failing").unwrap_err();
+
+    match &err {
+        error::RunWorkload::Run(run_system) => {
+            assert_eq!(format!("{:?}", run_system.workload), "Failing");
+            assert!(run_system.system == sys.as_label());
+            assert!(matches!(run_system.error, error::Run::GetStorage(_)));
+        }
+        _ => panic!(),
+    }
+
+    assert!(err.source().unwrap().is::<error::Run>());
+}
+
 #[test]
 fn tracking_enabled() {
     fn w() -> Workload {
@@ -245,3 +273,994 @@ fn skip_first() {
 
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn reentrant() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static REENTRANT: AtomicBool = AtomicBool::new(false);
+
+    let world: &'static World = Box::leak(Box::new(World::new()));
+
+    Workload::new("reentrant")
+        .with_system(move || {
+            let result = world.run_workload("reentrant");
+
+            REENTRANT.store(
+                matches!(result, Err(error::RunWorkload::Reentrant(_))),
+                Ordering::Relaxed,
+            );
+        })
+        .add_to_world(world)
+        .unwrap();
+
+    world.run_workload("reentrant").unwrap();
+
+    assert!(REENTRANT.load(Ordering::Relaxed));
+
+    // the guard is released once the workload is done
+    world.run_workload("reentrant").unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn reentrant_parallel() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static REENTRANT: AtomicUsize = AtomicUsize::new(0);
+
+    let world: &'static World = Box::leak(Box::new(World::new()));
+
+    let system = move || {
+        if matches!(
+            world.run_workload("reentrant"),
+            Err(error::RunWorkload::Reentrant(_))
+        ) {
+            REENTRANT.fetch_add(1, Ordering::Relaxed);
+        }
+    };
+
+    Workload::new("reentrant")
+        .with_system(system)
+        .with_system(system)
+        .with_system(system)
+        .add_to_world(world)
+        .unwrap();
+
+    world.run_workload("reentrant").unwrap();
+
+    assert_eq!(REENTRANT.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn fn_mut_system() {
+    use shipyard::scheduler::FnMutSystem;
+
+    let world = World::new();
+    world.add_unique(USIZE(0));
+
+    let mut sum = 0;
+    Workload::new("")
+        .with_system(FnMutSystem(move |mut total: UniqueViewMut<USIZE>| {
+            sum += 1;
+            total.0 = sum;
+        }))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 3);
+}
+
+#[test]
+fn fn_mut_system_own_state() {
+    use shipyard::scheduler::FnMutSystem;
+
+    let world = World::new();
+    world.add_unique(USIZE(0));
+
+    let mut workload = Workload::new("");
+    // both systems have the same type
+    for _ in 0..2 {
+        let mut count = 0;
+
+        workload = workload.with_system(FnMutSystem(move |mut total: UniqueViewMut<USIZE>| {
+            count += 1;
+            total.0 += count;
+        }));
+    }
+    workload.add_to_world(&world).unwrap();
+
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    // each system counts its own runs: 1 + 1 + 2 + 2
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 6);
+}
+
+#[test]
+fn fn_mut_system_workload() {
+    use shipyard::scheduler::FnMutSystem;
+
+    let world = World::new();
+    world.add_unique(USIZE(0));
+
+    FnMutSystem(|| Workload::new("").with_system(|mut total: UniqueViewMut<USIZE>| total.0 += 1))
+        .into_workload()
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 1);
+}
+
+#[test]
+fn accessed_storages_fn_mut_system() {
+    use shipyard::advanced::StorageId;
+    use shipyard::scheduler::FnMutSystem;
+    use shipyard::sparse_set::SparseSet;
+
+    let mut count = 0;
+    let workload = Workload::new("").with_system(FnMutSystem(move |_: View<U32>| count += 1));
+
+    let (shared, exclusive) = workload.accessed_storages();
+
+    assert_eq!(shared, [StorageId::of::<SparseSet<U32>>()]);
+    assert!(exclusive.is_empty());
+}
+
+#[test]
+fn workload_runner() {
+    fn sys1(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 += 1;
+    }
+    fn sys2(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 *= 10;
+    }
+    fn sys3(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 += 5;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2.run_if(|| false))
+        .with_system(sys3)
+        .add_to_world(&world)
+        .unwrap();
+
+    let mut runner = world.workload_runner("").unwrap();
+
+    assert_eq!(runner.step(&world).unwrap(), Some(sys1.as_label()));
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    assert_eq!(runner.step(&world).unwrap(), Some(sys3.as_label()));
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 6);
+
+    assert!(!runner.is_finished());
+    assert_eq!(runner.step(&world).unwrap(), None);
+    assert!(runner.is_finished());
+
+    assert!(matches!(
+        world.workload_runner("missing"),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn run_workloads_parallel() {
+    let worlds = [World::new(), World::new(), World::new()];
+
+    for (i, world) in worlds.iter().enumerate().skip(1) {
+        world.add_unique(USIZE(i));
+        Workload::new("double")
+            .with_system(|mut n: UniqueViewMut<USIZE>| n.0 *= 2)
+            .add_to_world(world)
+            .unwrap();
+    }
+
+    let results = World::run_workloads_parallel(&worlds, "double");
+
+    assert!(matches!(
+        results[0],
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+    assert!(results[1].is_ok());
+    assert!(results[2].is_ok());
+    assert_eq!(worlds[1].borrow::<UniqueView<USIZE>>().unwrap().0, 2);
+    assert_eq!(worlds[2].borrow::<UniqueView<USIZE>>().unwrap().0, 4);
+}
+
+#[test]
+fn run_workload_deterministic() {
+    use std::sync::Mutex;
+
+    static LOG: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(|_: View<U32>| LOG.lock().unwrap().push(0))
+        .with_system(|_: View<USIZE>| LOG.lock().unwrap().push(1))
+        .with_system(|_: View<U32>, _: View<USIZE>| LOG.lock().unwrap().push(2))
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..10 {
+        world.run_workload_deterministic("").unwrap();
+
+        assert_eq!(std::mem::take(&mut *LOG.lock().unwrap()), vec![0, 1, 2]);
+    }
+}
+
+#[test]
+fn last_workload_error() {
+    fn fail(flag: UniqueView<USIZE>) -> Result<(), error::MissingComponent> {
+        if flag.0 == 0 {
+            Ok(())
+        } else {
+            Err(error::MissingComponent {
+                id: EntityId::dead(),
+                name: "",
+            })
+        }
+    }
+
+    let world = World::new();
+
+    world.add_unique(USIZE(1));
+
+    Workload::new("")
+        .with_try_system(fail)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.last_workload_error("").is_none());
+    assert!(world.last_workload_error("missing").is_none());
+
+    assert!(world.run_workload("").is_err());
+
+    let (system, err) = world.last_workload_error("").unwrap();
+    assert!(system == fail.as_label());
+    assert!(matches!(err, error::Run::Custom(_)));
+
+    world.borrow::<UniqueViewMut<USIZE>>().unwrap().0 = 0;
+    world.run_workload("").unwrap();
+
+    assert!(world.last_workload_error("").is_none());
+}
+
+#[test]
+fn try_system_question_mark() {
+    fn check(usizes: View<USIZE>) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        usizes.get(EntityId::dead())?;
+
+        Ok(())
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_try_system(check)
+        .add_to_world(&world)
+        .unwrap();
+
+    let err = world.run_workload("").unwrap_err().custom_error().unwrap();
+    assert!(err.is::<error::MissingComponent>());
+}
+
+#[test]
+fn stages() {
+    fn input(_: ViewMut<U32>) {}
+    fn late_input(_: View<USIZE>) {}
+    fn simulation(_: View<U32>) {}
+    fn render() {}
+    fn unstaged() {}
+
+    let (_, info) = Workload::new("")
+        .stage("Input")
+        .add_system(input)
+        .stage("Simulation")
+        .add_system(simulation)
+        .stage("Render")
+        .add_system(render)
+        .stage("Input")
+        .add_system(late_input)
+        .end_stage()
+        .with_system(unstaged)
+        .build()
+        .unwrap();
+
+    let batch_of = |name: &str| {
+        info.batches_info
+            .iter()
+            .position(|batch| {
+                batch
+                    .systems()
+                    .any(|system| system.name.ends_with(&format!("::{})", name)))
+            })
+            .unwrap()
+    };
+
+    assert_eq!(batch_of("input"), 0);
+    assert_eq!(batch_of("late_input"), 0);
+    assert_eq!(batch_of("unstaged"), 0);
+    assert_eq!(batch_of("simulation"), 1);
+    assert_eq!(batch_of("render"), 2);
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn run_workload_budgeted() {
+    use std::time::Duration;
+
+    fn sys1(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 += 1;
+    }
+    fn sys2(mut u32s: UniqueViewMut<U32>) {
+        std::thread::sleep(Duration::from_millis(20));
+        u32s.0 += 1;
+    }
+    fn sys3(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .add_to_world(&world)
+        .unwrap();
+
+    let ran = world
+        .run_workload_budgeted("", Duration::from_millis(10))
+        .unwrap();
+
+    assert_eq!(ran, vec![sys1.as_label(), sys2.as_label()]);
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+
+    let ran = world
+        .run_workload_budgeted("", Duration::from_secs(60))
+        .unwrap();
+
+    assert_eq!(ran.len(), 3);
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 5);
+}
+
+#[test]
+fn manifest() {
+    use shipyard::error::FromManifest;
+    use shipyard::scheduler::{SystemRegistry, WorkloadManifest};
+
+    fn sys1(mut u32s: UniqueViewMut<U32>) {
+        assert_eq!(u32s.0, 1);
+        u32s.0 += 1;
+    }
+    fn sys2(mut u32s: UniqueViewMut<U32>) {
+        assert_eq!(u32s.0, 0);
+        u32s.0 += 1;
+    }
+
+    let workload = Workload::new("Manifest")
+        .with_system(sys1.after_all(sys2).tag("Tag"))
+        .with_system(sys2)
+        .with_barrier();
+
+    let manifest = workload.to_manifest().unwrap();
+
+    assert_eq!(manifest.name, "Manifest");
+    assert_eq!(manifest.systems.len(), 2);
+    assert_eq!(manifest.systems[0].tags, vec!["Tag".to_string()]);
+    assert_eq!(
+        manifest.systems[0].after_all,
+        vec![format!("System({})", manifest.systems[1].name)]
+    );
+    assert_eq!(manifest.barriers, vec![2]);
+
+    let mut registry = SystemRegistry::new();
+    registry.register(sys1).unwrap().register(sys2).unwrap();
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::from_manifest(&manifest, &registry)
+        .unwrap()
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Manifest").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+
+    let unknown = WorkloadManifest {
+        systems: vec![Default::default()],
+        ..manifest
+    };
+    assert_eq!(
+        Workload::from_manifest(&unknown, &registry).err(),
+        Some(FromManifest::UnknownSystem(String::new()))
+    );
+}
+
+#[test]
+fn manifest_run_if() {
+    use shipyard::scheduler::SystemRegistry;
+
+    fn sys(mut u32s: UniqueViewMut<U32>) {
+        u32s.0 += 1;
+    }
+    fn below_two(u32s: UniqueView<U32>) -> bool {
+        u32s.0 < 2
+    }
+
+    let workload = Workload::new("Manifest").with_system(sys.run_if_as("below_two", below_two));
+
+    let manifest = workload.to_manifest().unwrap();
+
+    assert_eq!(manifest.systems[0].run_if.as_deref(), Some("below_two"));
+
+    let mut registry = SystemRegistry::new();
+    registry
+        .register(sys)
+        .unwrap()
+        .register_run_if("below_two", below_two)
+        .unwrap();
+
+    let rebuilt = Workload::from_manifest(&manifest, &registry).unwrap();
+
+    assert_eq!(rebuilt.to_manifest().unwrap(), manifest);
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    rebuilt.add_to_world(&world).unwrap();
+
+    for _ in 0..4 {
+        world.run_workload("Manifest").unwrap();
+    }
+
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+}
+
+#[test]
+fn manifest_rejects_lossy_workloads() {
+    use shipyard::error::ToManifest;
+
+    fn sys1() {}
+    fn sys2() {}
+
+    assert!(matches!(
+        Workload::new("")
+            .with_system(sys1.run_if(|| true))
+            .to_manifest(),
+        Err(ToManifest::RunIf(_))
+    ));
+    // Other conditions drop the name
+    assert!(matches!(
+        Workload::new("")
+            .with_system(sys1.run_if_as("true", || true).run_if(|| true))
+            .to_manifest(),
+        Err(ToManifest::RunIf(_))
+    ));
+    assert!(matches!(
+        Workload::new("")
+            .with_system(sys1)
+            .after_all("Other")
+            .to_manifest(),
+        Err(ToManifest::Ordering(_))
+    ));
+
+    let manifest = Workload::new("")
+        .with_system(sys1.require_before(sys2).priority(1))
+        .with_system(sys2)
+        .max_threads(2)
+        .to_manifest()
+        .unwrap();
+
+    assert_eq!(manifest.systems[0].priority, 1);
+    assert_eq!(manifest.systems[0].require_before.len(), 1);
+    assert_eq!(manifest.max_threads, Some(2));
+}
+
+#[test]
+fn run_workload_stats() {
+    fn sys1(_: View<U32>) {}
+    fn sys2(_: View<U32>) {}
+    fn sys3(_: ViewMut<U32>) {}
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2.run_if(|| false))
+        .with_system(sys3)
+        .add_to_world(&world)
+        .unwrap();
+
+    let stats = world.run_workload_stats("").unwrap();
+
+    assert_eq!(stats.batches, 2);
+    assert_eq!(stats.max_batch_width, 2);
+    assert_eq!(stats.systems, 3);
+    assert_eq!(stats.critical_path, 2);
+    assert_eq!(stats.systems_run, 2);
+}
+
+#[test]
+fn critical_path() {
+    fn sys1(_: ViewMut<U32>) {}
+    fn sys2(_: ViewMut<U32>) {}
+    fn sys3(_: ViewMut<USIZE>) {}
+    fn sys4(_: ViewMut<USIZE>) {}
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .with_system(sys4)
+        .add_to_world(&world)
+        .unwrap();
+
+    let stats = world.run_workload_stats("").unwrap();
+
+    assert_eq!(stats.batches, 3);
+    assert_eq!(stats.critical_path, 2);
+}
+
+#[test]
+fn cancelable() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Increment")
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    let cancel = Arc::new(AtomicBool::new(false));
+
+    world
+        .run_workload_cancelable("Increment", cancel.clone())
+        .unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    cancel.store(true, Ordering::Relaxed);
+
+    assert!(matches!(
+        world.run_workload_cancelable("Increment", cancel.clone()),
+        Err(error::RunWorkload::Cancelled)
+    ));
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    // The workload can run again once cancelled
+    world.run_workload("Increment").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+}
+
+#[test]
+fn traced() {
+    use shipyard::scheduler::info::TraceEvent;
+
+    fn sys1() {}
+    fn sys2() {}
+
+    let world = World::new();
+
+    Workload::new("Traced")
+        .with_system(sys1)
+        .with_system(sys2.run_if(|u32: UniqueView<U32>| u32.0 > 0))
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Skipped")
+        .with_system(sys1)
+        .run_if(|| false)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.add_unique(U32(0));
+
+    let trace = world.run_workload_traced("Traced").unwrap();
+    assert_eq!(trace.events.len(), 2);
+    assert_eq!(
+        trace.events[0],
+        TraceEvent::Ran(format!("{:?}", sys1.as_label()))
+    );
+    assert_eq!(
+        trace.events[1],
+        TraceEvent::SkippedByRunIf(format!("{:?}", sys2.as_label()))
+    );
+
+    world.borrow::<UniqueViewMut<U32>>().unwrap().0 = 1;
+
+    let trace2 = world.run_workload_traced("Traced").unwrap();
+    assert_ne!(trace, trace2);
+    assert_eq!(
+        trace2.events[1],
+        TraceEvent::Ran(format!("{:?}", sys2.as_label()))
+    );
+
+    assert_eq!(
+        world.run_workload_traced("Skipped").unwrap().events,
+        [TraceEvent::WorkloadSkipped]
+    );
+}
+
+#[test]
+fn arena() {
+    fn sys1(arena: Arena, mut address: UniqueViewMut<USIZE>) {
+        let buffer = arena.alloc_slice_fill_with(100, |i| i as u32);
+        assert_eq!(buffer[99], 99);
+
+        address.0 = buffer.as_ptr() as usize;
+    }
+    fn sys2(arena: Arena) {
+        assert_eq!(arena.alloc_slice_copy(&[1u8, 2, 3]), &[1, 2, 3]);
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.add_unique(USIZE(0));
+
+    let stats = world.run_workload_stats("").unwrap();
+    assert_eq!(stats.batches, 2);
+
+    let first_address = world.borrow::<UniqueView<USIZE>>().unwrap().0;
+
+    world.run_workload("").unwrap();
+
+    assert_eq!(
+        world.borrow::<UniqueView<USIZE>>().unwrap().0,
+        first_address
+    );
+}
+
+#[test]
+fn arena_reset_by_runner() {
+    fn sys(arena: Arena, mut address: UniqueViewMut<USIZE>) {
+        address.0 = arena.alloc_slice_fill_with(100, |i| i as u32).as_ptr() as usize;
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.add_unique(USIZE(0));
+
+    let mut runner = world.workload_runner("").unwrap();
+    while runner.step(&world).unwrap().is_some() {}
+
+    let first_address = world.borrow::<UniqueView<USIZE>>().unwrap().0;
+
+    let mut runner = world.workload_runner("").unwrap();
+    while runner.step(&world).unwrap().is_some() {}
+
+    assert_eq!(
+        world.borrow::<UniqueView<USIZE>>().unwrap().0,
+        first_address
+    );
+}
+
+#[test]
+fn run_workload_branch() {
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+
+    world.add_unique(U32(0));
+
+    Workload::new("A")
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload_branch(|_| false, "A", "A").unwrap();
+    world.run_workload_branch(|_| true, "A", "Missing").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+
+    assert!(matches!(
+        world.run_workload_branch(|_| false, "A", "Missing"),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+#[test]
+fn max_threads() {
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    static THREADS: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+    fn sys1(_: View<U32>) {
+        THREADS.lock().unwrap().push(std::thread::current().id());
+    }
+    fn sys2(_: View<U32>) {
+        THREADS.lock().unwrap().push(std::thread::current().id());
+    }
+    fn sys3(_: View<U32>) {
+        THREADS.lock().unwrap().push(std::thread::current().id());
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .max_threads(1)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("").unwrap();
+
+    assert_eq!(*THREADS.lock().unwrap(), [std::thread::current().id(); 3]);
+}
+
+#[cfg(all(feature = "parallel", feature = "std"))]
+#[test]
+fn max_threads_merged() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RUNNING: AtomicUsize = AtomicUsize::new(0);
+    static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+    fn sys() {
+        let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+        MAX_RUNNING.fetch_max(running, Ordering::SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(|_: View<U32>| sys())
+        .with_system(|_: View<U32>| sys())
+        .merge(
+            Workload::new("Limited")
+                .with_system(|_: View<U32>| sys())
+                .with_system(|_: View<U32>| sys())
+                .with_system(|_: View<U32>| sys())
+                .max_threads(2),
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("").unwrap();
+
+    assert!(MAX_RUNNING.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn staggered() {
+    struct Ran(Vec<&'static str>);
+    impl Unique for Ran {}
+
+    fn sys1(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys1");
+    }
+    fn sys2(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys2");
+    }
+    fn sys3(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys3");
+    }
+
+    let world = World::new();
+
+    world.add_unique(Ran(Vec::new()));
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3.skip_if(|| true))
+        .staggered(2)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..4 {
+        world.run_workload("").unwrap();
+    }
+
+    assert_eq!(
+        world.borrow::<UniqueView<Ran>>().unwrap().0,
+        ["sys1", "sys2", "sys1", "sys2"]
+    );
+}
+
+#[test]
+fn staggered_advances_on_exit() {
+    struct Ran(Vec<&'static str>);
+    impl Unique for Ran {}
+
+    fn sys1(mut ran: UniqueViewMut<Ran>) -> Result<(), error::MissingComponent> {
+        ran.0.push("sys1");
+
+        Err(error::MissingComponent {
+            id: EntityId::dead(),
+            name: "U32",
+        })
+    }
+    fn sys2(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys2");
+    }
+    fn sys3(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys3");
+    }
+    fn sys4(mut ran: UniqueViewMut<Ran>) {
+        ran.0.push("sys4");
+    }
+
+    let world = World::new();
+
+    world.add_unique(Ran(Vec::new()));
+
+    Workload::new("")
+        .with_try_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .with_system(sys4)
+        .staggered(2)
+        .add_to_world(&world)
+        .unwrap();
+
+    // A failed run moves to the next group
+    assert!(world.run_workload("").is_err());
+
+    // So does a runner dropped before the end
+    let mut runner = world.workload_runner("").unwrap();
+    assert_eq!(runner.step(&world).unwrap(), Some(sys2.as_label()));
+    drop(runner);
+
+    // Or stopped by an error
+    let mut runner = world.workload_runner("").unwrap();
+    assert!(runner.step(&world).is_err());
+    assert_eq!(runner.step(&world).unwrap(), None);
+
+    world.run_workload("").unwrap();
+
+    assert_eq!(
+        world.borrow::<UniqueView<Ran>>().unwrap().0,
+        ["sys1", "sys2", "sys1", "sys2", "sys4"]
+    );
+}
+
+#[test]
+fn every() {
+    struct Ran(Vec<u32>);
+    impl Unique for Ran {}
+
+    fn sys(mut ran: UniqueViewMut<Ran>, value: UniqueView<U32>) {
+        ran.0.push(value.0);
+    }
+
+    let world = World::new();
+
+    world.add_unique(Ran(Vec::new()));
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(sys.every(2).run_if(|value: UniqueView<U32>| value.0 != 2))
+        .add_to_world(&world)
+        .unwrap();
+
+    for i in 0..6 {
+        world.borrow::<UniqueViewMut<U32>>().unwrap().0 = i;
+        world.run_workload("").unwrap();
+    }
+
+    assert_eq!(world.borrow::<UniqueView<Ran>>().unwrap().0, [0, 4]);
+}
+
+#[test]
+fn every_staggered() {
+    struct Ran(Vec<u32>);
+    impl Unique for Ran {}
+
+    fn sys(mut ran: UniqueViewMut<Ran>, value: UniqueView<U32>) {
+        ran.0.push(value.0);
+    }
+
+    let world = World::new();
+
+    world.add_unique(Ran(Vec::new()));
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(sys.every(2))
+        .with_system(|| {})
+        .staggered(2)
+        .add_to_world(&world)
+        .unwrap();
+
+    for i in 0..8 {
+        world.borrow::<UniqueViewMut<U32>>().unwrap().0 = i;
+        world.run_workload("").unwrap();
+    }
+
+    // The system's group runs on even ticks, all allowed by `every(2)`
+    assert_eq!(world.borrow::<UniqueView<Ran>>().unwrap().0, [0, 2, 4, 6]);
+}
+
+#[test]
+fn every_world_tick() {
+    struct Ran(Vec<u32>);
+    impl Unique for Ran {}
+
+    fn sys(mut ran: UniqueViewMut<Ran>, value: UniqueView<U32>) {
+        ran.0.push(value.0);
+    }
+    fn fail() -> Result<(), error::MissingComponent> {
+        Err(error::MissingComponent {
+            id: EntityId::dead(),
+            name: "",
+        })
+    }
+
+    let world = World::new();
+
+    world.add_unique(Ran(Vec::new()));
+    world.add_unique(U32(0));
+
+    Workload::new("every")
+        .with_system(sys.every(3))
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("other").add_to_world(&world).unwrap();
+    Workload::new("fail")
+        .with_try_system(fail)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(world.tick(), 0);
+
+    for i in 0..4 {
+        world.borrow::<UniqueViewMut<U32>>().unwrap().0 = i;
+        world.run_workload("every").unwrap();
+        world.run_workload("other").unwrap();
+    }
+
+    assert_eq!(world.tick(), 8);
+    // "every" runs on even ticks, the system on ticks 0 and 6
+    assert_eq!(world.borrow::<UniqueView<Ran>>().unwrap().0, [0, 3]);
+
+    // Runs stopped by an error advance the tick too
+    assert!(world.run_workload("fail").is_err());
+    assert_eq!(world.tick(), 9);
+
+    let mut runner = world.workload_runner("every").unwrap();
+    while runner.step(&world).unwrap().is_some() {}
+    assert_eq!(world.tick(), 10);
+}
@@ -67,3 +67,36 @@ fn tracking_enabled() {
 
     world.run_workload(w).unwrap();
 }
+
+#[cfg(feature = "parallel")]
+#[test]
+fn local_thread_pool() {
+    use std::sync::{Arc, Mutex};
+    use std::thread::ThreadId;
+
+    let world = World::builder()
+        .with_local_thread_pool(rayon::ThreadPoolBuilder::new().build().unwrap())
+        .build();
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut not_sends: NonSend<ViewMut<NotSend>>| {
+            entities.add_entity(&mut *not_sends, NotSend(core::ptr::null()));
+        },
+    );
+
+    let thread_id: Arc<Mutex<Option<ThreadId>>> = Arc::new(Mutex::new(None));
+    let system_thread_id = thread_id.clone();
+
+    Workload::new("NonSend")
+        .with_system(move |_: NonSend<ViewMut<NotSend>>| {
+            *system_thread_id.lock().unwrap() = Some(std::thread::current().id());
+        })
+        .add_to_world(&world)
+        .unwrap();
+    world.run_workload("NonSend").unwrap();
+
+    assert_eq!(
+        *thread_id.lock().unwrap(),
+        Some(std::thread::current().id())
+    );
+}
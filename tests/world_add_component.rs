@@ -108,3 +108,23 @@ fn dead_entity() {
     let u32s = world.borrow::<View<U32>>().unwrap();
     assert!(u32s.get(entity).is_err());
 }
+
+#[test]
+fn dead_entity_checked() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    assert_eq!(
+        world.add_component_checked(entity, (U32(1u32),)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+
+    let alive = world.add_entity(());
+    assert_eq!(world.add_component_checked(alive, (U32(2u32),)), Ok(()));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert!(u32s.get(entity).is_err());
+    assert_eq!(u32s.get(alive), Ok(&U32(2)));
+}